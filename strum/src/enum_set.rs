@@ -0,0 +1,361 @@
+//! `EnumSet`, a set of the variants of a unit-only enum stored as one bit per variant.
+
+/// A trait for enums whose variants can be stored in an `EnumSet`. This can be autoimplemented by
+/// deriving `EnumSetType` on an enum that only has unit variants. Each variant gets the bit at its
+/// position in the declaration, and `Repr` is the smallest storage that fits all of them.
+pub trait EnumSetType: Copy {
+    type Repr: EnumSetStorage;
+
+    /// The number of variants, which is also the number of bits in use.
+    const VARIANT_COUNT: usize;
+
+    /// The position of the variant in the declaration.
+    fn to_index(self) -> usize;
+
+    /// The variant at `idx`, or `None` if it's out of range.
+    fn from_index(idx: usize) -> Option<Self>;
+}
+
+/// The bits behind an `EnumSet`: a `u64` for up to 64 variants, a `u128` for up to 128 and an
+/// array of `u64`s beyond that.
+pub trait EnumSetStorage: Copy + Eq + core::hash::Hash {
+    const EMPTY: Self;
+
+    fn get(&self, bit: usize) -> bool;
+    fn set(&mut self, bit: usize, value: bool);
+    fn count(&self) -> usize;
+    fn zip(self, other: Self, f: fn(u64, u64) -> u64) -> Self;
+}
+
+impl EnumSetStorage for u64 {
+    const EMPTY: u64 = 0;
+
+    fn get(&self, bit: usize) -> bool {
+        self & (1 << bit) != 0
+    }
+
+    fn set(&mut self, bit: usize, value: bool) {
+        if value {
+            *self |= 1 << bit;
+        } else {
+            *self &= !(1 << bit);
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.count_ones() as usize
+    }
+
+    fn zip(self, other: u64, f: fn(u64, u64) -> u64) -> u64 {
+        f(self, other)
+    }
+}
+
+impl EnumSetStorage for u128 {
+    const EMPTY: u128 = 0;
+
+    fn get(&self, bit: usize) -> bool {
+        self & (1 << bit) != 0
+    }
+
+    fn set(&mut self, bit: usize, value: bool) {
+        if value {
+            *self |= 1 << bit;
+        } else {
+            *self &= !(1 << bit);
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.count_ones() as usize
+    }
+
+    fn zip(self, other: u128, f: fn(u64, u64) -> u64) -> u128 {
+        let low = f(self as u64, other as u64) as u128;
+        let high = f((self >> 64) as u64, (other >> 64) as u64) as u128;
+        (high << 64) | low
+    }
+}
+
+impl<const N: usize> EnumSetStorage for [u64; N] {
+    const EMPTY: [u64; N] = [0; N];
+
+    fn get(&self, bit: usize) -> bool {
+        self[bit / 64].get(bit % 64)
+    }
+
+    fn set(&mut self, bit: usize, value: bool) {
+        self[bit / 64].set(bit % 64, value)
+    }
+
+    fn count(&self) -> usize {
+        self.iter().map(|word| word.count()).sum()
+    }
+
+    fn zip(mut self, other: [u64; N], f: fn(u64, u64) -> u64) -> [u64; N] {
+        for (word, other) in self.iter_mut().zip(other.iter()) {
+            *word = f(*word, *other);
+        }
+        self
+    }
+}
+
+/// A set of variants of `T`, stored as one bit per variant. It's `Copy`, can be built in a
+/// `const`, and iterates in declaration order.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use strum::EnumSet;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, EnumSetType)]
+/// enum Permission {
+///     Read,
+///     Write,
+///     Execute,
+/// }
+///
+/// const NONE: EnumSet<Permission> = EnumSet::new();
+///
+/// fn main() {
+///     let mut perms = NONE;
+///     perms.insert(Permission::Execute);
+///     perms.insert(Permission::Read);
+///     assert!(perms.contains(Permission::Read));
+///     assert!(!perms.contains(Permission::Write));
+///     assert_eq!(vec![Permission::Read, Permission::Execute], perms.iter().collect::<Vec<_>>());
+///
+///     let writable: EnumSet<_> = [Permission::Write].iter().cloned().collect();
+///     assert_eq!(3, perms.union(writable).len());
+///     assert!(perms.intersection(writable).is_empty());
+/// }
+/// ```
+pub struct EnumSet<T: EnumSetType> {
+    bits: T::Repr,
+}
+
+impl<T: EnumSetType> EnumSet<T> {
+    /// An empty set.
+    pub const fn new() -> EnumSet<T> {
+        EnumSet { bits: T::Repr::EMPTY }
+    }
+
+    /// A set of every variant.
+    pub fn all() -> EnumSet<T> {
+        EnumSet::<T>::new().complement()
+    }
+
+    /// Adds `value`, returning `false` if it was already there.
+    pub fn insert(&mut self, value: T) -> bool {
+        let idx = value.to_index();
+        let added = !self.bits.get(idx);
+        self.bits.set(idx, true);
+        added
+    }
+
+    /// Removes `value`, returning `false` if it wasn't there.
+    pub fn remove(&mut self, value: T) -> bool {
+        let idx = value.to_index();
+        let removed = self.bits.get(idx);
+        self.bits.set(idx, false);
+        removed
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.bits.get(value.to_index())
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == T::Repr::EMPTY
+    }
+
+    pub fn clear(&mut self) {
+        self.bits = T::Repr::EMPTY;
+    }
+
+    pub fn union(self, other: EnumSet<T>) -> EnumSet<T> {
+        EnumSet { bits: self.bits.zip(other.bits, |a, b| a | b) }
+    }
+
+    pub fn intersection(self, other: EnumSet<T>) -> EnumSet<T> {
+        EnumSet { bits: self.bits.zip(other.bits, |a, b| a & b) }
+    }
+
+    /// The variants in `self` that aren't in `other`.
+    pub fn difference(self, other: EnumSet<T>) -> EnumSet<T> {
+        EnumSet { bits: self.bits.zip(other.bits, |a, b| a & !b) }
+    }
+
+    /// The variants in exactly one of `self` and `other`.
+    pub fn symmetric_difference(self, other: EnumSet<T>) -> EnumSet<T> {
+        EnumSet { bits: self.bits.zip(other.bits, |a, b| a ^ b) }
+    }
+
+    /// The variants that aren't in `self`.
+    pub fn complement(self) -> EnumSet<T> {
+        let mut set = EnumSet::<T>::new();
+        for idx in 0..T::VARIANT_COUNT {
+            set.bits.set(idx, !self.bits.get(idx));
+        }
+        set
+    }
+
+    pub fn is_subset(&self, other: &EnumSet<T>) -> bool {
+        self.difference(*other).is_empty()
+    }
+
+    pub fn is_superset(&self, other: &EnumSet<T>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Iterates over the variants in the set in declaration order.
+    pub fn iter(&self) -> EnumSetIter<T> {
+        EnumSetIter {
+            set: *self,
+            idx: 0,
+        }
+    }
+}
+
+impl<T: EnumSetType> Clone for EnumSet<T> {
+    fn clone(&self) -> EnumSet<T> {
+        *self
+    }
+}
+
+impl<T: EnumSetType> Copy for EnumSet<T> {}
+
+impl<T: EnumSetType> PartialEq for EnumSet<T> {
+    fn eq(&self, other: &EnumSet<T>) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<T: EnumSetType> Eq for EnumSet<T> {}
+
+impl<T: EnumSetType> core::hash::Hash for EnumSet<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bits.hash(state)
+    }
+}
+
+impl<T: EnumSetType> Default for EnumSet<T> {
+    fn default() -> EnumSet<T> {
+        EnumSet::new()
+    }
+}
+
+impl<T: EnumSetType + core::fmt::Debug> core::fmt::Debug for EnumSet<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: EnumSetType> From<T> for EnumSet<T> {
+    fn from(value: T) -> EnumSet<T> {
+        let mut set = EnumSet::new();
+        set.insert(value);
+        set
+    }
+}
+
+impl<T: EnumSetType> core::iter::FromIterator<T> for EnumSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> EnumSet<T> {
+        let mut set = EnumSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: EnumSetType> Extend<T> for EnumSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: EnumSetType> IntoIterator for EnumSet<T> {
+    type Item = T;
+    type IntoIter = EnumSetIter<T>;
+
+    fn into_iter(self) -> EnumSetIter<T> {
+        self.iter()
+    }
+}
+
+impl<T: EnumSetType> IntoIterator for &EnumSet<T> {
+    type Item = T;
+    type IntoIter = EnumSetIter<T>;
+
+    fn into_iter(self) -> EnumSetIter<T> {
+        self.iter()
+    }
+}
+
+impl<T: EnumSetType> core::ops::BitOr for EnumSet<T> {
+    type Output = EnumSet<T>;
+
+    fn bitor(self, other: EnumSet<T>) -> EnumSet<T> {
+        self.union(other)
+    }
+}
+
+impl<T: EnumSetType> core::ops::BitAnd for EnumSet<T> {
+    type Output = EnumSet<T>;
+
+    fn bitand(self, other: EnumSet<T>) -> EnumSet<T> {
+        self.intersection(other)
+    }
+}
+
+impl<T: EnumSetType> core::ops::BitXor for EnumSet<T> {
+    type Output = EnumSet<T>;
+
+    fn bitxor(self, other: EnumSet<T>) -> EnumSet<T> {
+        self.symmetric_difference(other)
+    }
+}
+
+impl<T: EnumSetType> core::ops::Sub for EnumSet<T> {
+    type Output = EnumSet<T>;
+
+    fn sub(self, other: EnumSet<T>) -> EnumSet<T> {
+        self.difference(other)
+    }
+}
+
+impl<T: EnumSetType> core::ops::Not for EnumSet<T> {
+    type Output = EnumSet<T>;
+
+    fn not(self) -> EnumSet<T> {
+        self.complement()
+    }
+}
+
+/// An iterator over the variants in an `EnumSet`, created by `EnumSet::iter`.
+pub struct EnumSetIter<T: EnumSetType> {
+    set: EnumSet<T>,
+    idx: usize,
+}
+
+impl<T: EnumSetType> Iterator for EnumSetIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.idx < T::VARIANT_COUNT {
+            let idx = self.idx;
+            self.idx += 1;
+            if self.set.bits.get(idx) {
+                return T::from_index(idx);
+            }
+        }
+        None
+    }
+}
@@ -28,6 +28,26 @@
 //! # fn main() {}
 //! ```
 //!
+//! Alternatively, enable the `derive` feature of strum, which re-exports all of the macros from
+//! strum_macros so only one dependency is needed.
+//!
+//! ```toml
+//! [dependencies]
+//! strum = { version = "*", features = ["derive"] }
+//! ```
+//!
+//! The derives can then be used through the strum crate.
+//!
+//! ```rust,ignore
+//! extern crate strum;
+//!
+//! #[derive(strum::EnumString, strum::Display)]
+//! enum Color {
+//!     Red,
+//!     Green,
+//! }
+//! ```
+//!
 //! # Strum Macros
 //!
 //! Strum has implemented the following macros:
@@ -40,6 +60,23 @@
 //!     The `default` attribute can be applied to a tuple variant with a single data parameter. When a match isn't
 //!     found, the given variant will be returned and the input string will be captured in the parameter.
 //!
+//!     When every serialization of every variant is a single character, as in a tokenizer's
+//!     `#[strum(serialize="(")] OpenParen`, `TryFrom<char>` and `From<YourEnum> for char` are
+//!     implemented too. They're skipped if the enum has a `default`, `flatten`ed or disabled variant.
+//!
+//!     When the error is strum's `ParseError`, empty input gets its own `ParseError::EmptyInput`, so a
+//!     blank field can be told apart from a bad value. A variant that should be parsed from an empty string can say so with
+//!     `#[strum(serialize="")]`, which is never given the enum's `prefix` or `suffix`.
+//!
+//!     Two variants can't parse from the same string, including after `serialize_all` has been applied
+//!     or, for `ascii_case_insensitive` variants, when they only differ in case. The derive fails with
+//!     an error naming both variants instead of leaving one of them unreachable.
+//!
+//!     Generic enums like `enum Wrapper<T> { A, B(T) }` work too. A field whose type mentions a type
+//!     parameter gets a bound for the way it's built: `Default` when it's filled in with its default,
+//!     `FromStr` for `flatten`, `field_delimiter` and `serialize_pattern` fields, and the
+//!     `default_conversion` for the `default` variant.
+//!
 //!     Here is an example of the code generated by deriving `EnumString`.
 //!
 //!     ```
@@ -71,7 +108,7 @@
 //!                 "Red" => Ok(Color::Red),
 //!                 "Green" => Ok(Color::Green { range:Default::default() }),
 //!                 "blue" | "b" => Ok(Color::Blue(Default::default())),
-//!                 _ => Err(strum::ParseError::VariantNotFound),
+//!                 _ => Err(strum::ParseError::VariantNotFound { type_name: "Color" }),
 //!             }
 //!         }
 //!     }
@@ -89,14 +126,29 @@
 //!     creates a new type called `YourEnumIter` that is the iterator object. You cannot derive
 //!     `EnumIter` on any type with a lifetime bound (`<'a>`) because the iterator would surely
 //!     create [unbounded lifetimes] (https://doc.rust-lang.org/nightly/nomicon/unbounded-lifetimes.html).
+//!     The iterator also implements `DoubleEndedIterator`, `ExactSizeIterator`, `FusedIterator`,
+//!     `Clone` and `Debug`, so adapters like `rev()` and `len()` work as expected.
+//!     Because the number of variants is known up front, `nth`, `count`, `last` and `skip` run in
+//!     constant time instead of walking every variant. `Color::iter()` is also generated as an inherent
+//!     function, so iterating doesn't require importing `strum::IntoEnumIterator`; the trait is only
+//!     needed for generic code.
+//!     Generic enums are supported too; the generated impls require `Default` for each field type that
+//!     uses one of the enum's type parameters.
+//!     If every variant is unit-like, `Color::iter_refs()` is generated as well. It iterates over
+//!     `&'static Color` values stored in a static array, and `&'static Color` also implements
+//!     `IntoEnumIterator`.
+//!     `Color::first()` and `Color::last()` return the first and last variants that are iterated
+//!     over, and are `const fn`s when those variants have no fields.
+//!     `Color::range(..)` iterates over the variants within a range of them, such as
+//!     `Color::range(Color::Green { range: 0 }..)`, with the same iterator type as `iter()`. Only the
+//!     variants of the bounds matter, not their fields.
+//!     These are all inherent functions of the enum, so it can't have its own functions with the
+//!     same names.
 //!
 //!     ```rust
 //!     # extern crate strum;
 //!     # #[macro_use] extern crate strum_macros;
 //!     # use std::fmt::Debug;
-//!     // You need to bring the type into scope to use it!!!
-//!     use strum::IntoEnumIterator;
-//!
 //!     #[derive(EnumIter,Debug)]
 //!     enum Color {
 //!         Red,
@@ -111,10 +163,7 @@
 //!             println!("My favorite color is {:?}", color);
 //!         }
 //!     }
-//!
-//!     fn main() {
-//!         debug_colors();
-//!     }
+//!     # fn main() { debug_colors(); }
 //!     ```
 //!
 //! 3. `EnumMessage`: encode strings into the enum itself. This macro implements
@@ -122,6 +171,7 @@
 //!     `#[strum(message="...")]` attributes on your variants.
 //!     You can also provided a `detailed_message="..."` attribute to create a
 //!     seperate more detailed message than the first.
+//!     The variants' `///` doc comments are returned by `get_documentation()`.
 //!
 //!     The generated code will look something like:
 //!
@@ -181,214 +231,2021 @@
 //!     # fn main() {}
 //!     ```
 //!
-//! # Additional Attributes
+//! 4. `Display`: prints out the given enum. This enables you to perform round trip style conversions
+//!     from enum into string and back again for unit style variants. `Display` will choose the
+//!     `to_string` attribute if it's present, otherwise the longest `serialize` attribute, and will
+//!     fall back on the name of the variant. Since `ToString` is implemented for every type that
+//!     implements `Display`, you get `to_string()` for free.
+//!     Without an allocator, `color.write_str(&mut w)` writes the same text into any `core::fmt::Write`,
+//!     such as a fixed buffer, and the generated impls only use `core`.
+//!     On generic enums, only the fields that are printed need bounds: `Display` for a `transparent`
+//!     field, and the trait of its placeholder for a field in a `to_string` template, e.g. `Debug` for
+//!     `{0:?}`.
 //!
-//! Strum supports several custom attributes to modify the generated code. Custom attributes are
-//! applied to a variant by adding #[strum(parameter="value")] to the variant.
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     // You need to bring the type into scope to use it!!!
+//!     use std::string::ToString;
 //!
-//! - `serialize="..."`: Changes the text that `FromStr()` looks for when parsing a string. This attribute can
-//!    be applied multiple times to an element and the enum variant will be parsed if any of them match.
+//!     #[derive(Display, Debug)]
+//!     enum Color {
+//!         #[strum(serialize="redred")]
+//!         Red,
+//!         Green { range:usize },
+//!         #[strum(to_string="blue", serialize="b")]
+//!         Blue(usize),
+//!     }
 //!
-//! - `default="true"`: Applied to a single variant of an enum. The variant must be a Tuple-like
-//!    variant with a single piece of data that can be create from a `&str` i.e. `T: From<&str>`.
-//!    The generated code will now return the variant with the input string captured as shown below
-//!    instead of failing.
-//!
-//!     ```ignore
-//!     // Replaces this:
-//!     _ => Err(strum::ParseError::VariantNotFound)
-//!     // With this in generated code:
-//!     default => Ok(Variant(default.into()))
+//!     fn debug_colors() {
+//!         let red = Color::Red;
+//!         assert_eq!(String::from("redred"), red.to_string());
+//!         assert_eq!("blue", format!("{}", Color::Blue(10)));
+//!     }
+//!     # fn main() { debug_colors(); }
 //!     ```
-//!     The plugin will fail if the data doesn't implement From<&str>. You can only have one `default`
-//!     on your enum.
 //!
-//! - `disabled="true"`: removes variant from generated code.
+//! 5. `EnumCount`: adds a `COUNT` constant holding the number of variants on the enum. The macro
+//!     implements `strum::EnumCount` and also adds the constant directly to your enum, so it can be used
+//!     to size arrays without bringing the trait into scope. Disabled variants are still counted.
 //!
-//! - `message=".."`: Adds a message to enum variant. This is used in conjunction with the `EnumMessage`
-//!    trait to associate a message with a variant. If `detailed_message` is not provided,
-//!    then `message` will also be returned when get_detailed_message() is called.
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     #[derive(EnumCount)]
+//!     enum Color {
+//!         Red,
+//!         Green { range:usize },
+//!         Blue(usize),
+//!     }
 //!
-//! - `detailed_message=".."`: Adds a more detailed message to a variant. If this value is omitted, then
-//!    `message` will be used in it's place.
+//!     fn weights() -> [f32; Color::COUNT] {
+//!         [1.0; Color::COUNT]
+//!     }
+//!     # fn main() { assert_eq!(3, weights().len()); }
+//!     ```
 //!
-//! # Examples
+//! 6. `EnumDiscriminants`: generates a new, fieldless enum called `YourEnumDiscriminants` with one
+//!     variant for every variant of your enum, along with `From<YourEnum>` and `From<&YourEnum>`
+//!     implementations. The generated enum derives `Clone`, `Copy`, `Debug`, `PartialEq`, `Eq` and
+//!     `Hash`, so it's a cheap key for maps and can be matched on without destructuring any data.
+//!     It also implements `strum::IntoDiscriminant` for your enum, so generic code can get from a value
+//!     to its discriminant without naming the generated type.
+//!     A separate `strum_discriminants` attribute on your enum customizes the generated enum:
+//!     `derive(..)` adds to the default derives, `name(..)` renames it and `vis(..)` changes its
+//!     visibility, e.g. `#[strum_discriminants(derive(EnumString), name(Kind), vis(pub(crate)))]`.
+//!     The visibility can also be given as a string like `iter_vis`, as in `vis="pub(crate)"`.
+//!     Like the `EnumIter` iterator, it can't be more private than your enum.
+//!     `forward_attrs(..)` copies the named attributes from each variant to its discriminant, such as
+//!     `forward_attrs(doc, serde)` to keep doc comments and `#[serde(rename)]`. Only the attributes
+//!     listed are copied, since most of them only make sense on the original enum.
+//!     If your enum is `#[non_exhaustive]`, the generated one is too, so other crates can't match it
+//!     exhaustively and break when a variant is added.
 //!
-//! Using `EnumMessage` for quickly implementing `Error`
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     #[derive(EnumDiscriminants)]
+//!     enum Message {
+//!         Quit,
+//!         Move { x: i32, y: i32 },
+//!         Write(String),
+//!     }
 //!
-//! ```rust
-//! extern crate strum;
-//! #[macro_use]
-//! extern crate strum_macros;
-//! # use std::error::Error;
-//! # use std::fmt::*;
-//! use strum::EnumMessage;
+//!     fn kind(msg: &Message) -> MessageDiscriminants {
+//!         msg.into()
+//!     }
+//!     # fn main() {
+//!     #     assert_eq!(MessageDiscriminants::Write, kind(&Message::Write("hi".into())));
+//!     # }
+//!     ```
 //!
-//! #[derive(Debug, EnumMessage)]
-//! enum ServerError {
-//!     #[strum(message="A network error occured")]
-//!     #[strum(detailed_message="Try checking your connection.")]
-//!     NetworkError,
-//!     #[strum(message="User input error.")]
-//!     #[strum(detailed_message="There was an error parsing user input. Please try again.")]
-//!     InvalidUserInputError,
-//! }
+//! 7. `FromRepr`: adds a `from_repr(discriminant)` function to your enum that returns the variant with
+//!     the given discriminant, or `None` if there isn't one. Explicit discriminants (`Red = 3`) are
+//!     honored and the argument uses the type from `#[repr(..)]`, defaulting to `usize`. Any additional
+//!     data on your variants will be set to `Default::default()`.
+//!     When the enum has a `#[repr(..)]` or explicit discriminants, it also converts to that integer with
+//!     `From`, and back with `TryFrom`, which fails with a `strum::FromReprError` holding the value.
 //!
-//! impl Display for ServerError {
-//!     fn fmt(&self, f: &mut Formatter) -> Result {
-//!         write!(f, "{}", self.get_message().unwrap())
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     # use std::convert::TryFrom;
+//!     #[derive(FromRepr, Debug, PartialEq)]
+//!     #[repr(u8)]
+//!     enum Opcode {
+//!         Nop,
+//!         Push = 0x10,
+//!         Pop,
+//!         Jump(u16) = 0x20,
 //!     }
-//! }
 //!
-//! impl Error for ServerError {
-//!     fn description(&self) -> &str {
-//!         self.get_detailed_message().unwrap()
+//!     fn decode(byte: u8) -> Option<Opcode> {
+//!         Opcode::from_repr(byte)
 //!     }
-//! }
-//! # fn main() {}
-//! ```
+//!     # fn main() {
+//!     #     assert_eq!(Some(Opcode::Pop), decode(0x11));
+//!     #     assert_eq!(Some(Opcode::Jump(0)), decode(0x20));
+//!     #     assert_eq!(None, decode(0x01));
+//!     #     assert_eq!(0x20, u8::from(Opcode::Jump(7)));
+//!     #     assert_eq!(Err(strum::FromReprError { value: 0x01 }), Opcode::try_from(0x01));
+//!     # }
+//!     ```
 //!
-//! Using `EnumString` to tokenize a series of inputs:
+//! 8. `EnumVariantNames`: implements `strum::VariantNames`, which adds a `VARIANTS` constant holding
+//!     the name of every variant. Each name is the string `Display` would print for that variant,
+//!     and disabled variants are left out.
 //!
-//! ```rust
-//! extern crate strum;
-//! #[macro_use]
-//! extern crate strum_macros;
-//! use std::str::FromStr;
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     // You need to bring the type into scope to use it!!!
+//!     use strum::VariantNames;
 //!
-//! #[derive(Eq, PartialEq, Debug, EnumString)]
-//! enum Tokens {
-//!     #[strum(serialize="fn")]
-//!     Function,
-//!     #[strum(serialize="(")]
-//!     OpenParen,
-//!     #[strum(serialize=")")]
-//!     CloseParen,
-//!     #[strum(default="true")]
-//!     Ident(String)
-//! }
+//!     #[derive(EnumVariantNames)]
+//!     enum Color {
+//!         Red,
+//!         #[strum(serialize="blue", serialize="b")]
+//!         Blue,
+//!     }
 //!
-//! fn main() {
-//!     let toks = ["fn", "hello_world", "(", ")"].iter()
-//!                    .map(|tok| Tokens::from_str(tok).unwrap())
-//!                    .collect::<Vec<_>>();
+//!     fn colors() -> &'static [&'static str] {
+//!         Color::VARIANTS
+//!     }
+//!     # fn main() { assert_eq!(["Red", "blue"], colors()); }
+//!     ```
 //!
-//!     assert_eq!(toks, vec![Tokens::Function,
-//!                           Tokens::Ident(String::from("hello_world")),
-//!                           Tokens::OpenParen,
-//!                           Tokens::CloseParen]);
-//! }
-//! ```
+//! 9. `VariantArray`: implements `strum::VariantArray`, which adds a `VARIANTS` constant holding a
+//!     static slice of every variant. It only works on enums where every variant is a unit variant,
+//!     and disabled variants are left out.
 //!
-//! # Debugging
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     // You need to bring the type into scope to use it!!!
+//!     use strum::VariantArray;
+//!
+//!     #[derive(VariantArray, Debug)]
+//!     enum Color {
+//!         Red,
+//!         Green,
+//!         Blue,
+//!     }
 //!
-//! To see the generated code, set the DEBUG_STRUM environment variable before compiling your code.
-//! `DEBUG_STRUM=1` will dump all of the generated code for every type. `DEBUG_STRUM=YourType` will
-//! only dump the code generated on a type named YourType.
+//!     fn all_colors() -> &'static [Color] {
+//!         Color::VARIANTS
+//!     }
+//!     # fn main() { assert_eq!(3, all_colors().len()); }
+//!     ```
 //!
-//! # Name
+//! 10. `IntoStaticStr`: implements `From<YourEnum>` and `From<&YourEnum>` for `&'static str`. The
+//!     string is chosen the same way `Display` chooses it, but it isn't tied to the lifetime of the
+//!     enum, so it can be handed to APIs that require a `&'static str`.
+//!     It also implements `PartialEq` between your enum and `str` or `&str` in both directions, so
+//!     `state == "idle"` compares against that same string without allocating. Disabled variants
+//!     aren't equal to any string.
+//!     `State::MAX_SERIALIZED_LEN` is the length in bytes of the longest of those strings, for sizing
+//!     fixed buffers at compile time.
 //!
-//! Strum is short for STRing enUM because it's a library for augmenting enums with additional
-//! information through strings.
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     #[derive(IntoStaticStr)]
+//!     enum State {
+//!         #[strum(serialize="idle")]
+//!         Idle,
+//!         Running { pid: u32 },
+//!     }
 //!
-//! Strumming is also a very whimsical motion, much like writing Rust code.
-
-/// The ParseError enum is a collection of all the possible reasons
-/// an enum can fail to parse from a string.
-#[derive(Debug,Clone,Copy,Eq,PartialEq,Hash)]
-pub enum ParseError {
-    VariantNotFound,
-}
-
-impl std::fmt::Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
-        match self {
-            &ParseError::VariantNotFound => write!(f, "Matching variant not found"),
-        }
-    }
-}
-
-impl std::error::Error for ParseError {
-    fn description(&self) -> &str {
-        match self {
-            &ParseError::VariantNotFound => {
-                "Unable to find a variant of the given enum matching the string given. Matching \
-                 can be extended with the Serialize attribute and is case sensitive."
-            }
-        }
-    }
-}
-
-/// This trait designates that an `Enum` can be iterated over. It can
-/// be auto generated using `strum_macros` on your behalf.
-///
-/// # Example
-///
-/// ```rust
-/// # extern crate strum;
-/// # #[macro_use] extern crate strum_macros;
-/// # use std::fmt::Debug;
-/// // You need to bring the type into scope to use it!!!
-/// use strum::IntoEnumIterator;
-///
-/// #[derive(EnumIter,Debug)]
-/// enum Color {
-///         Red,
-///         Green { range:usize },
-///         Blue(usize),
-///         Yellow,
-/// }
-///
-/// // Iterating over any enum requires 2 type parameters
-/// // A 3rd is used in this example to allow passing a predicate
-/// fn generic_iterator<E, I, F>(pred: F)
-///                      where E: IntoEnumIterator<Iterator=I>,
-///                            I: Iterator<Item=E>,
-///                            F: Fn(E) {
-///     for e in E::iter() {
-///         pred(e)
-///     }
-/// }
-///
-/// fn main() {
-///     generic_iterator::<Color,_, _>(|color| println!("{:?}", color));
-/// }
-/// ```
-pub trait IntoEnumIterator {
-    type Iterator;
-
-    fn iter() -> Self::Iterator;
-}
-
-/// Associates additional pieces of information with an Enum. This can be
-/// autoimplemented by deriving `EnumMessage` and annotating your variants with
-/// `#[strum(message="...")].
-///
-/// # Example
-///
-/// ```rust
-/// # extern crate strum;
-/// # #[macro_use] extern crate strum_macros;
-/// # use std::fmt::Debug;
-/// // You need to bring the type into scope to use it!!!
-/// use strum::EnumMessage;
-///
-/// #[derive(PartialEq, Eq, Debug, EnumMessage)]
-/// enum Pet {
-///     #[strum(message="I have a dog")]
-///     #[strum(detailed_message="My dog's name is Spots")]
-///     Dog,
-///     #[strum(message="I don't have a cat")]
-///     Cat,
-/// }
-///
-/// fn main() {
-///     let my_pet = Pet::Dog;
-///     assert_eq!("I have a dog", my_pet.get_message().unwrap());
-/// }
-/// ```
-pub trait EnumMessage {
-    fn get_message(&self) -> Option<&str>;
-    fn get_detailed_message(&self) -> Option<&str>;
-    fn get_serializations(&self) -> &[&str];
+//!     fn state_name(state: &State) -> &'static str {
+//!         state.into()
+//!     }
+//!     # fn main() {
+//!     #     assert_eq!("idle", state_name(&State::Idle));
+//!     #     assert_eq!("Running", state_name(&State::Running { pid: 1 }));
+//!     #     assert!(State::Idle == "idle");
+//!     # }
+//!     ```
+//!
+//! 11. `EnumIs`: adds an `is_variant_name(&self) -> bool` method for every variant of your enum. The
+//!     name of the method is the name of the variant converted to `snake_case`, and every method is
+//!     a `const fn`.
+//!
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     #[derive(EnumIs)]
+//!     enum Packet {
+//!         KeepAlive,
+//!         Data(Vec<u8>),
+//!         TcpReset { code: u16 },
+//!     }
+//!
+//!     fn needs_reply(packet: &Packet) -> bool {
+//!         packet.is_keep_alive() || packet.is_tcp_reset()
+//!     }
+//!     # fn main() {
+//!     #     assert!(needs_reply(&Packet::KeepAlive));
+//!     #     assert!(!needs_reply(&Packet::Data(vec![1, 2])));
+//!     # }
+//!     ```
+//!
+//! 12. `EnumProperty`: implements `strum::EnumProperty`, which looks up custom properties attached to
+//!     each variant with `#[strum(props(Key="value"))]`. Values can be strings, integers or bools, and
+//!     are returned by `get_str`, `get_int` and `get_bool` respectively. `get_all_props` returns every
+//!     key and value pair of a variant, with the values converted to strings.
+//!     Adding `#[strum(prop_accessors)]` to the enum also generates a method for each key, named after
+//!     the key in `snake_case`, e.g. `symbol(&self) -> Option<&'static str>` for the `Symbol` key below.
+//!     Keys can be made mandatory with `#[strum(props_required(Symbol, Weight))]` on the enum. Every
+//!     variant must then set them, or the derive fails to compile, and each required key gets an accessor
+//!     that returns the value directly, e.g. `symbol(&self) -> &'static str`.
+//!
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     use strum::EnumProperty;
+//!
+//!     #[derive(EnumProperty)]
+//!     enum Unit {
+//!         #[strum(props(Symbol="m", Weight=10, Metric=true))]
+//!         Meter,
+//!         #[strum(props(Symbol="ft", Metric=false))]
+//!         Foot,
+//!     }
+//!
+//!     fn describe(unit: Unit) -> String {
+//!         let system = if unit.get_bool("Metric") == Some(true) { "metric" } else { "imperial" };
+//!         format!("{} ({})", unit.get_str("Symbol").unwrap(), system)
+//!     }
+//!     # fn main() {
+//!     #     assert_eq!("m (metric)", describe(Unit::Meter));
+//!     #     assert_eq!(Some(10), Unit::Meter.get_int("Weight"));
+//!     #     assert_eq!(None, Unit::Foot.get_int("Weight"));
+//!     # }
+//!     ```
+//!
+//! 13. `EnumSerialize` and `EnumDeserialize`: implement `serde::Serialize` and `serde::Deserialize`
+//!     using the same strings as the other derives, so `serialize_all`, aliases and the `default`
+//!     variant don't have to be repeated with `#[serde(rename)]`. Serializing writes the string that
+//!     `IntoStaticStr` would return, or the captured string for the `default` variant. Deserializing
+//!     goes through the `FromStr` impl from `EnumString`, which has to be derived too. These derives
+//!     need the `serde` feature of strum_macros (or strum's `derive` and `serde` features), and serde
+//!     has to be a dependency of your crate.
+//!
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     # extern crate serde;
+//!     # extern crate serde_json;
+//!     #[derive(Debug, PartialEq, EnumString, EnumSerialize, EnumDeserialize)]
+//!     #[strum(serialize_all="kebab-case")]
+//!     enum Color {
+//!         DarkRed,
+//!         #[strum(serialize="b", serialize="blue")]
+//!         Blue,
+//!         #[strum(default="true")]
+//!         Other(String),
+//!     }
+//!
+//!     # fn main() {
+//!     assert_eq!("\"dark-red\"", serde_json::to_string(&Color::DarkRed).unwrap());
+//!     assert_eq!("\"blue\"", serde_json::to_string(&Color::Blue).unwrap());
+//!     assert_eq!(Color::Blue, serde_json::from_str::<Color>("\"b\"").unwrap());
+//!     assert_eq!("\"teal\"", serde_json::to_string(&Color::Other("teal".into())).unwrap());
+//!     # }
+//!     ```
+//!
+//! 14. `EnumValueEnum`: implements clap's `ValueEnum` for enums with unit variants, so they can be
+//!     used as `#[arg(value_enum)]` arguments. The preferred name of each variant is the value clap
+//!     shows, its other serializations are aliases and its `message` is the help text. Disabled
+//!     variants are left out. It also adds a `VALUE_NAMES` constant listing the accepted names, for
+//!     building a parser by hand. The derive needs the `clap` feature of strum_macros (or strum's
+//!     `derive` and `clap` features), and clap has to be a dependency of your crate.
+//!
+//!     ```rust,ignore
+//!     #[derive(Clone, EnumValueEnum)]
+//!     #[strum(serialize_all="kebab-case")]
+//!     enum Mode {
+//!         #[strum(message="Optimize for speed")]
+//!         Fast,
+//!         #[strum(serialize="small", serialize="s", message="Optimize for size")]
+//!         Small,
+//!     }
+//!
+//!     // `--mode fast`, `--mode small` and `--mode s` are accepted, and `--help` lists the messages.
+//!     assert_eq!(&["fast", "small"], Mode::VALUE_NAMES);
+//!     ```
+//!
+//! 15. `EnumJsonSchema`: implements `schemars::JsonSchema` (schemars 1.x) as a string schema whose
+//!     `enum` lists every serialization `EnumString` accepts, aliases and `serialize_all` included,
+//!     so generated API docs agree with `FromStr`. An enum with a `default` variant accepts any
+//!     string, so its schema is just `"type": "string"`. The derive needs the `schemars` feature of
+//!     strum_macros (or strum's `derive` and `schemars` features), and schemars has to be a dependency
+//!     of your crate.
+//!
+//!     ```rust,ignore
+//!     #[derive(EnumString, EnumJsonSchema)]
+//!     #[strum(serialize_all="snake_case")]
+//!     enum Mode {
+//!         FastMode,
+//!         #[strum(serialize="s", serialize="slow")]
+//!         Slow,
+//!     }
+//!
+//!     // { "type": "string", "enum": ["fast_mode", "s", "slow"] }
+//!     let schema = schemars::schema_for!(Mode);
+//!     ```
+//!
+//! 16. `EnumSqlxType`: implements sqlx's `Type`, `Encode` and `Decode` (sqlx 0.8) for any database
+//!     that can store a `&str`, so the enum can live in a TEXT column. Values are written as the
+//!     string `IntoStaticStr` would return, or the captured string for the `default` variant, and
+//!     read back with the `FromStr` impl from `EnumString`, which has to be derived too. A string that
+//!     doesn't parse becomes a decode error. The derive needs the `sqlx` feature of strum_macros (or
+//!     strum's `derive` and `sqlx` features), and sqlx has to be a dependency of your crate.
+//!
+//!     ```rust,ignore
+//!     #[derive(EnumString, EnumSqlxType)]
+//!     #[strum(serialize_all="snake_case")]
+//!     enum Status {
+//!         Active,
+//!         OnHold,
+//!     }
+//!
+//!     sqlx::query("INSERT INTO accounts (status) VALUES ($1)")
+//!         .bind(Status::OnHold)
+//!         .execute(&pool)
+//!         .await?;
+//!     ```
+//!
+//! 17. `EnumDieselText`: implements diesel's `ToSql<Text, DB>` and `FromSql<Text, DB>` (diesel 2.x)
+//!     for every backend, writing the string `IntoStaticStr` would return and reading it back with the
+//!     `FromStr` impl from `EnumString`, which has to be derived too. diesel's own `AsExpression` and
+//!     `FromSqlRow` derives are still needed to use the enum in a model. The derive needs the `diesel`
+//!     feature of strum_macros (or strum's `derive` and `diesel` features), and diesel has to be a
+//!     dependency of your crate.
+//!
+//!     ```rust,ignore
+//!     #[derive(AsExpression, FromSqlRow, EnumString, EnumDieselText)]
+//!     #[diesel(sql_type = diesel::sql_types::Text)]
+//!     #[strum(serialize_all="snake_case")]
+//!     enum Status {
+//!         Active,
+//!         OnHold,
+//!     }
+//!
+//!     diesel::update(accounts::table).set(accounts::status.eq(Status::OnHold)).execute(conn)?;
+//!     ```
+//!
+//! 18. `EnumPostgres`: implements `ToSql` and `FromSql` from postgres-types, the traits that
+//!     postgres and tokio-postgres use, by way of the strings `IntoStaticStr` and `EnumString` use.
+//!     `EnumString` and `Debug` have to be derived too. By default the enum maps to TEXT and
+//!     VARCHAR columns. With `#[strum(postgres_type="mood")]` on the enum it maps to a Postgres `ENUM`
+//!     of that name instead, whose labels should be the strum serializations. The derive needs
+//!     strum's `postgres-types` feature, which also brings in the bytes crate that `ToSql` writes to,
+//!     along with its `derive` feature or strum_macros' `postgres-types` feature. postgres-types has
+//!     to be a dependency of your crate.
+//!
+//!     ```rust,ignore
+//!     // CREATE TYPE mood AS ENUM ('happy', 'sad');
+//!     #[derive(Debug, EnumString, EnumPostgres)]
+//!     #[strum(serialize_all="snake_case", postgres_type="mood")]
+//!     enum Mood {
+//!         Happy,
+//!         Sad,
+//!     }
+//!
+//!     client.execute("INSERT INTO people (mood) VALUES ($1)", &[&Mood::Happy]).await?;
+//!     ```
+//!
+//! 19. `EnumRusqlite`: implements rusqlite's `ToSql` and `FromSql`, so the enum can be bound to and
+//!     read from SQLite TEXT columns as the string `IntoStaticStr` would return. Reading goes through
+//!     the `FromStr` impl from `EnumString`, which has to be derived too, and a parse error comes back
+//!     as `FromSqlError::Other`. The derive needs the `rusqlite` feature of strum_macros (or strum's
+//!     `derive` and `rusqlite` features), and rusqlite has to be a dependency of your crate.
+//!
+//!     ```rust,ignore
+//!     #[derive(EnumString, EnumRusqlite)]
+//!     #[strum(serialize_all="snake_case")]
+//!     enum Status {
+//!         Active,
+//!         OnHold,
+//!     }
+//!
+//!     conn.execute("INSERT INTO accounts (status) VALUES (?1)", [Status::OnHold])?;
+//!     let status: Status = conn.query_row("SELECT status FROM accounts", [], |row| row.get(0))?;
+//!     ```
+//!
+//! 20. `EnumRandom`: implements `Distribution<YourEnum>` for rand's `StandardUniform` (rand 0.9), so
+//!     `rand::random::<YourEnum>()` and `rng.random::<YourEnum>()` pick a variant uniformly. Disabled
+//!     variants are never picked, and fields are filled in with `Default::default()` like `EnumIter`
+//!     does. The derive needs the `rand` feature of strum_macros (or strum's `derive` and `rand`
+//!     features), and rand has to be a dependency of your crate.
+//!
+//!     ```rust,ignore
+//!     #[derive(EnumRandom)]
+//!     enum Color {
+//!         Red,
+//!         Green,
+//!         #[strum(disabled="true")]
+//!         Transparent,
+//!     }
+//!
+//!     let color: Color = rand::random(); // Red or Green
+//!     ```
+//!
+//!     It also adds `YourEnum::sample_weighted(&mut rng)`, which picks variants in proportion to a
+//!     `weight` property, e.g. `#[strum(props(weight=5))]`. Variants without one have a weight of 1,
+//!     and a weight of 0 means the variant is never picked. The weights are summed at compile time, so
+//!     sampling is a single random number and a walk down the variants.
+//!
+//!     ```rust,ignore
+//!     #[derive(EnumRandom)]
+//!     enum Loot {
+//!         #[strum(props(weight=90))]
+//!         Common,
+//!         #[strum(props(weight=9))]
+//!         Rare,
+//!         Legendary,
+//!     }
+//!
+//!     let drop = Loot::sample_weighted(&mut rand::rng()); // Legendary 1% of the time
+//!     ```
+//!
+//! 21. `EnumProptest` and `EnumQuickCheck`: implement `Arbitrary` from proptest and from quickcheck
+//!     (1.x) respectively. Both pick one of the enabled variants uniformly and generate its fields
+//!     with the fields' own `Arbitrary` impls, so data-carrying variants are covered too. On generic
+//!     enums the type parameters used in fields get an `Arbitrary` bound. The proptest strategy
+//!     doesn't shrink across variants, only within the fields. These derives need the `proptest` or
+//!     `quickcheck` feature of strum_macros (or strum's `derive` and `proptest` or `quickcheck`
+//!     features), and the matching crate has to be a dependency of your crate.
+//!
+//!     ```rust,ignore
+//!     #[derive(Debug, Clone, EnumProptest, EnumQuickCheck)]
+//!     enum Shape {
+//!         Dot,
+//!         Line(u8, u8),
+//!         Circle { radius: u16 },
+//!         #[strum(disabled="true")]
+//!         Invalid,
+//!     }
+//!
+//!     proptest! {
+//!         #[test]
+//!         fn area_is_finite(shape: Shape) {
+//!             prop_assert!(area(&shape).is_finite());
+//!         }
+//!     }
+//!     ```
+//!
+//! 22. `EnumArbitrary`: implements `arbitrary::Arbitrary` (arbitrary 1.x), so cargo-fuzz targets can
+//!     take the enum as input. The fuzzer's bytes pick one of the enabled variants and then build its
+//!     fields with their own `Arbitrary` impls. Disabled variants are never produced. On generic enums
+//!     the type parameters used in fields get an `Arbitrary` bound. The derive needs the `arbitrary`
+//!     feature of strum_macros (or strum's `derive` and `arbitrary` features), and arbitrary has to be
+//!     a dependency of your crate.
+//!
+//!     ```rust,ignore
+//!     #[derive(Debug, EnumArbitrary)]
+//!     enum Command {
+//!         Ping,
+//!         Move { x: i32, y: i32 },
+//!         Say(String),
+//!     }
+//!
+//!     fuzz_target!(|command: Command| {
+//!         server.handle(command);
+//!     });
+//!     ```
+//!
+//! 23. `EnumParIter`: adds `YourEnum::par_iter()` and implements rayon's `IntoParallelIterator` for the
+//!     iterator from `EnumIter`, which has to be derived too. The parallel iterator is indexed, so
+//!     rayon splits the variants by position without collecting them first, and `zip` or
+//!     `enumerate` keep working. A partly used iterator only yields the variants it has left. The
+//!     derive needs the `rayon` feature of strum_macros (or strum's `derive` and `rayon` features),
+//!     and rayon has to be a dependency of your crate.
+//!
+//!     ```rust,ignore
+//!     use rayon::prelude::*;
+//!
+//!     #[derive(EnumIter, EnumParIter)]
+//!     enum Opcode {
+//!         Add,
+//!         Sub,
+//!         // ...hundreds more
+//!     }
+//!
+//!     let timings: Vec<_> = Opcode::par_iter().map(|op| benchmark(op)).collect();
+//!     ```
+//!
+//! 24. `EnumSetType`: implements `strum::EnumSetType` for enums with unit variants, so they can be
+//!     collected in a `strum::EnumSet`. The set stores one bit per variant in a `u64`, a `u128` or an
+//!     array of `u64`s depending on how many variants there are, so it's `Copy`, allocation free and
+//!     can be built in a `const`. It has `insert`, `remove`, `contains`, `union`, `intersection`,
+//!     `difference`, `complement` and `iter`, along with the matching `|`, `&`, `^`, `-` and `!`
+//!     operators. The enum has to be `Copy`.
+//!
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     use strum::EnumSet;
+//!
+//!     #[derive(Debug, Clone, Copy, PartialEq, EnumSetType)]
+//!     enum Feature {
+//!         Logging,
+//!         Metrics,
+//!         Tracing,
+//!     }
+//!
+//!     const DEFAULTS: EnumSet<Feature> = EnumSet::new();
+//!
+//!     fn enabled() -> EnumSet<Feature> {
+//!         let mut features = DEFAULTS;
+//!         features.insert(Feature::Tracing);
+//!         features | EnumSet::from(Feature::Logging)
+//!     }
+//!     # fn main() {
+//!     #     assert_eq!(vec![Feature::Logging, Feature::Tracing], enabled().iter().collect::<Vec<_>>());
+//!     #     assert!(!enabled().contains(Feature::Metrics));
+//!     # }
+//!     ```
+//!
+//! 25. `EnumFlags`: generates a companion bitflags type for an enum with unit variants, named
+//!     `{Enum}Flags` unless `#[strum(flags_name="...")]` says otherwise. It has one constant per
+//!     variant in `SCREAMING_SNAKE_CASE`, each with the bit at the variant's position, and wraps the
+//!     smallest unsigned integer that fits them all, or the one given by `#[strum(flags_repr="u32")]`.
+//!     The flags type has `empty`, `all`, `bits`, `from_bits`, `from_bits_truncate`, `contains`,
+//!     `insert`, `remove` and `iter`, the `|`, `&`, `^`, `-` and `!` operators, and converts from the
+//!     enum and into the integer.
+//!
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     #[derive(Debug, Clone, Copy, PartialEq, EnumFlags)]
+//!     enum Permission {
+//!         Read,
+//!         Write,
+//!         ExecuteCode,
+//!     }
+//!
+//!     fn can_run(perms: PermissionFlags) -> bool {
+//!         perms.contains(PermissionFlags::READ | PermissionFlags::EXECUTE_CODE)
+//!     }
+//!     # fn main() {
+//!     #     let perms = PermissionFlags::from(Permission::Read) | PermissionFlags::EXECUTE_CODE;
+//!     #     assert!(can_run(perms));
+//!     #     assert_eq!(0b101u8, perms.bits());
+//!     #     assert_eq!("PermissionFlags(READ | EXECUTE_CODE)", format!("{:?}", perms));
+//!     # }
+//!     ```
+//!
+//! 26. `EnumIndex`: adds a `const fn to_index(&self) -> usize` giving each variant's position in the
+//!     declaration, and implements `Index` and `IndexMut` for arrays with one element per variant. With
+//!     `EnumCount` that gives per-variant tables that stay correct when variants are reordered, rather
+//!     than relying on `as usize` casts. It works for variants with data too.
+//!
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     #[derive(Clone, Copy, EnumCount, EnumIndex)]
+//!     enum Region {
+//!         Europe,
+//!         Americas,
+//!         Asia,
+//!     }
+//!
+//!     struct Stats {
+//!         requests: [u64; Region::COUNT],
+//!     }
+//!
+//!     impl Stats {
+//!         fn record(&mut self, region: Region) {
+//!             self.requests[region] += 1;
+//!         }
+//!     }
+//!     # fn main() {
+//!     #     let mut stats = Stats { requests: [0; Region::COUNT] };
+//!     #     stats.record(Region::Asia);
+//!     #     assert_eq!(1, stats.requests[Region::Asia]);
+//!     #     assert_eq!(0, stats.requests[Region::Europe]);
+//!     # }
+//!     ```
+//!
+//! 27. `EnumRotate`: adds `next(self)` and `previous(self)`, which step through the variants in
+//!     declaration order and wrap around at either end. Like `EnumIter`, fields of the variant being
+//!     stepped to are filled in with `Default::default()`. Variants marked
+//!     `#[strum(disabled(EnumRotate))]` are skipped over, but still step to their neighbours.
+//!
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     #[derive(Debug, PartialEq, EnumRotate)]
+//!     enum Theme {
+//!         Light,
+//!         Dark,
+//!         Solarized,
+//!     }
+//!
+//!     fn on_shortcut(theme: Theme, shift: bool) -> Theme {
+//!         if shift { theme.previous() } else { theme.next() }
+//!     }
+//!     # fn main() {
+//!     #     assert_eq!(Theme::Dark, on_shortcut(Theme::Light, false));
+//!     #     assert_eq!(Theme::Light, on_shortcut(Theme::Solarized, false));
+//!     #     assert_eq!(Theme::Solarized, on_shortcut(Theme::Light, true));
+//!     # }
+//!     ```
+//!
+//! 28. `EnumStableId`: adds `stable_id(&self) -> u64` and `from_stable_id(u64)`, which give every
+//!     variant an ID that's the 64 bit FNV-1a hash of the name `Display` would print. Unlike a
+//!     position or a discriminant, the ID doesn't change when variants are reordered or added, so it
+//!     suits binary logs and other persisted data. A variant can pin the string that's hashed with
+//!     `#[strum(stable_id="...")]` to keep its ID through a rename. `from_stable_id` fills in fields
+//!     with `Default::default()`, and two variants hashing to the same ID is a compile error.
+//!
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     #[derive(Debug, PartialEq, EnumStableId)]
+//!     #[strum(serialize_all="snake_case")]
+//!     enum Record {
+//!         Login,
+//!         #[strum(stable_id="logout")]
+//!         SignOut,
+//!     }
+//!
+//!     fn encode(record: &Record) -> [u8; 8] {
+//!         record.stable_id().to_le_bytes()
+//!     }
+//!
+//!     fn decode(bytes: [u8; 8]) -> Option<Record> {
+//!         Record::from_stable_id(u64::from_le_bytes(bytes))
+//!     }
+//!     # fn main() {
+//!     #     assert_eq!(Some(Record::SignOut), decode(encode(&Record::SignOut)));
+//!     #     assert_eq!(Some(Record::Login), decode(encode(&Record::Login)));
+//!     #     assert_eq!(None, decode([0; 8]));
+//!     # }
+//!     ```
+//!
+//! 29. `EnumCStr`: adds a `const fn as_cstr(&self) -> &'static CStr` holding the name `Display` would
+//!     print, and implements `TryFrom<&CStr>` parsing the same strings as `EnumString`, so names can
+//!     cross a C boundary without allocating a `CString` each time. A name containing a NUL byte is
+//!     a compile error. Parsing fills in fields with `Default::default()` and fails with
+//!     `strum::ParseError`.
+//!
+//!     ```rust
+//!     # extern crate strum;
+//!     # #[macro_use] extern crate strum_macros;
+//!     use std::convert::TryFrom;
+//!     use std::ffi::CStr;
+//!     use std::os::raw::c_char;
+//!
+//!     #[derive(Debug, PartialEq, EnumCStr)]
+//!     #[strum(serialize_all="lowercase")]
+//!     enum Codec {
+//!         Opus,
+//!         Vorbis,
+//!     }
+//!
+//!     extern "C" fn codec_name(codec: u8) -> *const c_char {
+//!         let codec = if codec == 0 { Codec::Opus } else { Codec::Vorbis };
+//!         codec.as_cstr().as_ptr()
+//!     }
+//!
+//!     fn parse_codec(name: &CStr) -> Option<Codec> {
+//!         Codec::try_from(name).ok()
+//!     }
+//!     # fn main() {
+//!     #     let name = unsafe { CStr::from_ptr(codec_name(1)) };
+//!     #     assert_eq!("vorbis", name.to_str().unwrap());
+//!     #     assert_eq!(Some(Codec::Vorbis), parse_codec(name));
+//!     # }
+//!     ```
+//!
+//! # Additional Attributes
+//!
+//! Strum supports several custom attributes to modify the generated code. Custom attributes are
+//! applied to a variant by adding #[strum(parameter="value")] to the variant.
+//!
+//! Every key is checked when deriving: a misspelled or unknown key, a key on the variant that belongs on
+//! the enum (or the other way around), a value of the wrong kind, or a key given twice that can only be
+//! given once is a compile error that lists the valid options.
+//!
+//! The error points at the key it's about, or at the name of the enum or variant when it isn't
+//! about one key. Every strum derive checks the attributes, but an error they all find is only
+//! reported once.
+//!
+//! Variants behind a `#[cfg(...)]` that's turned off are removed by the compiler before any derive runs,
+//! so they're left out of the iterators, parsers, counts and tables without any extra attributes, and
+//! `#[cfg_attr(..., strum(...))]` works the same as writing the `strum` attribute directly.
+//!
+//! `#[non_exhaustive]` enums work with every derive. The generated code is part of your crate, where
+//! the enum can still be matched exhaustively, so it never needs a wildcard arm.
+//!
+//! Variants named with raw identifiers like `r#type` are serialized and parsed without the `r#`, so
+//! `r#type` round trips as `"type"`.
+//!
+//! - `serialize="..."`: Changes the text that `FromStr()` looks for when parsing a string. This attribute can
+//!   be applied multiple times to an element and the enum variant will be parsed if any of them match.
+//!
+//! - `to_string="..."`: Similar to `serialize`. This value will be included when using `FromStr()`. More importantly,
+//!   this specifies what text to use when calling `variant.to_string()` with the `Display` derivation.
+//!   On variants with data, it can refer to the fields like a `message` template, e.g.
+//!   `to_string="{0} items"` or `to_string="range {range}"`, and `Display` will format them in.
+//!
+//! - `serialize_all="..."`: Applied to the enum itself rather than a variant. Every variant without a
+//!   `serialize` or `to_string` attribute will be converted into the given case style, both when parsing
+//!   and when printing. The supported styles are `snake_case`, `kebab-case`, `SCREAMING_SNAKE_CASE`,
+//!   `SCREAMING-KEBAB-CASE`, `lowercase`, `UPPERCASE`, `PascalCase` and `camelCase`.
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   #[derive(Display, EnumString)]
+//!   #[strum(serialize_all="snake_case")]
+//!   enum Brightness {
+//!       VeryDim,
+//!       #[strum(serialize="bright")]
+//!       Bright,
+//!   }
+//!
+//!   fn describe() -> String {
+//!       Brightness::VeryDim.to_string()
+//!   }
+//!   # fn main() { assert_eq!("very_dim", describe()); }
+//!   ```
+//!
+//! - `use_serde_names`: Applied to the enum itself, when it also derives serde's traits. Strum then
+//!   reads serde's `rename_all`, `rename` and `alias` attributes as well as its own, so `FromStr` and
+//!   `Display` agree with serde without repeating the names. `rename_all` is used unless there's a
+//!   `serialize_all`, and it splits words the way serde does, so `HTTPServer` is `h-t-t-p-server` in
+//!   `kebab-case`. A variant's `rename` is its preferred name unless it has a `to_string`, and its
+//!   `alias`es are accepted when parsing. serde's names don't get a `prefix` or `suffix`.
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   # #[macro_use] extern crate serde;
+//!   #[derive(Debug, PartialEq, Serialize, Deserialize, Display, EnumString)]
+//!   #[serde(rename_all = "kebab-case")]
+//!   #[strum(use_serde_names)]
+//!   enum Level {
+//!       ErrorLevel,
+//!       #[serde(rename = "warn", alias = "warning")]
+//!       Warning,
+//!   }
+//!
+//!   # fn main() {
+//!   assert_eq!("error-level", Level::ErrorLevel.to_string());
+//!   assert_eq!(Ok(Level::Warning), "warning".parse());
+//!   # }
+//!   ```
+//!
+//! - `ascii_case_insensitive`: Applied to the enum itself. The generated `FromStr` will ignore ASCII
+//!   case when matching, so `"red"`, `"RED"` and `"Red"` all parse into the same variant.
+//!   Individual variants can override the setting with `ascii_case_insensitive = false` or
+//!   `ascii_case_insensitive = true`.
+//!
+//! - `case_insensitive="ascii"`: Applied to the enum itself. Another spelling of
+//!   `ascii_case_insensitive`, next to `case_insensitive="unicode"` below. `from_str_const` and
+//!   `EnumCStr` follow it too.
+//!
+//! - `prefix="..."`: Applied to the enum itself. The prefix is added to every serialization of every
+//!   variant, so it's printed by `Display` and `IntoStaticStr` and expected by `FromStr`.
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   #[derive(Display, EnumString)]
+//!   #[strum(prefix="color/")]
+//!   enum Color {
+//!       Red,
+//!       #[strum(serialize="blue")]
+//!       Blue,
+//!   }
+//!
+//!   fn config_key() -> String {
+//!       Color::Blue.to_string()
+//!   }
+//!   # fn main() { assert_eq!("color/blue", config_key()); }
+//!   ```
+//!
+//! - `suffix="..."`: Applied to the enum itself. Works just like `prefix`, but the suffix is added to
+//!   the end of every serialization instead.
+//!
+//! - `use_phf`: Applied to the enum itself. Instead of a `match` that compares the input against every
+//!   serialization, `FromStr` will look the input up in a perfect hash table that's built at compile
+//!   time. This is much faster for enums with hundreds of variants. It requires the `phf` feature
+//!   of strum. Exact matches are preferred over `ascii_case_insensitive` ones.
+//!   Without it, enums with more than 16 serializations still branch on the length of the input
+//!   first, so it's only compared against the serializations of the same length. Past 256
+//!   serializations the input is binary searched in sorted tables instead, so that enums with
+//!   thousands of variants don't produce one enormous `match` for rustc to check.
+//!
+//!   ```toml
+//!   [dependencies]
+//!   strum = { version = "*", features = ["phf"] }
+//!   ```
+//!
+//! - `case_insensitive="unicode"`: Applied to the enum itself. Like `ascii_case_insensitive`, but
+//!   `FromStr` lowercases both strings and decomposes accented letters before comparing, so
+//!   `"ÉTÉ"` and `"été"` both match `#[strum(serialize="été")]` whether the accents are precomposed or
+//!   combining characters. `EnumCStr` matches C strings the same way. Variants can still opt out
+//!   with `ascii_case_insensitive=false`. It requires the `unicode` feature of strum, and can't be
+//!   combined with `use_phf` or `normalize_separators`.
+//!
+//!   This isn't full Unicode case folding. Only letters in U+00C0..U+052F (Latin, IPA, Greek and
+//!   Cyrillic) and U+1E00..U+1FFF (Latin Extended Additional and Greek Extended) are decomposed.
+//!   Lowercasing uses `char::to_lowercase`, plus `ß` matching `ss` and `ς` matching `σ`.
+//!   Compatibility characters such as `ﬁ` aren't folded, and combining marks aren't reordered.
+//!
+//!   ```toml
+//!   [dependencies]
+//!   strum = { version = "*", features = ["unicode"] }
+//!   ```
+//!
+//! - `shared_table`: Applied to the enum itself. `EnumVariantNames` adds one hidden table that holds
+//!   every string of every variant, and `Display`, `IntoStaticStr`, `EnumMessage::get_serializations`
+//!   and `EnumString` read the strings out of it by index instead of spelling them out in their own
+//!   match arms. An enum with all of them only carries one copy of its strings, which adds up for
+//!   large enums. The enum has to derive `EnumVariantNames`, or the others fail to compile with an
+//!   error saying so.
+//!
+//!   `EnumString` compares the input with each string in turn instead of matching on it, so large
+//!   enums don't switch to binary search, and `shared_table` can't be combined with `use_phf`.
+//!   Strings that aren't looked up there keep their own copies: messages, the candidates listed by
+//!   `suggest` and `rich_error`, `from_str_const`, and derives like `EnumCStr`.
+//!
+//! - `const_into_str`: Applied to an enum deriving `IntoStaticStr`. Adds a `const fn as_str(&self)` to the
+//!   enum that returns the same string as `From`. If every variant is a unit variant, a
+//!   `const fn from_str_const(s: &str) -> Option<Self>` that parses the same strings as `EnumString` is
+//!   added too, unless the enum uses `case_insensitive="unicode"`, which can't be done in a `const fn`.
+//!   Both can be used in `const` and `static` initializers.
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   #[derive(IntoStaticStr, Debug, PartialEq)]
+//!   #[strum(const_into_str)]
+//!   enum Level {
+//!       #[strum(serialize="lo")]
+//!       Low,
+//!       High,
+//!   }
+//!
+//!   const DEFAULT_LEVEL: &str = Level::Low.as_str();
+//!   const PARSED: Option<Level> = Level::from_str_const("High");
+//!   # fn main() {
+//!   #     assert_eq!("lo", DEFAULT_LEVEL);
+//!   #     assert_eq!(Some(Level::High), PARSED);
+//!   # }
+//!   ```
+//!
+//! - `trim`: Applied to the enum itself. The generated `FromStr` strips leading and trailing whitespace
+//!   from the input before matching it, so `" red\n"` parses like `"red"`. A captured `default` or error
+//!   input is the trimmed string.
+//!
+//! - `normalize_separators`: Applied to the enum itself. `FromStr` treats `-`, `_` and spaces as the
+//!   same character when matching, so a `kebab-case` variant also parses from `snake_case` or from
+//!   words separated by spaces. It can't be combined with `use_phf`.
+//!
+//! - `abbreviations`: Applied to the enum itself. When the input doesn't match a serialization exactly,
+//!   `FromStr` accepts it if it's the start of only one variant's serializations, the way many command
+//!   line tools accept abbreviated subcommands. If more than one variant starts with it, the error is
+//!   `ParseError::Ambiguous`. It needs strum's own `ParseError`, so it can't be combined with
+//!   `capture_input`, `suggest` or `parse_err_ty`.
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   # use std::str::FromStr;
+//!   #[derive(EnumString, Debug, PartialEq)]
+//!   #[strum(serialize_all="snake_case", abbreviations)]
+//!   enum Command {
+//!       Status,
+//!       Stash,
+//!       Commit,
+//!   }
+//!   # fn main() {
+//!   assert_eq!(Ok(Command::Status), Command::from_str("stat"));
+//!   assert_eq!(Ok(Command::Commit), Command::from_str("c"));
+//!   assert_eq!(Err(strum::ParseError::Ambiguous), Command::from_str("st"));
+//!   # }
+//!   ```
+//!
+//! - `serialize_pattern="..."`: Applied to a variant. The variant matches any input with the text
+//!   before and after the pattern's `*`, which has to stand for at least one character. If the variant
+//!   has a field, the text in place of the `*` is parsed into it with `FromStr`, and the pattern
+//!   doesn't match when that fails. A variant can have several patterns, and they're tried after the
+//!   exact serializations. It requires the `patterns` feature of strum.
+//!
+//!   ```rust,ignore
+//!   #[derive(EnumString)]
+//!   enum Token {
+//!       #[strum(serialize="+")]
+//!       Plus,
+//!       #[strum(serialize_pattern="reg*")]
+//!       Register(u8), // "reg0" through "reg255"
+//!   }
+//!   ```
+//!
+//! - `parse_discriminant`: Applied to the enum itself. When the input doesn't match a serialization,
+//!   `FromStr` parses it as an integer and returns the variant with that discriminant, so configs that
+//!   mix names with legacy numeric codes parse in one step. The integer type comes from the enum's
+//!   `#[repr]`, or is `isize` without one. Disabled and `default` variants are never returned.
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   # use std::str::FromStr;
+//!   #[derive(EnumString, Debug, PartialEq)]
+//!   #[strum(serialize_all="snake_case", parse_discriminant)]
+//!   #[repr(u8)]
+//!   enum Level {
+//!       Low = 1,
+//!       High = 10,
+//!   }
+//!   # fn main() {
+//!   assert_eq!(Ok(Level::High), Level::from_str("high"));
+//!   assert_eq!(Ok(Level::High), Level::from_str("10"));
+//!   # }
+//!   ```
+//!
+//! - `allow_qualified`: Applied to the enum itself. `FromStr` also accepts a serialization written
+//!   after the enum's name, like `"Color::Red"` or `"palette::Color::Red"`, as it often appears in logs.
+//!   Only the enum's own name is stripped, so `"Shade::Red"` is still an error.
+//!
+//! - `rich_error`: Applied to the enum itself. Instead of the shared `ParseError`, `FromStr` returns a
+//!   generated `YourEnumParseError` whose `Display` lists the strings that would have parsed, as in
+//!   `expected one of: Red, Green, blue`. They're also available as `YourEnumParseError::EXPECTED`, and
+//!   the error converts into `ParseError`. It can't be combined with `capture_input`, `suggest` or
+//!   `parse_err_ty`.
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   # use std::str::FromStr;
+//!   #[derive(EnumString, Debug, PartialEq)]
+//!   #[strum(rich_error)]
+//!   enum Color {
+//!       Red,
+//!       Green,
+//!       #[strum(serialize="blue")]
+//!       Blue,
+//!   }
+//!   # fn main() {
+//!   let err = Color::from_str("purple").unwrap_err();
+//!   assert_eq!("expected one of: Red, Green, blue", err.to_string());
+//!   # }
+//!   ```
+//!
+//! - `try_from`: Applied to an enum deriving `EnumString`. Also implements `TryFrom<&str>` and, with
+//!   strum's `alloc` feature, `TryFrom<String>`, both parsing the same way as `FromStr` and returning
+//!   the same error. It's opt in because the impls conflict with the standard library's blanket
+//!   `TryFrom` impl when the enum already implements `From<&str>`.
+//!   `TryFrom<&[u8]>` is implemented as well, so tokens can be parsed straight out of a byte buffer.
+//!   It compares the bytes against the serializations without checking them for UTF-8 first, and
+//!   only falls back to `FromStr` for input none of them match, like a `default` variant's.
+//!   With strum's `std` feature, `TryFrom<&OsStr>` is implemented too, for arguments from
+//!   `std::env::args_os`. Input that isn't valid UTF-8 fails to parse instead of being mangled by a
+//!   lossy conversion.
+//!
+//! - `capture_input`: Applied to the enum itself. The generated `FromStr` will return a
+//!   `strum::ParseErrorWithInput` that holds a copy of the string that failed to parse instead of a
+//!   `strum::ParseError`. This requires the `alloc` feature of strum, which is enabled by default.
+//!
+//! - `suggest`: Applied to the enum itself. When parsing fails, the generated `FromStr` returns a
+//!   `strum::ParseErrorWithInput` whose `suggestion` is the closest serialization to the input, as long
+//!   as it's close enough to be a likely typo. The suggestion is included when the error is displayed,
+//!   e.g. `Matching variant not found for "gren", did you mean "Green"?`. This requires the
+//!   `suggestions` feature of strum.
+//!
+//! - `parse_err_ty` and `parse_err_fn`: Applied to the enum itself, and always used together. The
+//!   generated `FromStr` will use `parse_err_ty` as its `Err` type, building the error by calling
+//!   `parse_err_fn` with the string that failed to parse. This keeps `strum::ParseError` out of your
+//!   public API.
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   # use std::str::FromStr;
+//!   #[derive(Debug)]
+//!   pub enum ConfigError {
+//!       UnknownBackend(String),
+//!   }
+//!
+//!   impl ConfigError {
+//!       fn unknown_backend(input: &str) -> ConfigError {
+//!           ConfigError::UnknownBackend(input.to_string())
+//!       }
+//!   }
+//!
+//!   #[derive(Debug, EnumString)]
+//!   #[strum(parse_err_ty="ConfigError", parse_err_fn="ConfigError::unknown_backend")]
+//!   enum Backend {
+//!       Sqlite,
+//!       Postgres,
+//!   }
+//!   # fn main() {
+//!   # match Backend::from_str("Mysql") {
+//!   #     Err(ConfigError::UnknownBackend(input)) => assert_eq!("Mysql", input),
+//!   #     Ok(backend) => panic!("parsed {:?}", backend),
+//!   # }
+//!   # }
+//!   ```
+//!
+//! - `bound="..."` and `no_bound`: Applied to the enum itself. On generic enums, the derives bound the
+//!   type of each field that mentions a type parameter with the trait it's built or printed with,
+//!   such as `Default` for the fields `EnumIter` fills in. `bound` replaces those with the where
+//!   clause predicates you give it, and `no_bound` removes them. This is for fields that are handled
+//!   by your own code, like a `parse_with` function, which strum can't see the requirements of:
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   use std::str::FromStr;
+//!
+//!   #[derive(EnumString, Debug, PartialEq)]
+//!   #[strum(bound="T: FromStr")]
+//!   enum Setting<T> {
+//!       Unset,
+//!       #[strum(serialize="=", parse_with="parse_value")]
+//!       Value(T),
+//!   }
+//!
+//!   fn parse_value<T: FromStr>(s: &str) -> Result<Setting<T>, strum::ParseError> {
+//!       s.parse()
+//!           .map(Setting::Value)
+//!           .map_err(|_| strum::ParseError::VariantNotFound { type_name: "Setting" })
+//!   }
+//!   # fn main() {
+//!   assert_eq!(Ok(Setting::Value(5)), Setting::<u8>::from_str("=5"));
+//!   # }
+//!   ```
+//!
+//! - `iter_name` and `iter_vis`: Applied to the enum itself. They change the name of the iterator type
+//!   that `EnumIter` generates (`YourEnumIter` by default) and its visibility (the enum's visibility
+//!   by default), e.g. `#[strum(iter_name="ColorVariants", iter_vis="pub(crate)")]`. The iterator
+//!   can't be more private than the enum, since it appears in the `IntoEnumIterator` impl.
+//!
+//! - `transparent`: Applied to a variant with exactly one field. `Display` will format the field
+//!   itself instead of printing the variant's name, so `Error::Other(String)` prints its message.
+//!   Other derives, like `IntoStaticStr`, still use the name. Since `EnumString` and `EnumCStr`
+//!   would then disagree with `Display`, they reject a `transparent` variant unless it's disabled
+//!   for them, or for `EnumString`, also `flatten`ed so the field is parsed back.
+//!
+//! - `flatten`: Applied to a variant with exactly one field whose type implements `FromStr`, usually
+//!   another `EnumString` enum. Input that doesn't match any of the other variants is parsed as the
+//!   field's type, and flattened variants are tried in the order they're declared.
+//!
+//!   ```rust
+//!   # extern crate strum;
+//!   # #[macro_use] extern crate strum_macros;
+//!   # use std::str::FromStr;
+//!   #[derive(Debug, PartialEq, EnumString)]
+//!   enum Color {
+//!       Red,
+//!       Blue,
+//!   }
+//!
+//!   #[derive(Debug, PartialEq, EnumString)]
+//!   enum Command {
+//!       #[strum(serialize="quit")]
+//!       Quit,
+//!       #[strum(flatten)]
+//!       Color(Color),
+//!   }
+//!   # fn main() {
+//!   # assert_eq!(Ok(Command::Color(Color::Red)), Command::from_str("Red"));
+//!   # assert_eq!(Ok(Command::Quit), Command::from_str("quit"));
+//!   # }
+//!   ```
+//!
+//! - `default="true"`: Applied to a single variant of an enum. The variant must be a Tuple-like
+//!   variant with a single piece of data that can be create from a `&str` i.e. `T: From<&str>`, or a
+//!   struct-like variant with a single field, like `Unknown { name: String }`.
+//!   The generated code will now return the variant with the input string captured as shown below
+//!   instead of failing.
+//!
+//!   ```ignore
+//!   // Replaces this:
+//!   _ => Err(strum::ParseError::VariantNotFound { type_name: "YourEnum" })
+//!   // With this in generated code:
+//!   default => Ok(Variant(default.into()))
+//!   ```
+//!   The plugin will fail if the data doesn't implement From<&str>. You can only have one `default`
+//!   on your enum.
+//!
+//!   Any type with `From<&str>` works, including `Box<str>` and `Arc<str>`. Add
+//!   `default_conversion="from_str"` to parse the input with the field's `FromStr` impl instead, or
+//!   `default_conversion="try_from"` to use its `TryFrom<&str>` impl. If that conversion fails,
+//!   parsing fails with `strum::ConversionError::Conversion` holding the field's error, and the usual
+//!   errors come wrapped in `ConversionError::Parse`. `from_str` also works on a generic field, but
+//!   `try_from` doesn't, since its error type can't be named. An enum with an error type of its own,
+//!   like `capture_input` or `parse_err_ty`, returns that instead and drops the field's error.
+//!
+//!   A field that borrows with one of the enum's lifetimes, like `Ident(&'a str)` on `Token<'a>`,
+//!   captures a slice of the input instead of a copy. `EnumString` then implements
+//!   `strum::TryFromStr<'a>` instead of `FromStr`, since `FromStr`'s input can't outlive the call.
+//!   Every type with `FromStr` also implements `TryFromStr`, so a parser can take either kind.
+//!
+//! - `field_delimiter=".."`: Applied to the enum itself. Variants with data can then be parsed with
+//!   their fields spelled out after the variant's name, each separated by the delimiter and parsed
+//!   with its own `FromStr` impl. With `field_delimiter=":"`, `"Blue:42"` parses as `Color::Blue(42)`
+//!   and `"Green:7"` parses as `Color::Green { range: 7 }`, using the order the fields are declared in.
+//!   Input that names the variant but has the wrong number of fields, or fields that don't parse,
+//!   is an error. The variant's name on its own still parses with default field values.
+//!
+//! - `parse_with="path::to::function"`: Applied to a variant. Input that starts with one of the
+//!   variant's serializations is handed to the function, minus that prefix, and the function builds
+//!   the value with the signature `fn(&str) -> Result<YourEnum, Err>`, where `Err` is the `FromStr`
+//!   error type. For example, `#[strum(serialize="#", parse_with="parse_hex")]` passes `"ff0000"` to
+//!   `parse_hex` when parsing `"#ff0000"`. Variants are tried in the order they're declared, after
+//!   the variants that match the whole input.
+//!
+//! - `default_with="path::to::function"`: Applied to the enum itself. Input that doesn't match any
+//!   variant is passed to the function, which has the signature `fn(&str) -> Result<YourEnum, Err>`
+//!   where `Err` is the `FromStr` error type. This is useful when the unknown string needs to be
+//!   normalized or interned before it's stored. It can't be combined with a `default` variant.
+//!
+//! - `disabled="true"`: removes variant from generated code. Use `disabled(EnumIter, Display)` instead
+//!   to only remove it from the listed derives, e.g. to skip a variant when iterating while still
+//!   parsing it with `EnumString`.
+//!
+//! - `message=".."`: Adds a message to enum variant. This is used in conjunction with the `EnumMessage`
+//!   trait to associate a message with a variant. If `detailed_message` is not provided,
+//!   then `message` will also be returned when get_detailed_message() is called.
+//!   On variants with data, the message can be a template that refers to the fields as `{name}` or
+//!   `{0}`, with the usual format specs like `{name:?}`. `get_message()` returns the template itself,
+//!   while `get_message_owned()` returns a `String` with the fields filled in. This needs the `alloc`
+//!   feature of strum.
+//!
+//! - `message_locale(lang="..", ..)`: Adds translated messages to a variant, e.g.
+//!   `#[strum(message="Open", message_locale(de="Öffnen", fr="Ouvrir"))]`. `get_message_lang("de")`
+//!   returns the message for that language, falling back to `message` for any other language.
+//!
+//! - `message_key=".."`: Records a translation key for a variant, returned by `get_message_key()`.
+//!   With the `i18n` feature of strum, `resolve_message()` looks the key up in a `strum::Translations`
+//!   implementation, like a fluent bundle or gettext catalog, and falls back to `message`.
+//!
+//! - `detailed_message=".."`: Adds a more detailed message to a variant. If this value is omitted, then
+//!   `message` will be used in it's place.
+//!
+//! # Foreign Enums
+//!
+//! The derives can't be added to an enum from another crate, and the orphan rule stops strum's traits
+//! from being implemented for it by hand. `impl_for_external!` takes a copy of the enum's declaration,
+//! with the path to the original as its name, and declares a local enum with the same variants along
+//! with `From` conversions in both directions. Derives and `strum` attributes on the copy work as
+//! usual. The local enum is named after the last segment of the path, or `as Name` picks another name.
+//!
+//! If the original enum is `#[non_exhaustive]`, the copy should be too. Converting the original is
+//! then done with `TryFrom`, which hands back the value if the copy has no variant for it. Generic
+//! enums aren't supported.
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate strum_macros;
+//! extern crate strum;
+//! # mod http {
+//! #     pub enum Method { Get, Post, Extension(String) }
+//! # }
+//!
+//! impl_for_external! {
+//!     #[derive(EnumString, Display)]
+//!     #[strum(serialize_all="UPPERCASE")]
+//!     pub enum http::Method as HttpMethod {
+//!         Get,
+//!         Post,
+//!         #[strum(default="true")]
+//!         Extension(String),
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let method: http::Method = "POST".parse::<HttpMethod>().unwrap().into();
+//!     assert!(matches!(method, http::Method::Post));
+//!     assert_eq!("GET", HttpMethod::from(http::Method::Get).to_string());
+//! }
+//! ```
+//!
+//! # Examples
+//!
+//! Using `EnumMessage` for quickly implementing `Error`
+//!
+//! ```rust
+//! extern crate strum;
+//! #[macro_use]
+//! extern crate strum_macros;
+//! # use std::error::Error;
+//! # use std::fmt::*;
+//! use strum::EnumMessage;
+//!
+//! #[derive(Debug, EnumMessage)]
+//! enum ServerError {
+//!     #[strum(message="A network error occured")]
+//!     #[strum(detailed_message="Try checking your connection.")]
+//!     NetworkError,
+//!     #[strum(message="User input error.")]
+//!     #[strum(detailed_message="There was an error parsing user input. Please try again.")]
+//!     InvalidUserInputError,
+//! }
+//!
+//! impl Display for ServerError {
+//!     fn fmt(&self, f: &mut Formatter) -> Result {
+//!         write!(f, "{}", self.get_message().unwrap())
+//!     }
+//! }
+//!
+//! impl Error for ServerError {
+//!     fn description(&self) -> &str {
+//!         self.get_detailed_message().unwrap()
+//!     }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Using `EnumString` to tokenize a series of inputs:
+//!
+//! ```rust
+//! extern crate strum;
+//! #[macro_use]
+//! extern crate strum_macros;
+//! use std::str::FromStr;
+//!
+//! #[derive(Eq, PartialEq, Debug, EnumString)]
+//! enum Tokens {
+//!     #[strum(serialize="fn")]
+//!     Function,
+//!     #[strum(serialize="(")]
+//!     OpenParen,
+//!     #[strum(serialize=")")]
+//!     CloseParen,
+//!     #[strum(default="true")]
+//!     Ident(String)
+//! }
+//!
+//! fn main() {
+//!     let toks = ["fn", "hello_world", "(", ")"].iter()
+//!                    .map(|tok| Tokens::from_str(tok).unwrap())
+//!                    .collect::<Vec<_>>();
+//!
+//!     assert_eq!(toks, vec![Tokens::Function,
+//!                           Tokens::Ident(String::from("hello_world")),
+//!                           Tokens::OpenParen,
+//!                           Tokens::CloseParen]);
+//! }
+//! ```
+//!
+//! # no_std
+//!
+//! Strum and the code generated by strum_macros only depend on `core`, so they can be used on targets
+//! without the standard library. Disable the default `std` feature to build strum without `std`. This
+//! removes the `std::error::Error` implementations for the error types. Enable the `alloc` feature to
+//! keep the pieces of strum that need an allocator, like `ParseErrorWithInput`.
+//!
+//! ```toml
+//! [dependencies]
+//! strum = { version = "*", default-features = false }
+//! strum_macros = "*"
+//! ```
+//!
+//! Keep in mind that a `default` variant captures the input with `From<&str>`, so it needs a type
+//! that can be built from a borrowed string, like `String` from the `alloc` crate.
+//!
+//! # Debugging
+//!
+//! To see the generated code, set the STRUM_DEBUG environment variable before compiling your code.
+//! `STRUM_DEBUG=1` will dump all of the generated code for every type. `STRUM_DEBUG=YourType` will
+//! only dump the code generated on a type named `YourType`, and `STRUM_DEBUG=YourType:EnumString`
+//! only the code from one derive on it. Several of these can be given separated by commas, and
+//! adding `out=path` appends the code to that file instead of printing it, which is easier to read
+//! in a large workspace: `STRUM_DEBUG=Color:EnumString,out=/tmp/strum.rs`.
+//!
+//! # Name
+//!
+//! Strum is short for STRing enUM because it's a library for augmenting enums with additional
+//! information through strings.
+//!
+//! Strumming is also a very whimsical motion, much like writing Rust code.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The numbered list above indents its paragraphs past the number, which clippy doesn't expect.
+#![allow(clippy::doc_overindented_list_items)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "derive")]
+extern crate strum_macros;
+
+#[cfg(feature = "derive")]
+pub use strum_macros::*;
+
+mod enum_set;
+
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+pub mod phf;
+
+#[cfg(feature = "suggestions")]
+#[doc(hidden)]
+pub mod suggest;
+
+#[cfg(feature = "unicode")]
+#[doc(hidden)]
+pub mod unicode;
+
+pub use enum_set::{EnumSet, EnumSetIter, EnumSetStorage, EnumSetType};
+
+/// The ParseError enum is a collection of all the possible reasons
+/// an enum can fail to parse from a string.
+///
+/// It's `#[non_exhaustive]`, since new parsing options can bring new ways to fail, so a `match` on
+/// it outside of strum needs a `_` arm.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use std::str::FromStr;
+///
+/// #[derive(Debug, EnumString)]
+/// enum Color {
+///     Red,
+/// }
+///
+/// fn main() {
+///     let err = Color::from_str("Purple").unwrap_err();
+///     assert_eq!(strum::ParseError::VariantNotFound { type_name: "Color" }, err);
+///     assert_eq!("Matching variant of Color not found", err.to_string());
+/// }
+/// ```
+#[derive(Debug,Clone,Copy,Eq,PartialEq,Hash)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// None of the variants match. `type_name` is the name of the enum that was being parsed, so
+    /// the error still says where it came from after it's been passed up with `?`.
+    VariantNotFound { type_name: &'static str },
+    /// The input is an abbreviation of more than one variant. Only returned for enums with the
+    /// `abbreviations` attribute.
+    Ambiguous,
+    /// The input was empty and no variant can be parsed from an empty string, so a blank field can
+    /// be told apart from a bad value.
+    EmptyInput,
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        match *self {
+            ParseError::VariantNotFound { type_name } => {
+                write!(f, "Matching variant of {} not found", type_name)
+            }
+            ParseError::Ambiguous => write!(f, "More than one variant starts with the input"),
+            ParseError::EmptyInput => write!(f, "Input was empty"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn description(&self) -> &str {
+        match *self {
+            ParseError::VariantNotFound { .. } => {
+                "Unable to find a variant of the given enum matching the string given. Matching \
+                 can be extended with the Serialize attribute and is case sensitive."
+            }
+            ParseError::Ambiguous => {
+                "The string given is an abbreviation of more than one variant of the given enum."
+            }
+            ParseError::EmptyInput => {
+                "The string given was empty, and no variant of the given enum matches an empty string."
+            }
+        }
+    }
+}
+
+/// The error from the `TryFrom` impl generated by `FromRepr`, holding the integer that doesn't
+/// match the discriminant of any variant.
+#[derive(Debug,Clone,Copy,Eq,PartialEq,Hash)]
+pub struct FromReprError<T> {
+    pub value: T,
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for FromReprError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "No variant has the discriminant {}", self.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: core::fmt::Debug + core::fmt::Display> std::error::Error for FromReprError<T> {}
+
+/// The error `EnumString` returns when the `default` variant is built with
+/// `default_conversion="from_str"` or `default_conversion="try_from"`, so the reason the field
+/// rejected the input isn't lost.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use std::str::FromStr;
+///
+/// #[derive(Debug, PartialEq, EnumString)]
+/// enum Port {
+///     #[strum(serialize="http")]
+///     Http,
+///     #[strum(default="true", default_conversion="from_str")]
+///     Number(u16),
+/// }
+///
+/// fn main() {
+///     let err = "ftp".parse::<u16>().unwrap_err();
+///     assert_eq!(Err(strum::ConversionError::Conversion(err)), Port::from_str("ftp"));
+///     assert_eq!("invalid digit found in string", Port::from_str("ftp").unwrap_err().to_string());
+/// }
+/// ```
+#[derive(Debug,Clone,Copy,Eq,PartialEq,Hash)]
+pub enum ConversionError<E> {
+    /// The input didn't parse, for one of the usual reasons.
+    Parse(ParseError),
+    /// The input reached the `default` variant, whose field failed to convert it.
+    Conversion(E),
+}
+
+impl<E> From<ParseError> for ConversionError<E> {
+    fn from(err: ParseError) -> ConversionError<E> {
+        ConversionError::Parse(err)
+    }
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for ConversionError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        match *self {
+            ConversionError::Parse(ref err) => err.fmt(f),
+            ConversionError::Conversion(ref err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for ConversionError<E> {
+    // `Display` already shows the field's error, so its source comes next in the chain.
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            ConversionError::Parse(_) => None,
+            ConversionError::Conversion(ref err) => err.source(),
+        }
+    }
+}
+
+/// Like `ParseError::VariantNotFound`, but it remembers the string that failed to parse.
+/// `EnumString` returns this error instead of `ParseError` when the enum has the
+/// `capture_input` or `suggest` attributes. This requires the `alloc` feature, which is
+/// enabled by `std`.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use std::str::FromStr;
+///
+/// #[derive(Debug, EnumString)]
+/// #[strum(capture_input)]
+/// enum Color {
+///     Red,
+///     Green,
+/// }
+///
+/// fn main() {
+///     let err = Color::from_str("Purple").unwrap_err();
+///     assert_eq!("Purple", err.input);
+///     assert_eq!(None, err.suggestion);
+///     assert_eq!("Matching variant not found for \"Purple\"", err.to_string());
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug,Clone,Eq,PartialEq,Hash)]
+pub struct ParseErrorWithInput {
+    pub input: alloc::string::String,
+    /// The closest serialization to `input`, filled in when the enum has the `suggest` attribute.
+    pub suggestion: Option<&'static str>,
+    /// The name of the enum that was being parsed.
+    pub type_name: &'static str,
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for ParseErrorWithInput {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "Matching variant not found for {:?}", self.input)?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, ", did you mean {:?}?", suggestion)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseErrorWithInput {
+    fn description(&self) -> &str {
+        "Unable to find a variant of the given enum matching the string given."
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ParseErrorWithInput> for ParseError {
+    fn from(err: ParseErrorWithInput) -> ParseError {
+        ParseError::VariantNotFound { type_name: err.type_name }
+    }
+}
+
+/// Like `FromStr`, but the parsed value can borrow from the input. Deriving `EnumString` implements
+/// this instead of `FromStr` when the `default` variant holds something that borrows with one of
+/// the enum's lifetimes, like `&'a str`, so unmatched input is captured without allocating. Every
+/// type that implements `FromStr` implements this too, so code can be generic over both.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use strum::TryFromStr;
+///
+/// #[derive(EnumString, Debug, PartialEq)]
+/// enum Token<'a> {
+///     #[strum(serialize="let")]
+///     Let,
+///     #[strum(default="true")]
+///     Ident(&'a str),
+/// }
+///
+/// fn main() {
+///     let source = String::from("let x");
+///     let tokens = source.split(' ').map(Token::try_from_str).collect::<Result<Vec<_>, _>>();
+///     assert_eq!(Ok(vec![Token::Let, Token::Ident("x")]), tokens);
+/// }
+/// ```
+pub trait TryFromStr<'a>: Sized {
+    type Error;
+
+    fn try_from_str(s: &'a str) -> Result<Self, Self::Error>;
+}
+
+impl<'a, T: core::str::FromStr> TryFromStr<'a> for T {
+    type Error = T::Err;
+
+    fn try_from_str(s: &'a str) -> Result<T, T::Err> {
+        s.parse()
+    }
+}
+
+/// This trait designates that an `Enum` can be iterated over. It can
+/// be auto generated using `strum_macros` on your behalf.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// # use std::fmt::Debug;
+/// // You need to bring the type into scope to use it!!!
+/// use strum::IntoEnumIterator;
+///
+/// #[derive(EnumIter,Debug)]
+/// enum Color {
+///         Red,
+///         Green { range:usize },
+///         Blue(usize),
+///         Yellow,
+/// }
+///
+/// // `E::iter()` yields `E`, so generic code only needs the one type parameter.
+/// // A 2nd is used in this example to allow passing a predicate
+/// fn generic_iterator<E, F>(pred: F)
+///     where E: IntoEnumIterator,
+///           F: Fn(E) {
+///     for e in E::iter() {
+///         pred(e)
+///     }
+/// }
+///
+/// fn main() {
+///     generic_iterator::<Color, _>(|color| println!("{:?}", color));
+/// }
+/// ```
+pub trait IntoEnumIterator: Sized {
+    type Iterator: Iterator<Item = Self>;
+
+    fn iter() -> Self::Iterator;
+}
+
+/// Associates additional pieces of information with an Enum. This can be
+/// autoimplemented by deriving `EnumMessage` and annotating your variants with
+/// `#[strum(message="...")].
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// # use std::fmt::Debug;
+/// // You need to bring the type into scope to use it!!!
+/// use strum::EnumMessage;
+///
+/// #[derive(PartialEq, Eq, Debug, EnumMessage)]
+/// enum Pet {
+///     #[strum(message="I have a dog")]
+///     #[strum(detailed_message="My dog's name is Spots")]
+///     Dog,
+///     #[strum(message="I don't have a cat")]
+///     Cat,
+/// }
+///
+/// fn main() {
+///     let my_pet = Pet::Dog;
+///     assert_eq!("I have a dog", my_pet.get_message().unwrap());
+/// }
+/// ```
+pub trait EnumMessage {
+    fn get_message(&self) -> Option<&str>;
+    fn get_detailed_message(&self) -> Option<&str>;
+    fn get_serializations(&self) -> &[&str];
+
+    /// The variant's `///` doc comments, with one line per comment. The derive fills this in;
+    /// hand-written impls return `None` unless they override it.
+    fn get_documentation(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The message for `lang`, taken from `#[strum(message_locale(lang="..."))]`. Falls back to
+    /// `get_message()` when there's no message for that language.
+    fn get_message_lang(&self, lang: &str) -> Option<&str> {
+        let _ = lang;
+        self.get_message()
+    }
+
+    /// The translation key from `#[strum(message_key="...")]`.
+    fn get_message_key(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Looks up the variant's `message_key` in `translations`, falling back to
+    /// `get_message_owned()` when the variant has no key or the key isn't translated.
+    #[cfg(feature = "i18n")]
+    fn resolve_message(&self, translations: &dyn Translations) -> Option<alloc::string::String> {
+        self.get_message_key()
+            .and_then(|key| translations.translate(key))
+            .or_else(|| self.get_message_owned())
+    }
+
+    /// The message with any `{field}` placeholders filled in from the variant's data. Messages
+    /// without placeholders are returned as they are.
+    #[cfg(feature = "alloc")]
+    fn get_message_owned(&self) -> Option<alloc::string::String> {
+        self.get_message().map(alloc::string::String::from)
+    }
+}
+
+/// A source of translated messages, such as a fluent bundle or a gettext catalog, used by
+/// `EnumMessage::resolve_message`. It's implemented for closures, so a catalog can be plugged in
+/// without a wrapper type.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use std::collections::HashMap;
+/// use strum::EnumMessage;
+///
+/// #[derive(EnumMessage)]
+/// enum Menu {
+///     #[strum(message="Open", message_key="menu-open")]
+///     Open,
+///     #[strum(message="Close", message_key="menu-close")]
+///     Close,
+/// }
+///
+/// fn translate() {
+///     let mut catalog = HashMap::new();
+///     catalog.insert("menu-open", "Öffnen");
+///     let translations = |key: &str| catalog.get(key).map(|msg| msg.to_string());
+///
+///     assert_eq!("Öffnen", Menu::Open.resolve_message(&translations).unwrap());
+///     assert_eq!("Close", Menu::Close.resolve_message(&translations).unwrap());
+/// }
+/// # fn main() { translate(); }
+/// ```
+#[cfg(feature = "i18n")]
+pub trait Translations {
+    fn translate(&self, key: &str) -> Option<alloc::string::String>;
+}
+
+#[cfg(feature = "i18n")]
+impl<F> Translations for F
+    where F: Fn(&str) -> Option<alloc::string::String>
+{
+    fn translate(&self, key: &str) -> Option<alloc::string::String> {
+        self(key)
+    }
+}
+
+// Used by all of the generated code instead of `core`, which a `mod core` in the deriving crate
+// would shadow. `::core` isn't an option because 2015 edition crates don't have it in their root.
+#[doc(hidden)]
+pub extern crate core as __core;
+
+// Used by the generated `get_message_owned`, which can't name the `alloc` crate itself.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use alloc::string::String as __String;
+
+/// Expands to the items it's given when strum has the `alloc` feature, so generated code can
+/// include impls that need an allocator without failing to build on targets that don't have one.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_alloc {
+    ($($item:tt)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_alloc {
+    ($($item:tt)*) => {};
+}
+
+/// The strings of an enum with `#[strum(shared_table)]`, which the derives read by index instead
+/// of spelling them out. Implemented by `EnumVariantNames`.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`#[strum(shared_table)]` needs `{Self}` to derive `EnumVariantNames`",
+    label = "the strings of `{Self}` are kept by `EnumVariantNames`"
+)]
+pub trait __SharedTable {
+    const STRINGS: &'static [&'static str];
+}
+
+/// Compares the input of `FromStr` with a serialization for `normalize_separators`, treating `-`,
+/// `_` and spaces as the same character.
+#[doc(hidden)]
+pub fn __eq_ignore_separators(input: &str, serialization: &str, ignore_case: bool) -> bool {
+    let is_separator = |b: u8| b == b'-' || b == b'_' || b == b' ';
+    input.len() == serialization.len() &&
+    input.bytes().zip(serialization.bytes()).all(|(a, b)| {
+        a == b || (is_separator(a) && is_separator(b)) || (ignore_case && a.eq_ignore_ascii_case(&b))
+    })
+}
+
+// Used by the generated `postgres_types::ToSql`, whose output buffer is a `bytes::BytesMut`.
+#[cfg(feature = "postgres-types")]
+#[doc(hidden)]
+pub extern crate bytes as __bytes;
+
+// Used by the generated `TryFrom<&OsStr>`.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use std::ffi::OsStr as __OsStr;
+
+/// Like `__if_alloc`, but for impls that need the `std` feature.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_std {
+    ($($item:tt)*) => { $($item)* };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_std {
+    ($($item:tt)*) => {};
+}
+
+// Used by the generated `JsonSchema::schema_name`.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub use alloc::borrow::Cow as __Cow;
+
+/// A trait for capturing the number of variants in an Enum. This can be
+/// autoimplemented by deriving `EnumCount`. The derive also adds an inherent
+/// `COUNT` constant, so the trait doesn't need to be in scope to use it.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use strum::EnumCount;
+///
+/// #[derive(EnumCount)]
+/// enum Week {
+///     Sunday,
+///     Monday,
+///     Tuesday,
+///     Wednesday,
+///     Thursday,
+///     Friday,
+///     Saturday,
+/// }
+///
+/// fn count<E: EnumCount>() -> usize {
+///     E::COUNT
+/// }
+///
+/// fn main() {
+///     let hours_worked = [0.0f32; Week::COUNT];
+///     assert_eq!(7, hours_worked.len());
+///     assert_eq!(7, count::<Week>());
+/// }
+/// ```
+pub trait EnumCount {
+    const COUNT: usize;
+}
+
+/// A trait for getting the position of a variant in the declaration. This can be autoimplemented
+/// by deriving `EnumIndex`, which also lets a `[T; COUNT]` array be indexed by the enum directly,
+/// so per-variant tables don't depend on `as usize` casts that break when variants are reordered.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// #[derive(Clone, Copy, EnumCount, EnumIndex)]
+/// enum Outcome {
+///     Passed,
+///     Failed,
+///     Skipped,
+/// }
+///
+/// fn main() {
+///     let mut totals = [0u32; Outcome::COUNT];
+///     for outcome in &[Outcome::Passed, Outcome::Skipped, Outcome::Passed] {
+///         totals[*outcome] += 1;
+///     }
+///     assert_eq!(2, totals[Outcome::Passed]);
+///     assert_eq!(0, totals[Outcome::Failed]);
+///     assert_eq!(2, Outcome::Skipped.to_index());
+/// }
+/// ```
+pub trait EnumIndex {
+    fn to_index(&self) -> usize;
+}
+
+/// A trait for getting the fieldless discriminant of a value, for code that's generic over enums.
+/// This can be autoimplemented by deriving `EnumDiscriminants`, in which case `Discriminant` is the
+/// generated `YourEnumDiscriminants` type.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use std::collections::HashMap;
+/// use std::hash::Hash;
+/// use strum::IntoDiscriminant;
+///
+/// #[derive(EnumDiscriminants)]
+/// enum Event {
+///     Click { x: i32, y: i32 },
+///     Key(char),
+/// }
+///
+/// fn tally<E>(events: &[E]) -> HashMap<E::Discriminant, usize>
+///     where E: IntoDiscriminant, E::Discriminant: Eq + Hash
+/// {
+///     let mut counts = HashMap::new();
+///     for event in events {
+///         *counts.entry(event.discriminant()).or_insert(0) += 1;
+///     }
+///     counts
+/// }
+///
+/// fn main() {
+///     let counts = tally(&[Event::Key('a'), Event::Click { x: 1, y: 2 }, Event::Key('b')]);
+///     assert_eq!(2, counts[&EventDiscriminants::Key]);
+///     assert_eq!(1, counts[&EventDiscriminants::Click]);
+/// }
+/// ```
+pub trait IntoDiscriminant {
+    type Discriminant;
+
+    fn discriminant(&self) -> Self::Discriminant;
+}
+
+/// A trait for retrieving the names of each variant in an Enum. This can be
+/// autoimplemented by deriving `EnumVariantNames`. The names are the same ones
+/// that `Display` would print, so `to_string` and `serialize` are respected.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use strum::VariantNames;
+///
+/// #[derive(EnumVariantNames)]
+/// enum Color {
+///     Red,
+///     #[strum(serialize="b")]
+///     Blue,
+///     #[strum(to_string="yellow", serialize="y")]
+///     Yellow,
+/// }
+///
+/// fn help_text<E: VariantNames>() -> String {
+///     format!("expected one of: {}", E::VARIANTS.join(", "))
+/// }
+///
+/// fn main() {
+///     assert_eq!(["Red", "b", "yellow"], Color::VARIANTS);
+///     assert_eq!("expected one of: Red, b, yellow", help_text::<Color>());
+/// }
+/// ```
+pub trait VariantNames {
+    const VARIANTS: &'static [&'static str];
+}
+
+/// A trait for looking up the properties attached to each variant with
+/// `#[strum(props(Key="value"))]`. This can be autoimplemented by deriving `EnumProperty`.
+/// String, integer and bool values are supported, and each is only returned by the
+/// matching getter. `get_all_props` lists all of them.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use strum::EnumProperty;
+///
+/// #[derive(EnumProperty)]
+/// enum Class {
+///     #[strum(props(Teacher="Ms.Frizzle", Room=201, Online=false))]
+///     History,
+///     #[strum(props(Teacher="Mr.Smith"))]
+///     Math,
+/// }
+///
+/// fn main() {
+///     assert_eq!(Some("Ms.Frizzle"), Class::History.get_str("Teacher"));
+///     assert_eq!(Some(201), Class::History.get_int("Room"));
+///     assert_eq!(Some(false), Class::History.get_bool("Online"));
+///     assert_eq!(None, Class::Math.get_int("Room"));
+/// }
+/// ```
+pub trait EnumProperty {
+    fn get_str(&self, prop: &str) -> Option<&'static str>;
+
+    fn get_int(&self, prop: &str) -> Option<i64> {
+        let _ = prop;
+        None
+    }
+
+    fn get_bool(&self, prop: &str) -> Option<bool> {
+        let _ = prop;
+        None
+    }
+
+    /// Every property of the variant in the order they were declared. Integer and bool values
+    /// are converted to strings.
+    fn get_all_props(&self) -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+}
+
+/// A trait for borrowing every variant of an Enum as a static slice. This can be
+/// autoimplemented by deriving `VariantArray` on an enum that only has unit variants.
+/// Unlike `IntoEnumIterator`, no values are constructed and no `Default` bounds are
+/// needed, so `VARIANTS` can be used from `static` and `const` initializers.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate strum;
+/// # #[macro_use] extern crate strum_macros;
+/// use strum::VariantArray;
+///
+/// #[derive(Debug, PartialEq, Eq, Clone, Copy, VariantArray)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// static RED_SUITS: &[Suit] = &[Suit::VARIANTS[1], Suit::VARIANTS[2]];
+///
+/// fn main() {
+///     assert_eq!(4, Suit::VARIANTS.len());
+///     assert_eq!([Suit::Diamonds, Suit::Hearts], RED_SUITS);
+/// }
+/// ```
+pub trait VariantArray: Sized + 'static {
+    const VARIANTS: &'static [Self];
 }
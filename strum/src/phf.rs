@@ -0,0 +1,84 @@
+//! Support code for `#[strum(use_phf)]`. The tables are built at compile time by
+//! `strum_macros`, so `hash`, `split` and `displace` must stay in sync with the
+//! copies in `strum_macros/src/phf.rs`.
+
+/// A static perfect hash map from strings to indices.
+pub struct Map {
+    pub key: u64,
+    pub disps: &'static [(u32, u32)],
+    pub entries: &'static [(&'static str, usize)],
+}
+
+impl Map {
+    /// Looks up `s`, comparing case sensitively.
+    pub fn get(&self, s: &str) -> Option<usize> {
+        self.find(s, false).filter(|entry| entry.0 == s).map(|entry| entry.1)
+    }
+
+    /// Looks up `s` ignoring ASCII case. The table must have been built from lowercase keys.
+    pub fn get_ignore_ascii_case(&self, s: &str) -> Option<usize> {
+        self.find(s, true).filter(|entry| entry.0.eq_ignore_ascii_case(s)).map(|entry| entry.1)
+    }
+
+    fn find(&self, s: &str, lowercase: bool) -> Option<&(&'static str, usize)> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let (g, f1, f2) = split(hash(self.key, s.as_bytes(), lowercase));
+        let (d1, d2) = self.disps[g as usize % self.disps.len()];
+        self.entries.get(displace(f1, f2, d1, d2) as usize % self.entries.len())
+    }
+}
+
+fn hash(key: u64, bytes: &[u8], lowercase: bool) -> u64 {
+    let mut h = 0xcbf2_9ce4_8422_2325 ^ key;
+    for &b in bytes {
+        let b = if lowercase { b.to_ascii_lowercase() } else { b };
+        h ^= u64::from(b);
+        h = h.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^ (h >> 33)
+}
+
+fn split(h: u64) -> (u32, u32, u32) {
+    ((h >> 42) as u32, ((h >> 21) & 0x1f_ffff) as u32, (h & 0x1f_ffff) as u32)
+}
+
+fn displace(f1: u32, f2: u32, d1: u32, d2: u32) -> u32 {
+    d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The same vectors are checked in `strum_macros/src/phf.rs`, so the two copies of `hash` can't
+    /// drift apart without a test failing.
+    const HASHES: &[(u64, &str, bool, u64)] = &[
+        (0, "", false, 0xefd0_1f60_ba99_2926),
+        (0, "Red", false, 0x41e6_aa4b_52b1_d648),
+        (0, "Red", true, 0x9ec9_ba33_30a6_4fb2),
+        (7, "ascii_case_insensitive", false, 0xf70f_1732_9085_bae7),
+        (u64::MAX, "\u{e9}t\u{e9}", true, 0x54fc_10ce_5c2e_9144),
+    ];
+
+    #[test]
+    fn hash_vectors() {
+        for &(key, s, lowercase, expected) in HASHES {
+            assert_eq!(expected, hash(key, s.as_bytes(), lowercase), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn split_and_displace() {
+        assert_eq!((0x3f_ffff, 0x1f_ffff, 0x1f_ffff), split(u64::MAX));
+        assert_eq!(10, displace(1, 2, 3, 5));
+        assert_eq!(1, displace(0, 2, u32::MAX, u32::MAX));
+    }
+}
@@ -0,0 +1,36 @@
+//! Support code for `#[strum(suggest)]`.
+
+use alloc::vec::Vec;
+
+/// Finds the candidate closest to `input`, as long as it's close enough to plausibly be a typo.
+pub fn closest(input: &str, candidates: &[&'static str]) -> Option<&'static str> {
+    let input = input.chars().flat_map(char::to_lowercase).collect::<Vec<_>>();
+    let max_distance = core::cmp::max(1, input.len() / 3);
+
+    candidates.iter()
+        .map(|&candidate| {
+            let candidate_chars = candidate.chars().flat_map(char::to_lowercase).collect::<Vec<_>>();
+            (edit_distance(&input, &candidate_chars), candidate)
+        })
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut prev = (0..b.len() + 1).collect::<Vec<_>>();
+    let mut curr = Vec::with_capacity(b.len() + 1);
+    for (i, ca) in a.iter().enumerate() {
+        curr.clear();
+        curr.push(i + 1);
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + if ca == cb { 0 } else { 1 };
+            curr.push(core::cmp::min(substitution, core::cmp::min(prev[j + 1], curr[j]) + 1));
+        }
+
+        core::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
@@ -0,0 +1,56 @@
+//! `EnumArbitrary`, which implements `arbitrary::Arbitrary` so fuzz targets can build a variant
+//! and its fields straight from the fuzzer's bytes.
+
+use quote;
+use syn;
+
+use super::{bound_generic_fields, is_disabled};
+
+pub fn arbitrary_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumArbitrary only works on Enums"),
+    };
+
+    let bound = "arbitrary::Arbitrary<'arbitrary>";
+    let mut generics = bound_generic_fields(ast, "EnumArbitrary", bound);
+    generics.lifetimes.insert(0, syn::LifetimeDef::new("'arbitrary"));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    let mut arms = Vec::new();
+    let enabled = variants.iter().filter(|variant| !is_disabled(&variant.attrs, "EnumArbitrary"));
+    for (idx, variant) in enabled.enumerate() {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let fields = variant.data.fields();
+        let values = fields.iter().map(|_| quote!{ arbitrary::Arbitrary::arbitrary(u)? });
+        let value = match variant.data {
+            Unit => quote!{ #name::#ident },
+            Tuple(..) => quote!{ #name::#ident(#(#values),*) },
+            Struct(..) => {
+                let names = fields.iter().map(|field| field.ident.as_ref().unwrap());
+                quote!{ #name::#ident { #(#names: #values),* } }
+            }
+        };
+        arms.push(quote!{ #idx => #value });
+    }
+
+    if arms.is_empty() {
+        panic!("EnumArbitrary needs at least one variant that isn't disabled");
+    }
+
+    let last = arms.len() - 1;
+    arms.push(quote!{ _ => unreachable!() });
+
+    quote!{
+        impl #impl_generics arbitrary::Arbitrary<'arbitrary> for #name #ty_generics #where_clause {
+            fn arbitrary(u: &mut arbitrary::Unstructured<'arbitrary>) -> arbitrary::Result<Self> {
+                Ok(match u.int_in_range(0..=#last)? {
+                    #(#arms),*
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+/// The casing conventions that can be requested with `#[strum(serialize_all = "...")]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaseStyle {
+    Snake,
+    Kebab,
+    ShoutySnake,
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    ShoutyKebab,
+}
+
+const VALID_CASE_STYLES: &str = "\"snake_case\", \"kebab-case\", \"SCREAMING_SNAKE_CASE\", \
+                                 \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \
+                                 \"SCREAMING-KEBAB-CASE\"";
+
+impl CaseStyle {
+    pub fn from_attr(style: &str) -> CaseStyle {
+        match style {
+            "snake_case" => CaseStyle::Snake,
+            "kebab-case" => CaseStyle::Kebab,
+            "SCREAMING_SNAKE_CASE" => CaseStyle::ShoutySnake,
+            "lowercase" => CaseStyle::Lower,
+            "UPPERCASE" => CaseStyle::Upper,
+            "PascalCase" => CaseStyle::Pascal,
+            "camelCase" => CaseStyle::Camel,
+            "SCREAMING-KEBAB-CASE" => CaseStyle::ShoutyKebab,
+            _ => panic!("Unexpected case style for serialize_all: `{}`. Valid values are: {}",
+                        style,
+                        VALID_CASE_STYLES),
+        }
+    }
+
+    /// Converts a `PascalCase` variant identifier into this style.
+    pub fn apply(self, ident: &str) -> String {
+        let words = split_words(ident);
+        match self {
+            CaseStyle::Snake => words.join("_"),
+            CaseStyle::Kebab => words.join("-"),
+            CaseStyle::ShoutySnake => words.join("_").to_uppercase(),
+            CaseStyle::Lower => words.concat(),
+            CaseStyle::Upper => words.concat().to_uppercase(),
+            CaseStyle::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            CaseStyle::Camel => {
+                let mut camel = words.first().cloned().unwrap_or_default();
+                camel.extend(words.iter().skip(1).map(|w| capitalize(w)));
+                camel
+            }
+            CaseStyle::ShoutyKebab => words.join("-").to_uppercase(),
+        }
+    }
+
+    /// Converts a variant identifier the way serde's `rename_all` does. Unlike `apply`, every
+    /// capital starts a new word, so `HTTPServer` becomes `h_t_t_p_server` in `snake_case`.
+    pub fn apply_serde(self, ident: &str) -> String {
+        let snake = || {
+            let mut snake = String::new();
+            for (i, c) in ident.chars().enumerate() {
+                if i > 0 && c.is_uppercase() {
+                    snake.push('_');
+                }
+                snake.push(c.to_ascii_lowercase());
+            }
+            snake
+        };
+
+        match self {
+            CaseStyle::Snake => snake(),
+            CaseStyle::Kebab => snake().replace('_', "-"),
+            CaseStyle::ShoutySnake => snake().to_ascii_uppercase(),
+            CaseStyle::ShoutyKebab => snake().replace('_', "-").to_ascii_uppercase(),
+            CaseStyle::Lower => ident.to_ascii_lowercase(),
+            CaseStyle::Upper => ident.to_ascii_uppercase(),
+            CaseStyle::Pascal => ident.into(),
+            CaseStyle::Camel => ident[..1].to_ascii_lowercase() + &ident[1..],
+        }
+    }
+}
+
+/// Splits an identifier into lowercase words. Both `_` and changes in case separate words, and
+/// runs of capitals are treated as a single word, so `HTTPServer` becomes `["http", "server"]`.
+fn split_words(ident: &str) -> Vec<String> {
+    let chars = ident.chars().collect::<Vec<_>>();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' {
+            if !word.is_empty() {
+                words.push(word);
+                word = String::new();
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                words.push(word);
+                word = String::new();
+            }
+        }
+
+        word.extend(c.to_lowercase());
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
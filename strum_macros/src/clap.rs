@@ -0,0 +1,78 @@
+//! `EnumValueEnum`, which implements clap's `ValueEnum` with strum's serializations as the value
+//! names and the `message`s as help text.
+
+use quote;
+use syn;
+
+use super::{get_preferred_name, get_serializations, is_disabled, unique_attr, Naming};
+
+pub fn value_enum_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumValueEnum only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+
+    let mut values = Vec::new();
+    let mut names = Vec::new();
+    let mut arms = Vec::new();
+    for variant in variants {
+        let ident = &variant.ident;
+        if variant.data != syn::VariantData::Unit {
+            panic!("EnumValueEnum only works on Enums with unit variants");
+        }
+
+        if is_disabled(&variant.attrs, "EnumValueEnum") {
+            continue;
+        }
+
+        // The preferred name is what clap shows, and every other serialization is an alias.
+        let output = get_preferred_name(ident, &variant.attrs, naming);
+        let aliases = get_serializations(ident, &variant.attrs, naming)
+            .into_iter()
+            .filter(|s| *s != output)
+            .collect::<Vec<_>>();
+        let aliases = if aliases.is_empty() {
+            quote!{}
+        } else {
+            quote!{ .aliases([#(#aliases),*]) }
+        };
+        let help = match unique_attr(&variant.attrs, "strum", "message") {
+            Some(msg) => quote!{ .help(#msg) },
+            None => quote!{},
+        };
+
+        arms.push(quote!{
+            #name::#ident => Some(clap::builder::PossibleValue::new(#output)
+                #help
+                #aliases)
+        });
+        values.push(quote!{ #name::#ident });
+        names.push(output);
+    }
+
+    if arms.len() < variants.len() {
+        arms.push(quote!{ _ => None });
+    }
+
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// The value names clap accepts, in declaration order.
+            pub const VALUE_NAMES: &'static [&'static str] = &[#(#names),*];
+        }
+
+        impl #impl_generics clap::ValueEnum for #name #ty_generics #where_clause {
+            fn value_variants<'a>() -> &'a [Self] {
+                &[#(#values),*]
+            }
+
+            fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+                match *self {
+                    #(#arms),*
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! `EnumCStr`, which converts variants to and from the C strings of their names.
+
+use quote;
+use syn;
+
+use super::{default_fields, get_preferred_name, get_serializations, is_disabled, unraw, CaseMode,
+            Naming};
+
+pub fn cstr_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let type_name = unraw(name.as_ref());
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumCStr only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+    let case_mode = CaseMode::from_attrs(&ast.attrs);
+
+    let mut as_cstr_arms = Vec::new();
+    let mut from_cstr_arms = Vec::new();
+    for variant in variants {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+
+        if is_disabled(&variant.attrs, "EnumCStr") {
+            continue;
+        }
+
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+
+        // A NUL in the middle would cut the C string short, so it's rejected up front.
+        let output = get_preferred_name(ident, &variant.attrs, naming);
+        if output.contains('\0') {
+            panic!("{}::{} can't be converted to a C string because \"{}\" contains a NUL",
+                   name, ident, output.escape_debug());
+        }
+        let with_nul = format!("{}\0", output);
+        let with_nul = quote::ByteStr(&with_nul);
+        as_cstr_arms.push(quote!{
+            &#name::#ident #params => {
+                const NAME: &strum::__core::ffi::CStr =
+                    match strum::__core::ffi::CStr::from_bytes_with_nul(#with_nul) {
+                        Ok(name) => name,
+                        Err(_) => panic!("invalid C string"),
+                    };
+                NAME
+            }
+        });
+
+        let serializations = get_serializations(ident, &variant.attrs, naming);
+        let bytes = serializations.iter().map(|s| quote::ByteStr(s)).collect::<Vec<_>>();
+        let fields = default_fields(variant);
+        let variant_case_insensitive = case_mode.ignores_case(&variant.attrs);
+        from_cstr_arms.push(if variant_case_insensitive && case_mode == CaseMode::Unicode {
+            // Folding works on `str`, so a C string that isn't UTF-8 can't match.
+            quote!{
+                b if strum::__core::str::from_utf8(b).map_or(false, |s| {
+                    #(strum::unicode::eq_folded(s, #serializations))||*
+                }) => Ok(#name::#ident #fields)
+            }
+        } else if variant_case_insensitive {
+            quote!{ b if #(b.eq_ignore_ascii_case(#bytes))||* => Ok(#name::#ident #fields) }
+        } else {
+            quote!{ #(#bytes)|* => Ok(#name::#ident #fields) }
+        });
+    }
+
+    if as_cstr_arms.len() < variants.len() {
+        as_cstr_arms.push(quote!{ _ => panic!("as_cstr() called on disabled variant.") });
+    }
+
+    let mut ref_generics = ast.generics.clone();
+    ref_generics.lifetimes.insert(0, syn::LifetimeDef::new("'_strum"));
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// The name of the variant as a C string, without allocating.
+            pub const fn as_cstr(&self) -> &'static strum::__core::ffi::CStr {
+                match self {
+                    #(#as_cstr_arms),*
+                }
+            }
+        }
+
+        impl #ref_impl_generics strum::__core::convert::TryFrom<&'_strum strum::__core::ffi::CStr>
+            for #name #ty_generics #where_clause
+        {
+            type Error = strum::ParseError;
+            fn try_from(s: &'_strum strum::__core::ffi::CStr) -> Result<Self, strum::ParseError> {
+                match s.to_bytes() {
+                    #(#from_cstr_arms,)*
+                    _ => Err(strum::ParseError::VariantNotFound { type_name: #type_name }),
+                }
+            }
+        }
+    }
+}
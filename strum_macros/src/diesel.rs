@@ -0,0 +1,54 @@
+//! `EnumDieselText`, which implements diesel's `ToSql` and `FromSql` for the `Text` SQL type by
+//! way of the strings `IntoStaticStr` and `EnumString` use.
+
+use quote;
+use syn;
+
+use super::{extend_generics, stored_str_arms};
+
+pub fn diesel_text_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let self_ty = quote!{ #name #ty_generics };
+
+    let store = |value: quote::Tokens, _| value;
+    let disabled = quote!{ return Err("to_sql() called on disabled variant.".into()) };
+    let arms = stored_str_arms(ast, "EnumDieselText", &store, disabled);
+
+    let to_sql_predicates = "DB: diesel::backend::Backend, \
+                             str: diesel::serialize::ToSql<diesel::sql_types::Text, DB>";
+    let to_sql_generics = extend_generics(&ast.generics, &[], &["DB"], to_sql_predicates);
+    let (to_sql_impl_generics, _, to_sql_where_clause) = to_sql_generics.split_for_impl();
+
+    let from_sql_predicates = format!("DB: diesel::backend::Backend, \
+                                       strum::__String: \
+                                       diesel::deserialize::FromSql<diesel::sql_types::Text, DB>, \
+                                       <{} as strum::__core::str::FromStr>::Err: \
+                                       std::error::Error + Send + Sync + 'static",
+                                      self_ty.as_str());
+    let from_sql_generics = extend_generics(&ast.generics, &[], &["DB"], &from_sql_predicates);
+    let (from_sql_impl_generics, _, from_sql_where_clause) = from_sql_generics.split_for_impl();
+
+    quote!{
+        impl #to_sql_impl_generics diesel::serialize::ToSql<diesel::sql_types::Text, DB>
+            for #self_ty #to_sql_where_clause {
+            fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, DB>)
+                -> diesel::serialize::Result {
+                let value: &'b str = match *self {
+                    #(#arms),*
+                };
+                <str as diesel::serialize::ToSql<diesel::sql_types::Text, DB>>::to_sql(value, out)
+            }
+        }
+
+        impl #from_sql_impl_generics diesel::deserialize::FromSql<diesel::sql_types::Text, DB>
+            for #self_ty #from_sql_where_clause {
+            fn from_sql(bytes: <DB as diesel::backend::Backend>::RawValue<'_>)
+                -> diesel::deserialize::Result<Self> {
+                let value = <strum::__String as diesel::deserialize::FromSql<
+                    diesel::sql_types::Text, DB>>::from_sql(bytes)?;
+                Ok(strum::__core::str::FromStr::from_str(&value)?)
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! `impl_for_external!`, which mirrors an enum from another crate so strum's derives can be used
+//! on it. The orphan rule stops a crate from implementing `Display` or strum's traits for a type it
+//! doesn't own, so the macro declares a local enum with the same variants instead, along with
+//! conversions between the two.
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+use quote;
+use syn;
+
+/// The pieces of `enum path::to::Name as Local { .. }`.
+struct Declaration {
+    /// Everything before `enum`, i.e. the attributes and visibility.
+    prefix: TokenStream,
+    foreign: String,
+    local: String,
+    body: TokenTree,
+}
+
+fn split_declaration(input: TokenStream) -> Declaration {
+    let mut tokens = input.into_iter();
+    let mut prefix = Vec::new();
+    loop {
+        match tokens.next() {
+            Some(TokenTree::Ident(ref ident)) if ident.to_string() == "enum" => break,
+            Some(token) => prefix.push(token),
+            None => panic!("impl_for_external! expects an enum declaration"),
+        }
+    }
+
+    let mut foreign = String::new();
+    let mut local = None;
+    let mut body = None;
+    for token in tokens.by_ref() {
+        match token {
+            TokenTree::Ident(ref ident) if ident.to_string() == "as" => (),
+            TokenTree::Ident(ref ident) if foreign.is_empty() || foreign.ends_with("::") => {
+                foreign.push_str(&ident.to_string())
+            }
+            TokenTree::Ident(ref ident) if local.is_none() => local = Some(ident.to_string()),
+            TokenTree::Punct(ref punct) if punct.as_char() == ':' && local.is_none() => {
+                foreign.push(':')
+            }
+            TokenTree::Punct(ref punct) if punct.as_char() == '<' => {
+                panic!("impl_for_external! doesn't support generic enums")
+            }
+            TokenTree::Group(ref group) if group.delimiter() == Delimiter::Brace => {
+                body = Some(token.clone());
+                break;
+            }
+            other => panic!("impl_for_external! didn't expect `{}` in the enum's name", other),
+        }
+    }
+
+    if tokens.next().is_some() {
+        panic!("impl_for_external! expects a single enum declaration");
+    }
+
+    let body = body.unwrap_or_else(|| panic!("impl_for_external! expects the enum's variants"));
+    // Without `as`, the local enum is named after the last segment of the path.
+    let local = local.unwrap_or_else(|| foreign.rsplit("::").next().unwrap().to_owned());
+    if local == foreign {
+        panic!("impl_for_external! needs the path to {} or a local name given with `as`", local);
+    }
+    Declaration {
+        prefix: prefix.into_iter().collect(),
+        foreign,
+        local,
+        body,
+    }
+}
+
+pub fn parse(input: TokenStream) -> (syn::DeriveInput, syn::Path) {
+    let declaration = split_declaration(super::hide_raw_idents(input));
+    let source = format!("{} enum {} {}", declaration.prefix, declaration.local, declaration.body);
+    let ast = syn::parse_derive_input(&source).unwrap();
+    let foreign = syn::parse_path(&declaration.foreign)
+        .unwrap_or_else(|_| panic!("`{}` isn't a valid path", declaration.foreign));
+    (ast, foreign)
+}
+
+pub fn external_inner(ast: &syn::DeriveInput, foreign: &syn::Path) -> quote::Tokens {
+    let name = &ast.ident;
+    if !ast.generics.lifetimes.is_empty() || !ast.generics.ty_params.is_empty() {
+        panic!("impl_for_external! doesn't support generic enums");
+    }
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("impl_for_external! only works on Enums"),
+    };
+
+    let mut local_pats = Vec::new();
+    let mut foreign_pats = Vec::new();
+    for variant in variants {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let bindings = match variant.data {
+            Unit => quote!{},
+            Tuple(ref fields) => {
+                let fields = (0..fields.len())
+                    .map(|idx| quote::Ident::from(format!("field_{}", idx)));
+                quote!{ (#(#fields),*) }
+            }
+            Struct(ref fields) => {
+                let fields = fields.iter().map(|field| field.ident.as_ref().unwrap());
+                quote!{ { #(#fields),* } }
+            }
+        };
+        local_pats.push(quote!{ #name::#ident #bindings });
+        foreign_pats.push(quote!{ #foreign::#ident #bindings });
+    }
+
+    // The foreign enum can gain variants if it's non_exhaustive, so it can only be converted
+    // fallibly, handing back the value the local enum has no variant for.
+    let non_exhaustive = ast.attrs.iter().any(|attr| attr.name() == "non_exhaustive");
+    let (locals, foreigns) = (local_pats.iter(), foreign_pats.iter());
+    let from_impl = if non_exhaustive {
+        quote!{
+            impl strum::__core::convert::TryFrom<#foreign> for #name {
+                type Error = #foreign;
+                fn try_from(value: #foreign) -> Result<Self, #foreign> {
+                    #[allow(unreachable_patterns)]
+                    match value {
+                        #(#foreigns => Ok(#locals),)*
+                        value => Err(value),
+                    }
+                }
+            }
+        }
+    } else {
+        quote!{
+            impl strum::__core::convert::From<#foreign> for #name {
+                fn from(value: #foreign) -> Self {
+                    match value {
+                        #(#foreigns => #locals,)*
+                    }
+                }
+            }
+        }
+    };
+
+    let (locals, foreigns) = (local_pats.iter(), foreign_pats.iter());
+    quote!{
+        #ast
+
+        impl strum::__core::convert::From<#name> for #foreign {
+            fn from(value: #name) -> Self {
+                match value {
+                    #(#locals => #foreigns,)*
+                }
+            }
+        }
+
+        #from_impl
+    }
+}
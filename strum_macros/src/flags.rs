@@ -0,0 +1,271 @@
+//! `EnumFlags`, which generates a companion bitflags type with one bit per variant.
+
+use quote;
+use syn;
+
+use case_style::CaseStyle;
+use super::{unique_attr, unraw};
+
+pub fn flags_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumFlags only works on Enums"),
+    };
+
+    if !ast.generics.lifetimes.is_empty() || !ast.generics.ty_params.is_empty() {
+        panic!("EnumFlags doesn't support generic Enums");
+    }
+
+    let flags_name = match unique_attr(&ast.attrs, "strum", "flags_name") {
+        Some(flags_name) => quote::Ident::from(flags_name),
+        None => quote::Ident::from(&*format!("{}Flags", name)),
+    };
+    let flags_name_str = flags_name.as_ref();
+
+    // The smallest unsigned integer with a bit for every variant, unless one is asked for.
+    let count = variants.len();
+    let repr = match unique_attr(&ast.attrs, "strum", "flags_repr") {
+        Some(repr) => {
+            let bits = match repr {
+                "u8" => 8,
+                "u16" => 16,
+                "u32" => 32,
+                "u64" => 64,
+                "u128" => 128,
+                _ => panic!("flags_repr must be one of u8, u16, u32, u64 or u128"),
+            };
+            if count > bits {
+                panic!("{} has {} variants, which don't fit in a {}", name, count, repr);
+            }
+            repr
+        }
+        None => match count {
+            0..=8 => "u8",
+            9..=16 => "u16",
+            17..=32 => "u32",
+            33..=64 => "u64",
+            65..=128 => "u128",
+            _ => panic!("EnumFlags supports at most 128 variants, {} has {}", name, count),
+        },
+    };
+    let repr = quote::Ident::from(repr);
+
+    let mut consts = Vec::new();
+    let mut from_arms = Vec::new();
+    let mut from_index = Vec::new();
+    let mut debug_names = Vec::new();
+    for (idx, variant) in variants.iter().enumerate() {
+        let ident = &variant.ident;
+        if variant.data != syn::VariantData::Unit {
+            panic!("EnumFlags only works on Enums with unit variants");
+        }
+
+        let const_name = quote::Ident::from(CaseStyle::ShoutySnake.apply(unraw(ident.as_ref())));
+        let const_name_str = const_name.as_ref();
+        let bit = quote::Ident::from(format!("{:#x}", 1u128 << idx));
+        consts.push(quote!{ pub const #const_name: #flags_name = #flags_name(#bit); });
+        from_arms.push(quote!{ #name::#ident => #flags_name::#const_name });
+        from_index.push(quote!{ #idx => #name::#ident });
+        debug_names.push(quote!{ (#const_name_str, #bit) });
+    }
+    from_index.push(quote!{ _ => unreachable!() });
+
+    let all_bits = (0..count).fold(0u128, |all, idx| all | (1 << idx));
+    let all_bits = quote::Ident::from(format!("{:#x}", all_bits));
+
+    let flags = quote!{
+        /// A set of bit flags, one for each variant of the enum.
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #vis struct #flags_name(#repr);
+
+        impl #flags_name {
+            #(#consts)*
+
+            /// No flags set.
+            pub const fn empty() -> #flags_name {
+                #flags_name(0)
+            }
+
+            /// Every flag set.
+            pub const fn all() -> #flags_name {
+                #flags_name(#all_bits)
+            }
+
+            /// The underlying integer.
+            pub const fn bits(&self) -> #repr {
+                self.0
+            }
+
+            /// Converts from an integer, or returns `None` if it has bits that don't belong to
+            /// a variant.
+            pub const fn from_bits(bits: #repr) -> Option<#flags_name> {
+                if bits & !#all_bits == 0 {
+                    Some(#flags_name(bits))
+                } else {
+                    None
+                }
+            }
+
+            /// Converts from an integer, dropping the bits that don't belong to a variant.
+            pub const fn from_bits_truncate(bits: #repr) -> #flags_name {
+                #flags_name(bits & #all_bits)
+            }
+        }
+    };
+
+    let queries = quote!{
+        impl #flags_name {
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            pub const fn is_all(&self) -> bool {
+                self.0 == #all_bits
+            }
+
+            /// Whether every flag in `other` is also set in `self`.
+            pub const fn contains(&self, other: #flags_name) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Whether any flag in `other` is also set in `self`.
+            pub const fn intersects(&self, other: #flags_name) -> bool {
+                self.0 & other.0 != 0
+            }
+
+            pub fn insert(&mut self, other: #flags_name) {
+                self.0 |= other.0;
+            }
+
+            pub fn remove(&mut self, other: #flags_name) {
+                self.0 &= !other.0;
+            }
+
+            pub fn toggle(&mut self, other: #flags_name) {
+                self.0 ^= other.0;
+            }
+
+            /// The variants whose flags are set, in declaration order.
+            pub fn iter(&self) -> impl Iterator<Item = #name> {
+                let bits = self.0;
+                (0..#count)
+                    .filter(move |idx| bits & (1 << idx) != 0)
+                    .map(|idx| match idx {
+                        #(#from_index),*
+                    })
+            }
+        }
+    };
+
+    let conversions = quote!{
+        impl From<#name> for #flags_name {
+            fn from(value: #name) -> #flags_name {
+                match value {
+                    #(#from_arms),*
+                }
+            }
+        }
+
+        impl From<#flags_name> for #repr {
+            fn from(flags: #flags_name) -> #repr {
+                flags.0
+            }
+        }
+
+        impl strum::__core::iter::FromIterator<#name> for #flags_name {
+            fn from_iter<I: IntoIterator<Item = #name>>(iter: I) -> #flags_name {
+                iter.into_iter().fold(#flags_name::empty(), |flags, value| {
+                    flags | #flags_name::from(value)
+                })
+            }
+        }
+
+        impl strum::__core::fmt::Debug for #flags_name {
+            fn fmt(&self, f: &mut strum::__core::fmt::Formatter) -> strum::__core::fmt::Result {
+                f.write_str(#flags_name_str)?;
+                f.write_str("(")?;
+                let mut first = true;
+                for &(name, bit) in &[#(#debug_names),*] {
+                    if self.0 & bit != 0 {
+                        if !first {
+                            f.write_str(" | ")?;
+                        }
+                        first = false;
+                        f.write_str(name)?;
+                    }
+                }
+                f.write_str(")")
+            }
+        }
+    };
+
+    let operators = quote!{
+        impl strum::__core::ops::BitOr for #flags_name {
+            type Output = #flags_name;
+            fn bitor(self, other: #flags_name) -> #flags_name {
+                #flags_name(self.0 | other.0)
+            }
+        }
+
+        impl strum::__core::ops::BitAnd for #flags_name {
+            type Output = #flags_name;
+            fn bitand(self, other: #flags_name) -> #flags_name {
+                #flags_name(self.0 & other.0)
+            }
+        }
+
+        impl strum::__core::ops::BitXor for #flags_name {
+            type Output = #flags_name;
+            fn bitxor(self, other: #flags_name) -> #flags_name {
+                #flags_name(self.0 ^ other.0)
+            }
+        }
+
+        impl strum::__core::ops::Sub for #flags_name {
+            type Output = #flags_name;
+            fn sub(self, other: #flags_name) -> #flags_name {
+                #flags_name(self.0 & !other.0)
+            }
+        }
+
+        impl strum::__core::ops::Not for #flags_name {
+            type Output = #flags_name;
+            fn not(self) -> #flags_name {
+                #flags_name(!self.0 & #all_bits)
+            }
+        }
+
+        impl strum::__core::ops::BitOrAssign for #flags_name {
+            fn bitor_assign(&mut self, other: #flags_name) {
+                self.0 |= other.0;
+            }
+        }
+
+        impl strum::__core::ops::BitAndAssign for #flags_name {
+            fn bitand_assign(&mut self, other: #flags_name) {
+                self.0 &= other.0;
+            }
+        }
+
+        impl strum::__core::ops::BitXorAssign for #flags_name {
+            fn bitxor_assign(&mut self, other: #flags_name) {
+                self.0 ^= other.0;
+            }
+        }
+
+        impl strum::__core::ops::SubAssign for #flags_name {
+            fn sub_assign(&mut self, other: #flags_name) {
+                self.0 &= !other.0;
+            }
+        }
+    };
+
+    quote!{
+        #flags
+        #queries
+        #conversions
+        #operators
+    }
+}
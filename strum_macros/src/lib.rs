@@ -12,50 +12,432 @@ extern crate syn;
 extern crate quote;
 extern crate proc_macro;
 
-use proc_macro::TokenStream;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod case_style;
+#[cfg(feature = "clap")]
+mod clap;
+mod cstr;
+#[cfg(feature = "diesel")]
+mod diesel;
+mod external;
+mod flags;
+mod phf;
+#[cfg(feature = "postgres-types")]
+mod postgres;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(feature = "serde")]
+mod serde;
+mod shared_table;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+mod stable_id;
+mod table;
+mod unicode;
+mod validate;
+
+use case_style::CaseStyle;
+use proc_macro::{Delimiter, Group, Literal, Punct, Spacing, TokenStream, TokenTree};
 use syn::Attribute;
+use std::collections::{HashMap, HashSet};
 use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
 
 #[proc_macro_derive(EnumString,attributes(strum))]
 pub fn from_string(input: TokenStream) -> TokenStream {
-    let s = input.to_string();
-    let ast = syn::parse_derive_input(&s).unwrap();
-
-    let toks = from_string_inner(&ast);
-    debug_print_generated(&ast, &toks);
-    toks.parse().unwrap()
+    expand(input, "EnumString", from_string_inner)
 }
 
 #[proc_macro_derive(EnumIter,attributes(strum))]
 pub fn enum_iter(input: TokenStream) -> TokenStream {
-    let s = input.to_string();
-    let ast = syn::parse_derive_input(&s).unwrap();
+    expand(input, "EnumIter", enum_iter_inner)
+}
 
-    let toks = enum_iter_inner(&ast);
-    debug_print_generated(&ast, &toks);
-    toks.parse().unwrap()
+#[proc_macro_derive(EnumRotate,attributes(strum))]
+pub fn enum_rotate(input: TokenStream) -> TokenStream {
+    expand(input, "EnumRotate", enum_rotate_inner)
 }
 
 #[proc_macro_derive(EnumMessage,attributes(strum))]
 pub fn enum_messages(input: TokenStream) -> TokenStream {
-    let s = input.to_string();
-    let ast = syn::parse_derive_input(&s).unwrap();
+    expand(input, "EnumMessage", enum_message_inner)
+}
+
+#[proc_macro_derive(Display,attributes(strum))]
+pub fn display(input: TokenStream) -> TokenStream {
+    expand(input, "Display", display_inner)
+}
+
+#[proc_macro_derive(EnumCount,attributes(strum))]
+pub fn enum_count(input: TokenStream) -> TokenStream {
+    expand(input, "EnumCount", enum_count_inner)
+}
+
+#[proc_macro_derive(EnumIndex,attributes(strum))]
+pub fn enum_index(input: TokenStream) -> TokenStream {
+    expand(input, "EnumIndex", enum_index_inner)
+}
+
+#[proc_macro_derive(EnumDiscriminants,attributes(strum,strum_discriminants))]
+pub fn enum_discriminants(input: TokenStream) -> TokenStream {
+    expand(input, "EnumDiscriminants", enum_discriminants_inner)
+}
+
+#[proc_macro_derive(FromRepr,attributes(strum))]
+pub fn from_repr(input: TokenStream) -> TokenStream {
+    expand(input, "FromRepr", from_repr_inner)
+}
+
+#[proc_macro_derive(EnumVariantNames,attributes(strum))]
+pub fn variant_names(input: TokenStream) -> TokenStream {
+    expand(input, "EnumVariantNames", variant_names_inner)
+}
+
+#[proc_macro_derive(VariantArray,attributes(strum))]
+pub fn variant_array(input: TokenStream) -> TokenStream {
+    expand(input, "VariantArray", variant_array_inner)
+}
+
+#[proc_macro_derive(IntoStaticStr,attributes(strum))]
+pub fn into_static_str(input: TokenStream) -> TokenStream {
+    expand(input, "IntoStaticStr", into_static_str_inner)
+}
+
+#[proc_macro_derive(EnumIs,attributes(strum))]
+pub fn enum_is(input: TokenStream) -> TokenStream {
+    expand(input, "EnumIs", enum_is_inner)
+}
+
+#[proc_macro_derive(EnumProperty,attributes(strum))]
+pub fn enum_properties(input: TokenStream) -> TokenStream {
+    expand(input, "EnumProperty", enum_properties_inner)
+}
+
+#[proc_macro_derive(EnumSetType,attributes(strum))]
+pub fn enum_set_type(input: TokenStream) -> TokenStream {
+    expand(input, "EnumSetType", enum_set_type_inner)
+}
+
+#[proc_macro_derive(EnumFlags,attributes(strum))]
+pub fn enum_flags(input: TokenStream) -> TokenStream {
+    expand(input, "EnumFlags", flags::flags_inner)
+}
+
+#[proc_macro_derive(EnumCStr,attributes(strum))]
+pub fn enum_cstr(input: TokenStream) -> TokenStream {
+    expand(input, "EnumCStr", cstr::cstr_inner)
+}
+
+#[proc_macro_derive(EnumStableId,attributes(strum))]
+pub fn enum_stable_id(input: TokenStream) -> TokenStream {
+    expand(input, "EnumStableId", stable_id::stable_id_inner)
+}
+
+#[cfg(feature = "serde")]
+#[proc_macro_derive(EnumSerialize,attributes(strum))]
+pub fn enum_serialize(input: TokenStream) -> TokenStream {
+    expand(input, "EnumSerialize", serde::serialize_inner)
+}
+
+#[cfg(feature = "serde")]
+#[proc_macro_derive(EnumDeserialize,attributes(strum))]
+pub fn enum_deserialize(input: TokenStream) -> TokenStream {
+    expand(input, "EnumDeserialize", serde::deserialize_inner)
+}
+
+#[cfg(feature = "clap")]
+#[proc_macro_derive(EnumValueEnum,attributes(strum))]
+pub fn enum_value_enum(input: TokenStream) -> TokenStream {
+    expand(input, "EnumValueEnum", clap::value_enum_inner)
+}
+
+#[cfg(feature = "schemars")]
+#[proc_macro_derive(EnumJsonSchema,attributes(strum))]
+pub fn enum_json_schema(input: TokenStream) -> TokenStream {
+    expand(input, "EnumJsonSchema", schemars::json_schema_inner)
+}
+
+#[cfg(feature = "sqlx")]
+#[proc_macro_derive(EnumSqlxType,attributes(strum))]
+pub fn enum_sqlx_type(input: TokenStream) -> TokenStream {
+    expand(input, "EnumSqlxType", sqlx::sqlx_type_inner)
+}
+
+#[cfg(feature = "diesel")]
+#[proc_macro_derive(EnumDieselText,attributes(strum))]
+pub fn enum_diesel_text(input: TokenStream) -> TokenStream {
+    expand(input, "EnumDieselText", diesel::diesel_text_inner)
+}
+
+#[cfg(feature = "postgres-types")]
+#[proc_macro_derive(EnumPostgres,attributes(strum))]
+pub fn enum_postgres(input: TokenStream) -> TokenStream {
+    expand(input, "EnumPostgres", postgres::postgres_inner)
+}
+
+#[cfg(feature = "rusqlite")]
+#[proc_macro_derive(EnumRusqlite,attributes(strum))]
+pub fn enum_rusqlite(input: TokenStream) -> TokenStream {
+    expand(input, "EnumRusqlite", rusqlite::rusqlite_inner)
+}
+
+#[cfg(feature = "rand")]
+#[proc_macro_derive(EnumRandom,attributes(strum))]
+pub fn enum_random(input: TokenStream) -> TokenStream {
+    expand(input, "EnumRandom", rand::random_inner)
+}
+
+#[cfg(feature = "proptest")]
+#[proc_macro_derive(EnumProptest,attributes(strum))]
+pub fn enum_proptest(input: TokenStream) -> TokenStream {
+    expand(input, "EnumProptest", proptest::arbitrary_inner)
+}
+
+#[cfg(feature = "quickcheck")]
+#[proc_macro_derive(EnumQuickCheck,attributes(strum))]
+pub fn enum_quickcheck(input: TokenStream) -> TokenStream {
+    expand(input, "EnumQuickCheck", quickcheck::arbitrary_inner)
+}
+
+#[cfg(feature = "arbitrary")]
+#[proc_macro_derive(EnumArbitrary,attributes(strum))]
+pub fn enum_arbitrary(input: TokenStream) -> TokenStream {
+    expand(input, "EnumArbitrary", arbitrary::arbitrary_inner)
+}
+
+#[cfg(feature = "rayon")]
+#[proc_macro_derive(EnumParIter,attributes(strum))]
+pub fn enum_par_iter(input: TokenStream) -> TokenStream {
+    expand(input, "EnumParIter", rayon::par_iter_inner)
+}
+
+#[proc_macro]
+pub fn impl_for_external(input: TokenStream) -> TokenStream {
+    let (ast, foreign) = external::parse(input);
+
+    let toks = external::external_inner(&ast, &foreign);
+    let output = restore_raw_idents(toks.as_str().parse().unwrap());
+    debug_print_generated(&ast, "impl_for_external", &output);
+    output
+}
+
+/// Runs a derive: parses the input, checks its `#[strum(..)]` attributes and returns the code
+/// `inner` generates, or the problems with the attributes as errors pointing into the input.
+fn expand(input: TokenStream,
+          derive: &str,
+          inner: fn(&syn::DeriveInput) -> quote::Tokens)
+          -> TokenStream {
+    let spans = validate::Spans::of(&input);
+    let ast = parse_input(input);
+
+    let toks = match validate::checked(&ast, derive, inner) {
+        Ok(toks) => toks,
+        Err(errors) => return validate::compile_errors(&errors, &spans),
+    };
+    let output = restore_raw_idents(toks.as_str().parse().unwrap());
+    debug_print_generated(&ast, derive, &output);
+    output
+}
+
+/// syn 0.11 predates raw identifiers, so `r#type` is parsed as `__strum_raw_type` and turned back
+/// into `r#type` in the generated code. Names derived from it go through `unraw` instead.
+const RAW_PREFIX: &str = "__strum_raw_";
+
+fn parse_input(input: TokenStream) -> syn::DeriveInput {
+    let input = quote_discriminants_vis(drop_foreign_attrs(hide_raw_idents(input)));
+    syn::parse_derive_input(&input.to_string()).unwrap()
+}
+
+/// syn 0.11 only reads attributes whose values are literals, so another derive's attribute like
+/// `#[diesel(sql_type = Text)]` would stop the whole input from parsing. Strum never reads those,
+/// so they're left out. Its own attributes are kept either way, so mistakes in them still fail.
+fn drop_foreign_attrs(input: TokenStream) -> TokenStream {
+    let mut output = Vec::new();
+    let mut tokens = input.into_iter().peekable();
+    while let Some(tree) = tokens.next() {
+        match tree {
+            TokenTree::Punct(ref punct) if punct.as_char() == '#' => {
+                if let Some(TokenTree::Group(group)) = tokens.peek() {
+                    let ours = match group.stream().into_iter().next() {
+                        Some(TokenTree::Ident(ref ident)) => ident.to_string().starts_with("strum"),
+                        _ => false,
+                    };
+                    if group.delimiter() == Delimiter::Bracket && !ours &&
+                       syn::parse_outer_attr(&format!("#{}", group)).is_err() {
+                        tokens.next();
+                        continue;
+                    }
+                }
+                output.push(tree.clone());
+            }
+            TokenTree::Group(group) => {
+                let mut kept = Group::new(group.delimiter(), drop_foreign_attrs(group.stream()));
+                kept.set_span(group.span());
+                output.push(TokenTree::Group(kept));
+            }
+            other => output.push(other),
+        }
+    }
+    output.into_iter().collect()
+}
+
+/// syn 0.11 can't read `pub` inside an attribute either, so `#[strum_discriminants(vis(pub(crate)))]`
+/// is rewritten to the `vis="pub(crate)"` that `EnumDiscriminants` reads.
+fn quote_discriminants_vis(input: TokenStream) -> TokenStream {
+    input.into_iter()
+        .map(|tree| match tree {
+            TokenTree::Group(ref group) if group.delimiter() == Delimiter::Bracket => {
+                let mut trees = group.stream().into_iter();
+                let args = match (trees.next(), trees.next(), trees.next()) {
+                    (Some(TokenTree::Ident(ref ident)), Some(TokenTree::Group(args)), None)
+                        if ident.to_string() == "strum_discriminants" => args,
+                    _ => return tree.clone(),
+                };
+
+                let mut quoted = Vec::new();
+                let mut args_trees = args.stream().into_iter().peekable();
+                while let Some(arg) = args_trees.next() {
+                    let value = match (&arg, args_trees.peek()) {
+                        (TokenTree::Ident(key), Some(TokenTree::Group(value)))
+                            if key.to_string() == "vis" &&
+                               value.delimiter() == Delimiter::Parenthesis => value.stream(),
+                        _ => {
+                            quoted.push(arg);
+                            continue;
+                        }
+                    };
+                    args_trees.next();
+                    quoted.push(arg);
+                    quoted.push(TokenTree::Punct(Punct::new('=', Spacing::Alone)));
+                    quoted.push(TokenTree::Literal(Literal::string(&value.to_string())));
+                }
+
+                let mut args_group = Group::new(args.delimiter(), quoted.into_iter().collect());
+                args_group.set_span(args.span());
+                let attr = group.stream().into_iter().next().into_iter()
+                    .chain(Some(TokenTree::Group(args_group)))
+                    .collect();
+                let mut attr_group = Group::new(Delimiter::Bracket, attr);
+                attr_group.set_span(group.span());
+                TokenTree::Group(attr_group)
+            }
+            other => other,
+        })
+        .collect()
+}
+
+fn hide_raw_idents(input: TokenStream) -> TokenStream {
+    input.into_iter()
+        .map(|tree| match tree {
+            TokenTree::Ident(ident) => {
+                let hidden = ident.to_string().strip_prefix("r#").map(|name| {
+                    proc_macro::Ident::new(&format!("{}{}", RAW_PREFIX, name), ident.span())
+                });
+                TokenTree::Ident(hidden.unwrap_or(ident))
+            }
+            TokenTree::Group(group) => {
+                let mut hidden = Group::new(group.delimiter(), hide_raw_idents(group.stream()));
+                hidden.set_span(group.span());
+                TokenTree::Group(hidden)
+            }
+            other => other,
+        })
+        .collect()
+}
 
-    let toks = enum_message_inner(&ast);
-    debug_print_generated(&ast, &toks);
-    toks.parse().unwrap()
+/// Only identifiers are turned back, so a string that happens to contain the prefix, like a
+/// `serialize` value or a doc comment, comes out as it was written.
+fn restore_raw_idents(input: TokenStream) -> TokenStream {
+    input.into_iter()
+        .map(|tree| match tree {
+            TokenTree::Ident(ident) => {
+                let restored = ident.to_string().strip_prefix(RAW_PREFIX).map(|name| {
+                    proc_macro::Ident::new_raw(name, ident.span())
+                });
+                TokenTree::Ident(restored.unwrap_or(ident))
+            }
+            TokenTree::Group(group) => {
+                let mut restored = Group::new(group.delimiter(), restore_raw_idents(group.stream()));
+                restored.set_span(group.span());
+                TokenTree::Group(restored)
+            }
+            other => other,
+        })
+        .collect()
+}
+
+/// The name of an identifier without the `r#` of a raw identifier.
+fn unraw(ident: &str) -> &str {
+    ident.strip_prefix(RAW_PREFIX).unwrap_or(ident)
+}
+
+/// Where `STRUM_DEBUG` sends the code generated for one derive.
+#[derive(Debug, PartialEq)]
+enum DebugOutput<'a> {
+    Skip,
+    Print,
+    Append(&'a str),
 }
 
-fn debug_print_generated(ast: &syn::DeriveInput, toks: &quote::Tokens) {
-    let ident = ast.ident.as_ref();
-    let debug = env::var("STRUM_DEBUG");
-    if let Ok(s) = debug {
-        if s == "1" {
-            println!("{}", toks);
+/// Reads a `STRUM_DEBUG` value. It's a comma separated list of `1` for everything, `Type` for every
+/// derive on a type, or `Type:Derive` for just one, plus an optional `out=path` to append the code
+/// to a file instead of printing it.
+fn debug_output<'a>(debug: &'a str, ident: &str, derive: &str) -> DebugOutput<'a> {
+    let mut out = None;
+    let mut wanted = false;
+    for entry in debug.split(',').map(str::trim) {
+        if let Some(path) = entry.strip_prefix("out=") {
+            out = Some(path);
+            continue;
         }
 
-        if s == ident {
-            println!("{}", toks);
+        wanted |= match entry.split_once(':') {
+            Some((ty, d)) => ty == ident && d == derive,
+            None => entry == "1" || entry == ident,
+        };
+    }
+
+    match out {
+        _ if !wanted => DebugOutput::Skip,
+        Some(path) => DebugOutput::Append(path),
+        None => DebugOutput::Print,
+    }
+}
+
+/// Prints the code generated for a derive when `STRUM_DEBUG` asks for it.
+fn debug_print_generated(ast: &syn::DeriveInput, derive: &str, output: &TokenStream) {
+    let debug = match env::var("STRUM_DEBUG") {
+        Ok(debug) => debug,
+        Err(_) => return,
+    };
+
+    let ident = unraw(ast.ident.as_ref());
+    match debug_output(&debug, ident, derive) {
+        DebugOutput::Skip => (),
+        DebugOutput::Print => println!("{}", output),
+        DebugOutput::Append(path) => {
+            let written = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| {
+                    writeln!(file, "// {} on {}\n{}\n", derive, ident, output)
+                });
+            if let Err(err) = written {
+                panic!("STRUM_DEBUG couldn't write to {}: {}", path, err);
+            }
         }
     }
 }
@@ -71,7 +453,7 @@ fn extract_attrs<'a>(attrs: &'a [Attribute], attr: &str, prop: &str) -> Vec<&'a
                 None
             }
         })
-        .flat_map(|nested| nested)
+        .flatten()
         // Get all the inner elements as long as they start with ser.
         .filter_map(|attribute| {
             use syn::NestedMetaItem::*;
@@ -84,288 +466,3437 @@ fn extract_attrs<'a>(attrs: &'a [Attribute], attr: &str, prop: &str) -> Vec<&'a
         }).collect()
 }
 
-fn unique_attr<'a>(attrs: &'a [Attribute], attr: &str, prop: &str) -> Option<&'a str> {
-    let mut curr = extract_attrs(attrs, attr, prop);
-    if curr.len() > 1 {
-        panic!("More than one property: {} found on variant", prop);
+fn unique_attr<'a>(attrs: &'a [Attribute], attr: &str, prop: &str) -> Option<&'a str> {
+    let mut curr = extract_attrs(attrs, attr, prop);
+    if curr.len() > 1 {
+        panic!("More than one property: {} found on variant", prop);
+    }
+
+    curr.pop()
+}
+
+/// Looks for a flag like `#[strum(prop)]`, `#[strum(prop = false)]` or `#[strum(prop = "true")]`.
+/// Returns `None` if the flag isn't present at all.
+fn extract_flag(attrs: &[Attribute], attr: &str, prop: &str) -> Option<bool> {
+    let mut flags = attrs.iter()
+        .filter_map(|attribute| {
+            use syn::MetaItem::*;
+            if let List(ref i, ref nested) = attribute.value {
+                if i == attr { Some(nested) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .filter_map(|attribute| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            match *attribute {
+                MetaItem(Word(ref i)) if i == prop => Some(true),
+                MetaItem(NameValue(ref i, syn::Lit::Bool(b))) if i == prop => Some(b),
+                MetaItem(NameValue(ref i, syn::Lit::Str(ref s, ..))) if i == prop => {
+                    match &**s {
+                        "true" => Some(true),
+                        "false" => Some(false),
+                        _ => panic!("Expected `true` or `false` for {}, found \"{}\"", prop, s),
+                    }
+                }
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if flags.len() > 1 {
+        panic!("More than one property: {} found", prop);
+    }
+
+    flags.pop()
+}
+
+/// Enum-level attributes that change how every variant is serialized.
+#[derive(Clone, Copy)]
+struct Naming<'a> {
+    case_style: Option<CaseStyle>,
+    prefix: Option<&'a str>,
+    suffix: Option<&'a str>,
+    /// Set by `use_serde_names`, which also reads serde's `rename`, `rename_all` and `alias`.
+    serde_names: bool,
+    /// Whether `case_style` came from serde's `rename_all`, which splits words differently.
+    serde_case: bool,
+}
+
+impl<'a> Naming<'a> {
+    fn from_attrs(attrs: &'a [Attribute]) -> Naming<'a> {
+        let serde_names = extract_flag(attrs, "strum", "use_serde_names").unwrap_or(false);
+        let strum_case = unique_attr(attrs, "strum", "serialize_all");
+        let serde_case = if serde_names && strum_case.is_none() {
+            unique_attr(attrs, "serde", "rename_all")
+        } else {
+            None
+        };
+
+        Naming {
+            case_style: strum_case.or(serde_case).map(CaseStyle::from_attr),
+            prefix: unique_attr(attrs, "strum", "prefix"),
+            suffix: unique_attr(attrs, "strum", "suffix"),
+            serde_names,
+            serde_case: serde_case.is_some(),
+        }
+    }
+
+    /// The name a variant gets when it doesn't have any `serialize` or `to_string` attributes.
+    fn default_name(&self, ident: &syn::Ident) -> String {
+        match self.case_style {
+            Some(style) if self.serde_case => style.apply_serde(unraw(ident.as_ref())),
+            Some(style) => style.apply(unraw(ident.as_ref())),
+            None => unraw(ident.as_ref()).into(),
+        }
+    }
+
+    /// serde's `rename` for the variant, if `use_serde_names` is on.
+    fn serde_rename<'b>(&self, attrs: &'b [Attribute]) -> Option<&'b str> {
+        if self.serde_names { unique_attr(attrs, "serde", "rename") } else { None }
+    }
+
+    /// serde's `alias`es for the variant, if `use_serde_names` is on.
+    fn serde_aliases<'b>(&self, attrs: &'b [Attribute]) -> Vec<&'b str> {
+        if self.serde_names { extract_attrs(attrs, "serde", "alias") } else { Vec::new() }
+    }
+
+    /// Adds the enum's prefix and suffix to a serialization.
+    fn decorate(&self, serialization: &str) -> String {
+        // `serialize=""` is how a variant asks for the empty input, so it's left alone.
+        if serialization.is_empty() {
+            return String::new();
+        }
+
+        format!("{}{}{}",
+                self.prefix.unwrap_or(""),
+                serialization,
+                self.suffix.unwrap_or(""))
+    }
+}
+
+/// How the strings a variant is parsed from are compared, as set on the enum by
+/// `ascii_case_insensitive` or `case_insensitive="ascii"`/`"unicode"`. Every derive that parses
+/// strings reads it from here, so they all accept the same ones.
+#[derive(Clone, Copy, PartialEq)]
+enum CaseMode {
+    Sensitive,
+    Ascii,
+    /// Folds case with strum's tables instead of comparing ASCII.
+    Unicode,
+}
+
+impl CaseMode {
+    fn from_attrs(attrs: &[Attribute]) -> CaseMode {
+        match unique_attr(attrs, "strum", "case_insensitive") {
+            Some("unicode") => CaseMode::Unicode,
+            Some("ascii") => CaseMode::Ascii,
+            Some(other) => {
+                panic!("case_insensitive must be \"ascii\" or \"unicode\", not {:?}", other)
+            }
+            None if extract_flag(attrs, "strum", "ascii_case_insensitive").unwrap_or(false) => {
+                CaseMode::Ascii
+            }
+            None => CaseMode::Sensitive,
+        }
+    }
+
+    /// Whether a variant ignores case, which it can still change with `ascii_case_insensitive`.
+    fn ignores_case(self, variant_attrs: &[Attribute]) -> bool {
+        extract_flag(variant_attrs, "strum", "ascii_case_insensitive")
+            .unwrap_or(self != CaseMode::Sensitive)
+    }
+}
+
+/// Returns every string a variant can be parsed from: all of the `serialize`
+/// attributes plus `to_string`, falling back on the variant's name.
+fn get_serializations(ident: &syn::Ident, attrs: &[Attribute], naming: Naming) -> Vec<String> {
+    let mut serializations = extract_attrs(attrs, "strum", "serialize")
+        .into_iter()
+        .chain(extract_attrs(attrs, "strum", "to_string"))
+        .map(|s| naming.decorate(s))
+        .collect::<Vec<_>>();
+
+    // serde's names are used exactly as they're written, and an `alias` is accepted on top of
+    // whatever the variant is otherwise called.
+    serializations.extend(naming.serde_rename(attrs).map(String::from));
+    if serializations.is_empty() {
+        serializations.push(naming.decorate(&naming.default_name(ident)));
+    }
+    serializations.extend(naming.serde_aliases(attrs).into_iter().map(String::from));
+
+    serializations
+}
+
+/// Returns the string a variant should be displayed as. `to_string` wins, then the
+/// longest `serialize` attribute, then the name of the variant.
+fn get_preferred_name(ident: &syn::Ident, attrs: &[Attribute], naming: Naming) -> String {
+    if let Some(to_string) = unique_attr(attrs, "strum", "to_string") {
+        return naming.decorate(to_string);
+    }
+
+    if let Some(rename) = naming.serde_rename(attrs) {
+        return rename.into();
+    }
+
+    let name = extract_attrs(attrs, "strum", "serialize")
+        .into_iter()
+        .fold(None, |longest: Option<&str>, s| match longest {
+            Some(l) if l.len() >= s.len() => Some(l),
+            _ => Some(s),
+        })
+        .map(String::from)
+        .unwrap_or_else(|| naming.default_name(ident));
+
+    naming.decorate(&name)
+}
+
+/// Builds the arms of a `match *self` that stores each variant as a string, for the database
+/// integrations. `store` gets the `&str` to write and whether it's `'static`, which it isn't for
+/// the string captured by a `default` variant. Disabled variants evaluate to `disabled`.
+#[cfg(any(feature = "diesel", feature = "postgres-types", feature = "rusqlite",
+          feature = "sqlx"))]
+fn stored_str_arms(ast: &syn::DeriveInput,
+                   derive: &str,
+                   store: &dyn Fn(quote::Tokens, bool) -> quote::Tokens,
+                   disabled: quote::Tokens)
+                   -> Vec<quote::Tokens> {
+    let name = &ast.ident;
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("{} only works on Enums", derive),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+
+    let mut arms = Vec::new();
+    for variant in variants {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+
+        if is_disabled(&variant.attrs, derive) {
+            continue;
+        }
+
+        if unique_attr(&variant.attrs, "strum", "default") == Some("true") {
+            let pattern = match variant.data {
+                Tuple(..) => quote::Ident::from("(ref inner)"),
+                Struct(ref fields) => {
+                    let field = fields[0].ident.as_ref().unwrap();
+                    quote::Ident::from(&*format!("{{ {}: ref inner }}", field))
+                }
+                Unit => panic!("Default only works on variants with a single String field"),
+            };
+            let value = store(quote!{ strum::__core::convert::AsRef::<str>::as_ref(inner) }, false);
+            arms.push(quote!{ #name::#ident #pattern => #value });
+            continue;
+        }
+
+        let output = get_preferred_name(ident, &variant.attrs, naming);
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+        let value = store(quote!{ #output }, true);
+        arms.push(quote!{ #name::#ident #params => #value });
+    }
+
+    if arms.len() < variants.len() {
+        arms.push(quote!{ _ => #disabled });
+    }
+
+    arms
+}
+
+/// Adds the lifetimes, type parameters and where clause predicates an integration's impl needs
+/// on top of the enum's own generics, e.g. `'r`, `DB` and `DB: sqlx::Database`.
+#[cfg(any(feature = "diesel", feature = "postgres-types", feature = "rusqlite",
+          feature = "sqlx"))]
+fn extend_generics(generics: &syn::Generics,
+                   lifetimes: &[&str],
+                   params: &[&str],
+                   predicates: &str)
+                   -> syn::Generics {
+    let mut generics = generics.clone();
+    for (i, lifetime) in lifetimes.iter().enumerate() {
+        generics.lifetimes.insert(i, syn::LifetimeDef::new(*lifetime));
+    }
+    for param in params {
+        generics.ty_params.push(syn::TyParam {
+            attrs: Vec::new(),
+            ident: syn::Ident::new(*param),
+            bounds: Vec::new(),
+            default: None,
+        });
+    }
+    let extra = syn::parse_where_clause(&format!("where {}", predicates))
+        .expect("invalid where clause");
+    generics.where_clause.predicates.extend(extra.predicates);
+    generics
+}
+
+/// Adds `bound` to the type of every field of an enabled variant that mentions one of the enum's
+/// type parameters, for the derives that build each field with a trait of its own.
+fn bound_generic_fields(ast: &syn::DeriveInput, derive: &str, bound: &str) -> syn::Generics {
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("{} only works on Enums", derive),
+    };
+
+    let bounds = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, derive))
+        .flat_map(|variant| variant.data.fields())
+        .map(|field| (&field.ty, bound))
+        .collect::<Vec<_>>();
+    bound_generic_types(ast, &bounds)
+}
+
+/// Adds a `ty: bound` predicate for each of `bounds` whose type mentions one of the type
+/// parameters. Types that don't are left alone, since their impls are already known.
+///
+/// `#[strum(bound="T: Trait")]` on the enum replaces these predicates with its own, and
+/// `#[strum(no_bound)]` drops them, for when the inferred ones are stricter than they need to be.
+fn bound_generic_types(ast: &syn::DeriveInput, bounds: &[(&syn::Ty, &str)]) -> syn::Generics {
+    let mut generics = ast.generics.clone();
+    let custom = unique_attr(&ast.attrs, "strum", "bound");
+    let no_bound = extract_flag(&ast.attrs, "strum", "no_bound").unwrap_or(false);
+    match (custom, no_bound) {
+        (Some(_), true) => panic!("bound and no_bound can't be combined"),
+        (Some(custom), false) => {
+            if !custom.trim().is_empty() {
+                let extra = syn::parse_where_clause(&format!("where {}", custom))
+                    .unwrap_or_else(|_| panic!("bound=\"{}\" isn't a valid where clause", custom));
+                generics.where_clause.predicates.extend(extra.predicates);
+            }
+            return generics;
+        }
+        (None, true) => return generics,
+        (None, false) => {}
+    }
+
+    let mut predicates = Vec::new();
+    for &(ty, bound) in bounds {
+        let mentions = Mentions::of(ty);
+        let is_generic = generics.ty_params
+            .iter()
+            .any(|param| mentions.types.contains(&param.ident));
+        let predicate = format!("{}: {}", quote!{ #ty }.as_str(), bound);
+        if is_generic && !predicates.contains(&predicate) {
+            predicates.push(predicate);
+        }
+    }
+
+    if !predicates.is_empty() {
+        let extra = syn::parse_where_clause(&format!("where {}", predicates.join(", ")))
+            .expect("invalid where clause");
+        generics.where_clause.predicates.extend(extra.predicates);
+    }
+    generics
+}
+
+/// The names in a type that could be the enum's generic parameters: the first segment of every
+/// relative path, so `T`, `Vec<T>` and `T::Assoc` mention `T` but `other::T` doesn't, and every
+/// lifetime.
+#[derive(Default)]
+struct Mentions<'a> {
+    types: HashSet<&'a syn::Ident>,
+    lifetimes: HashSet<&'a syn::Ident>,
+}
+
+impl<'a> Mentions<'a> {
+    fn of(ty: &'a syn::Ty) -> Mentions<'a> {
+        let mut mentions = Mentions::default();
+        mentions.add_ty(ty);
+        mentions
+    }
+
+    fn add_ty(&mut self, ty: &'a syn::Ty) {
+        use syn::Ty::*;
+        match *ty {
+            Slice(ref ty) | Array(ref ty, _) | Paren(ref ty) => self.add_ty(ty),
+            Ptr(ref mut_ty) => self.add_ty(&mut_ty.ty),
+            Rptr(ref lifetime, ref mut_ty) => {
+                self.lifetimes.extend(lifetime.as_ref().map(|lifetime| &lifetime.ident));
+                self.add_ty(&mut_ty.ty);
+            }
+            BareFn(ref bare_fn) => {
+                for arg in &bare_fn.inputs {
+                    self.add_ty(&arg.ty);
+                }
+                if let syn::FunctionRetTy::Ty(ref ty) = bare_fn.output {
+                    self.add_ty(ty);
+                }
+            }
+            Tup(ref tys) => {
+                for ty in tys {
+                    self.add_ty(ty);
+                }
+            }
+            Path(ref qself, ref path) => {
+                match *qself {
+                    Some(ref qself) => self.add_ty(&qself.ty),
+                    None if !path.global => {
+                        self.types.extend(path.segments.first().map(|segment| &segment.ident))
+                    }
+                    None => {}
+                }
+                self.add_path_args(path);
+            }
+            TraitObject(ref bounds) | ImplTrait(ref bounds) => {
+                for bound in bounds {
+                    match *bound {
+                        syn::TyParamBound::Trait(ref poly, _) => {
+                            self.add_path_args(&poly.trait_ref)
+                        }
+                        syn::TyParamBound::Region(ref lifetime) => {
+                            self.lifetimes.insert(&lifetime.ident);
+                        }
+                    }
+                }
+            }
+            Never | Infer | Mac(_) => {}
+        }
+    }
+
+    fn add_path_args(&mut self, path: &'a syn::Path) {
+        for segment in &path.segments {
+            match segment.parameters {
+                syn::PathParameters::AngleBracketed(ref data) => {
+                    self.lifetimes.extend(data.lifetimes.iter().map(|lifetime| &lifetime.ident));
+                    for ty in data.types.iter().chain(data.bindings.iter().map(|b| &b.ty)) {
+                        self.add_ty(ty);
+                    }
+                }
+                syn::PathParameters::Parenthesized(ref data) => {
+                    for ty in data.inputs.iter().chain(data.output.as_ref()) {
+                        self.add_ty(ty);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The fields of a variant filled in with `Default::default()`, ready to follow the variant's path.
+fn default_fields(variant: &syn::Variant) -> quote::Ident {
+    use syn::VariantData::*;
+    match variant.data {
+        Unit => quote::Ident::from(""),
+        Tuple(ref fields) => {
+            let default = fields.iter()
+                .map(|_| "Default::default()")
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            quote::Ident::from(&*format!("({})", default))
+        }
+        Struct(ref fields) => {
+            let default = fields.iter()
+                .map(|field| {
+                    format!("{}:{}", field.ident.as_ref().unwrap(), "Default::default()")
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            quote::Ident::from(&*format!("{{{}}}", default))
+        }
+    }
+}
+
+/// Returns the integer type from a `#[repr(..)]` attribute on the enum, if there is one.
+fn get_repr_type(attrs: &[Attribute]) -> Option<&syn::Ident> {
+    const INT_TYPES: &[&str] = &["u8", "u16", "u32", "u64", "u128", "usize",
+                                 "i8", "i16", "i32", "i64", "i128", "isize"];
+
+    attrs.iter()
+        .filter_map(|attribute| {
+            use syn::MetaItem::*;
+            if let List(ref i, ref nested) = attribute.value {
+                if i == "repr" { Some(nested) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .filter_map(|item| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            if let MetaItem(Word(ref i)) = *item {
+                if INT_TYPES.contains(&i.as_ref()) { Some(i) } else { None }
+            } else {
+                None
+            }
+        })
+        .next()
+}
+
+/// Collects the doc comments on an item into a single string, one line per `///` comment.
+fn get_documentation(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs.iter()
+        .filter_map(|attribute| {
+            use syn::MetaItem::*;
+            if let NameValue(ref i, syn::Lit::Str(ref s, ..)) = attribute.value {
+                if i == "doc" { Some(s) } else { None }
+            } else {
+                None
+            }
+        })
+        .map(|line| {
+            let line = line.strip_prefix("///").unwrap_or(line);
+            line.strip_prefix(' ').unwrap_or(line)
+        })
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Finds the `{field}` placeholders in a `message` or `to_string` template. Tuple fields are referred to by
+/// position, so `{0}` is rewritten to `{_0}` to line up with the bindings in the generated
+/// pattern. Returns the rewritten template and the field names, in order of first use.
+fn parse_message_template(msg: &str) -> (String, Vec<String>) {
+    let mut template = String::new();
+    let mut fields: Vec<String> = Vec::new();
+    let mut chars = msg.chars().peekable();
+    while let Some(c) = chars.next() {
+        template.push(c);
+        if c != '{' {
+            continue;
+        }
+
+        if chars.peek() == Some(&'{') {
+            template.push(chars.next().unwrap());
+            continue;
+        }
+
+        let mut field = String::new();
+        while let Some(&c) = chars.peek() {
+            if c == '}' || c == ':' {
+                break;
+            }
+
+            field.push(c);
+            chars.next();
+        }
+
+        if field.is_empty() {
+            panic!("Placeholders in message=\"{}\" must name a field", msg);
+        }
+
+        if field.chars().next().unwrap().is_ascii_digit() {
+            field.insert(0, '_');
+        }
+
+        template.push_str(&field);
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+
+    (template, fields)
+}
+
+/// The formatting trait each placeholder of a template formats its field with, e.g. `Debug` for
+/// `{0:?}`. The fields are named the way `parse_message_template` names them.
+fn template_traits(msg: &str) -> Vec<(String, &'static str)> {
+    let mut traits = Vec::new();
+    let mut rest = msg;
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+        if let Some(escaped) = rest.strip_prefix('{') {
+            rest = escaped;
+            continue;
+        }
+
+        let end = rest.find('}').unwrap_or(rest.len());
+        let (field, spec) = rest[..end].split_once(':').unwrap_or((&rest[..end], ""));
+        let format_trait = match spec.chars().last() {
+            Some('?') => "strum::__core::fmt::Debug",
+            Some('x') => "strum::__core::fmt::LowerHex",
+            Some('X') => "strum::__core::fmt::UpperHex",
+            Some('o') => "strum::__core::fmt::Octal",
+            Some('b') => "strum::__core::fmt::Binary",
+            Some('e') => "strum::__core::fmt::LowerExp",
+            Some('E') => "strum::__core::fmt::UpperExp",
+            _ => "strum::__core::fmt::Display",
+        };
+        let field = if field.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("_{}", field)
+        } else {
+            field.to_string()
+        };
+        traits.push((field, format_trait));
+        rest = &rest[end..];
+    }
+
+    traits
+}
+
+/// The type of the field a template placeholder refers to, if the variant has one by that name.
+fn template_field_ty<'a>(variant: &'a syn::Variant, field: &str) -> Option<&'a syn::Ty> {
+    match variant.data {
+        syn::VariantData::Tuple(ref fields) => {
+            field[1..].parse::<usize>().ok().and_then(|i| fields.get(i)).map(|field| &field.ty)
+        }
+        syn::VariantData::Struct(ref fields) => {
+            fields.iter().find(|f| f.ident.as_ref().unwrap() == field).map(|field| &field.ty)
+        }
+        syn::VariantData::Unit => None,
+    }
+}
+
+/// Collects the `(lang, message)` pairs from `#[strum(message_locale(de="...", fr="..."))]`.
+fn get_message_locales(attrs: &[Attribute]) -> Vec<(&str, &str)> {
+    attrs.iter()
+        .filter_map(|attribute| {
+            use syn::MetaItem::*;
+            if let List(ref i, ref nested) = attribute.value {
+                if i == "strum" { Some(nested) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .filter_map(|attribute| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            if let &MetaItem(List(ref i, ref locales)) = attribute {
+                if i == "message_locale" { Some(locales) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .map(|locale| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            match *locale {
+                MetaItem(NameValue(ref lang, syn::Lit::Str(ref msg, ..))) => (lang.as_ref(), &**msg),
+                _ => panic!("message_locale(...) expects a list of lang=\"message\" pairs"),
+            }
+        })
+        .collect()
+}
+
+/// Collects the `key=value` pairs from `#[strum(props(Key="str", Other=10, Flag=true))]`.
+fn get_props(attrs: &[Attribute]) -> Vec<(&syn::Ident, &syn::Lit)> {
+    attrs.iter()
+        .filter_map(|attribute| {
+            use syn::MetaItem::*;
+            if let List(ref i, ref nested) = attribute.value {
+                if i == "strum" { Some(nested) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .filter_map(|attribute| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            if let &MetaItem(List(ref i, ref props)) = attribute {
+                if i == "props" { Some(props) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .map(|prop| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            match *prop {
+                MetaItem(NameValue(ref key, ref value)) => (key, value),
+                _ => panic!("props(...) expects a list of Key=value pairs"),
+            }
+        })
+        .collect()
+}
+
+/// Collects the keys from `#[strum(props_required(Key, Other))]` on the enum.
+fn get_required_props(attrs: &[Attribute]) -> Vec<&str> {
+    attrs.iter()
+        .filter_map(|attribute| {
+            use syn::MetaItem::*;
+            if let List(ref i, ref nested) = attribute.value {
+                if i == "strum" { Some(nested) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .filter_map(|attribute| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            if let &MetaItem(List(ref i, ref keys)) = attribute {
+                if i == "props_required" { Some(keys) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .map(|key| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            match *key {
+                MetaItem(Word(ref key)) => key.as_ref(),
+                _ => panic!("props_required(...) expects a list of keys"),
+            }
+        })
+        .collect()
+}
+
+/// The derives that can be listed in `#[strum(disabled(...))]`.
+const DERIVES: &[&str] = &["EnumString", "EnumIter", "EnumMessage", "Display", "FromRepr",
+                           "EnumVariantNames", "VariantArray", "IntoStaticStr", "EnumIs",
+                           "EnumProperty", "EnumSerialize", "EnumValueEnum",
+                           "EnumSqlxType", "EnumDieselText", "EnumPostgres", "EnumRusqlite",
+                           "EnumRandom", "EnumProptest", "EnumQuickCheck", "EnumArbitrary",
+                           "EnumRotate", "EnumCStr"];
+
+/// Checks for `disabled="true"`, which removes the variant from every derive, or
+/// `disabled(EnumIter, Display)`, which only removes it from the listed derives.
+fn is_disabled(attrs: &[Attribute], derive: &str) -> bool {
+    let v = extract_attrs(attrs, "strum", "disabled");
+    let everywhere = match v.len() {
+        0 => false,
+        1 => v[0] == "true",
+        _ => panic!("Can't have multiple values for 'disabled'"),
+    };
+
+    everywhere || attrs.iter()
+        .filter_map(|attribute| {
+            use syn::MetaItem::*;
+            if let List(ref i, ref nested) = attribute.value {
+                if i == "strum" { Some(nested) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .filter_map(|attribute| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            if let &MetaItem(List(ref i, ref derives)) = attribute {
+                if i == "disabled" { Some(derives) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten()
+        .any(|item| {
+            use syn::NestedMetaItem::*;
+            use syn::MetaItem::*;
+            match *item {
+                MetaItem(Word(ref ident)) if DERIVES.contains(&ident.as_ref()) => ident == derive,
+                _ => {
+                    panic!("disabled(...) expects a list of these derives: {}", DERIVES.join(", "))
+                }
+            }
+        })
+}
+
+fn from_string_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("FromString only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+    let case_mode = CaseMode::from_attrs(&ast.attrs);
+    let unicode_case = case_mode == CaseMode::Unicode;
+
+    let use_phf = extract_flag(&ast.attrs, "strum", "use_phf").unwrap_or(false);
+    let normalize_separators = extract_flag(&ast.attrs, "strum", "normalize_separators")
+        .unwrap_or(false);
+    if use_phf && normalize_separators {
+        panic!("use_phf can't be combined with normalize_separators");
+    }
+    if unicode_case && (use_phf || normalize_separators) {
+        panic!("case_insensitive=\"unicode\" can't be combined with use_phf or normalize_separators");
+    }
+
+    // With `shared_table`, the strings are read out of the enum's table wherever they don't have
+    // to be patterns.
+    let layout = shared_table::layout(ast, naming);
+    let table = shared_table::table(ast);
+    if layout.is_some() && use_phf {
+        panic!("shared_table can't be combined with use_phf, whose hash tables are built from the \
+                strings themselves");
+    }
+
+    // `allow_qualified` also accepts the variant written with the enum's path, like `Color::Red`.
+    let qualified = if extract_flag(&ast.attrs, "strum", "allow_qualified").unwrap_or(false) {
+        let name_str = name.as_ref();
+        let qualified_suffix = format!("::{}", name);
+        quote!{
+            let s = match s.rsplit_once("::") {
+                Some((path, variant)) if path == #name_str || path.ends_with(#qualified_suffix) => {
+                    variant
+                }
+                _ => s,
+            };
+        }
+    } else {
+        quote!{}
+    };
+
+    // `trim` strips surrounding whitespace before anything is matched.
+    let (trim, trim_bytes) = if extract_flag(&ast.attrs, "strum", "trim").unwrap_or(false) {
+        (quote!{ let s = s.trim(); }, quote!{ let bytes = bytes.trim_ascii(); })
+    } else {
+        (quote!{}, quote!{})
+    };
+
+    let capture_input = extract_flag(&ast.attrs, "strum", "capture_input").unwrap_or(false);
+    let suggest = extract_flag(&ast.attrs, "strum", "suggest").unwrap_or(false);
+    let parse_err_ty = unique_attr(&ast.attrs, "strum", "parse_err_ty");
+    let parse_err_fn = unique_attr(&ast.attrs, "strum", "parse_err_fn");
+    let rich_error = extract_flag(&ast.attrs, "strum", "rich_error").unwrap_or(false);
+    let type_name = unraw(name.as_ref());
+
+    // Every string that parses, for `suggest` to pick from and `rich_error` to list.
+    let candidates = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, "EnumString"))
+        .filter(|variant| unique_attr(&variant.attrs, "strum", "default") != Some("true"))
+        .filter(|variant| !extract_flag(&variant.attrs, "strum", "flatten").unwrap_or(false))
+        .filter(|variant| extract_attrs(&variant.attrs, "strum", "serialize_pattern").is_empty())
+        .flat_map(|variant| get_serializations(&variant.ident, &variant.attrs, naming))
+        .collect::<Vec<_>>();
+
+    // A `default` variant converted with `from_str` or `try_from` has an error of its own, which
+    // is passed through in a `ConversionError` unless the enum asked for a different error type.
+    let plain_error = !rich_error && !capture_input && !suggest && parse_err_ty.is_none() &&
+                      parse_err_fn.is_none();
+    let conversion_err = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, "EnumString"))
+        .filter(|variant| unique_attr(&variant.attrs, "strum", "default") == Some("true"))
+        .filter(|_| plain_error)
+        .filter_map(|variant| {
+            let ty = &variant.data.fields().first()?.ty;
+            match unique_attr(&variant.attrs, "strum", "default_conversion") {
+                Some("from_str") => Some(quote!{ <#ty as strum::__core::str::FromStr>::Err }),
+                Some("try_from") => {
+                    // The error can only be named for one input lifetime, which is only known
+                    // to be the same for all of them once the type is.
+                    let mentions = Mentions::of(ty);
+                    let generic = ast.generics.ty_params
+                        .iter()
+                        .any(|param| mentions.types.contains(&param.ident));
+                    if generic {
+                        panic!("default_conversion=\"try_from\" can't be used on a generic field, \
+                                since its error type can't be named. Use from_str instead");
+                    }
+                    Some(quote!{
+                        <#ty as strum::__core::convert::TryFrom<&'static str>>::Error
+                    })
+                }
+                _ => None,
+            }
+        })
+        .next();
+    // Wraps one of the usual `ParseError`s to fit `err_ty`.
+    let parse_error = |err: quote::Tokens| if conversion_err.is_some() {
+        quote!{ strum::ConversionError::Parse(#err) }
+    } else {
+        err
+    };
+
+    let mut rich_error_def = quote!{};
+    let (err_ty, not_found) = if rich_error {
+        if capture_input || suggest || parse_err_ty.is_some() || parse_err_fn.is_some() {
+            panic!("rich_error can't be combined with capture_input, suggest or parse_err_ty");
+        }
+
+        let mut expected = Vec::new();
+        let patterns = variants.iter()
+            .filter(|variant| !is_disabled(&variant.attrs, "EnumString"))
+            .flat_map(|variant| extract_attrs(&variant.attrs, "strum", "serialize_pattern"));
+        let mut seen = HashSet::new();
+        for candidate in candidates.iter().map(|s| &**s).chain(patterns) {
+            if seen.insert(candidate) {
+                expected.push(candidate);
+            }
+        }
+
+        let vis = &ast.vis;
+        let err_name = quote::Ident::from(format!("{}ParseError", name));
+        let doc = format!("The error returned when a string isn't one of the serializations of \
+                           [`{}`].",
+                          name);
+        rich_error_def = quote!{
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #vis struct #err_name;
+
+            impl #err_name {
+                /// The strings that would have parsed.
+                pub const EXPECTED: &'static [&'static str] = &[#(#expected),*];
+            }
+
+            impl strum::__core::fmt::Display for #err_name {
+                fn fmt(&self, f: &mut strum::__core::fmt::Formatter) -> strum::__core::fmt::Result {
+                    f.write_str("expected one of: ")?;
+                    for (idx, expected) in #err_name::EXPECTED.iter().enumerate() {
+                        if idx > 0 {
+                            f.write_str(", ")?;
+                        }
+                        f.write_str(expected)?;
+                    }
+                    Ok(())
+                }
+            }
+
+            impl strum::__core::error::Error for #err_name {}
+
+            impl From<#err_name> for strum::ParseError {
+                fn from(_: #err_name) -> strum::ParseError {
+                    strum::ParseError::VariantNotFound { type_name: #type_name }
+                }
+            }
+        };
+
+        (quote!{ #err_name }, quote!{ #err_name })
+    } else if parse_err_ty.is_some() || parse_err_fn.is_some() {
+        if capture_input || suggest {
+            panic!("parse_err_ty and parse_err_fn can't be combined with capture_input or suggest");
+        }
+
+        match (parse_err_ty, parse_err_fn) {
+            (Some(ty), Some(func)) => {
+                let ty = quote::Ident::from(ty);
+                let func = quote::Ident::from(func);
+                (quote!{ #ty }, quote!{ #func(s) })
+            }
+            _ => panic!("parse_err_ty and parse_err_fn must be used together"),
+        }
+    } else if suggest {
+        let candidates = candidates.iter();
+        (quote!{ strum::ParseErrorWithInput },
+         quote!{
+             strum::ParseErrorWithInput {
+                 input: s.into(),
+                 suggestion: strum::suggest::closest(s, &[#(#candidates),*]),
+                 type_name: #type_name,
+             }
+         })
+    } else if capture_input {
+        (quote!{ strum::ParseErrorWithInput },
+         quote!{
+             strum::ParseErrorWithInput { input: s.into(), suggestion: None, type_name: #type_name }
+         })
+    } else if let Some(ref conversion_err) = conversion_err {
+        (quote!{ strum::ConversionError<#conversion_err> },
+         parse_error(quote!{ strum::ParseError::VariantNotFound { type_name: #type_name } }))
+    } else {
+        (quote!{ strum::ParseError },
+         quote!{ strum::ParseError::VariantNotFound { type_name: #type_name } })
+    };
+
+    let mut has_default = false;
+    // A `default` variant that borrows with one of the enum's lifetimes gets `TryFromStr` instead
+    // of `FromStr`, whose input can't outlive the call.
+    let mut borrowed = None;
+    let mut default_value = if plain_error {
+        // Empty input gets its own error, unless a variant claims it.
+        let empty = parse_error(quote!{ strum::ParseError::EmptyInput });
+        quote!{
+            Err(if s.is_empty() { #empty } else { #not_found })
+        }
+    } else {
+        quote! { Err(#not_found) }
+    };
+    let mut flattened = Vec::new();
+    let mut parsers = Vec::new();
+    // The traits each generic field is built with, which become bounds on the impls.
+    let mut bounds = Vec::new();
+    let field_delimiter = unique_attr(&ast.attrs, "strum", "field_delimiter");
+    let mut field_parsers = Vec::new();
+    let mut prefix_parsers = Vec::new();
+    let mut pattern_parsers = Vec::new();
+    let mut parser_idents = Vec::new();
+    let mut parser_strs = Vec::new();
+    for (variant_idx, variant) in variants.iter().enumerate() {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+
+        if is_disabled(&variant.attrs, "EnumString") {
+            continue;
+        }
+
+        // Input that doesn't match any of our own variants is handed to the wrapped enum.
+        if extract_flag(&variant.attrs, "strum", "flatten").unwrap_or(false) {
+            let (ty, value) = match variant.data {
+                Tuple(ref fields) if fields.len() == 1 => {
+                    (&fields[0].ty, quote!{ #name::#ident(inner) })
+                }
+                Struct(ref fields) if fields.len() == 1 => {
+                    let field = fields[0].ident.as_ref().unwrap();
+                    (&fields[0].ty, quote!{ #name::#ident { #field: inner } })
+                }
+                _ => panic!("flatten can only be used on variants with exactly one field"),
+            };
+
+            bounds.push((ty, "strum::__core::str::FromStr"));
+            flattened.push(quote!{
+                if let Ok(inner) = <#ty as strum::__core::str::FromStr>::from_str(default) {
+                    return Ok(#value);
+                }
+            });
+            continue;
+        }
+
+        if let Some("true") = unique_attr(&variant.attrs, "strum", "default") {
+            if has_default {
+                panic!("Can't have multiple default variants");
+            }
+
+            // Builds the variant out of the converted input.
+            let ctor = match variant.data {
+                Tuple(ref fields) if fields.len() == 1 => quote!{ #name::#ident },
+                Struct(ref fields) if fields.len() == 1 => {
+                    let field = fields[0].ident.as_ref().unwrap();
+                    quote!{ |inner| #name::#ident { #field: inner } }
+                }
+                _ => panic!("Default only works on variants with a single String field"),
+            };
+            let ty = &variant.data.fields()[0].ty;
+            let mentions = Mentions::of(ty);
+            borrowed = ast.generics.lifetimes.iter()
+                .map(|def| &def.lifetime)
+                .find(|lifetime| mentions.lifetimes.contains(&lifetime.ident));
+
+            let map_err = if conversion_err.is_some() {
+                quote!{ strum::ConversionError::Conversion }
+            } else {
+                quote!{ |_| #not_found }
+            };
+            default_value = match unique_attr(&variant.attrs, "strum", "default_conversion") {
+                None | Some("into") => {
+                    bounds.push((ty, "for<'strum> strum::__core::convert::From<&'strum str>"));
+                    quote!{ Ok((#ctor)(default.into())) }
+                }
+                Some("from_str") => {
+                    bounds.push((ty, "strum::__core::str::FromStr"));
+                    quote!{ default.parse().map(#ctor).map_err(#map_err) }
+                }
+                Some("try_from") => {
+                    bounds.push((ty, "for<'strum> strum::__core::convert::TryFrom<&'strum str>"));
+                    quote!{
+                        strum::__core::convert::TryFrom::try_from(default)
+                            .map(#ctor)
+                            .map_err(#map_err)
+                    }
+                }
+                Some(other) => {
+                    panic!("Unknown default_conversion \"{}\", expected into, from_str or \
+                            try_from",
+                           other)
+                }
+            };
+
+            has_default = true;
+            continue;
+        }
+
+        // Look at all the serialize attributes, or the name if there aren't any.
+        let attrs = get_serializations(ident, &variant.attrs, naming);
+        let strs = match layout {
+            Some(ref layout) => {
+                let start = layout.variants[variant_idx].start;
+                (start..start + attrs.len()).map(|idx| quote!{ #table[#idx] }).collect()
+            }
+            None => attrs.iter().map(|attr| quote!{ #attr }).collect::<Vec<_>>(),
+        };
+
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(ref fields) => {
+                let default = fields.iter()
+                    .map(|_| "Default::default()")
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                quote::Ident::from(&*format!("({})", default))
+            }
+            Struct(ref fields) => {
+                let default = fields.iter()
+                    .map(|field| {
+                        format!("{}:{}", field.ident.as_ref().unwrap(), "Default::default()")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                quote::Ident::from(&*format!("{{{}}}", default))
+            }
+        };
+
+        // Variants can opt in or out of the enum's case sensitivity.
+        let variant_case_insensitive = case_mode.ignores_case(&variant.attrs);
+
+        // `serialize_pattern="reg*"` matches anything with that prefix and suffix, and the text in
+        // place of the `*` is parsed into the variant's field, if it has one.
+        let patterns = extract_attrs(&variant.attrs, "strum", "serialize_pattern");
+        if !patterns.is_empty() {
+            if !cfg!(feature = "patterns") {
+                panic!("serialize_pattern requires the patterns feature of strum");
+            }
+
+            let (binding, value) = match variant.data {
+                Unit => (quote!{}, quote!{ #name::#ident }),
+                Tuple(ref fields) if fields.len() == 1 => {
+                    (quote!{ captured }, quote!{ #name::#ident(captured) })
+                }
+                Struct(ref fields) if fields.len() == 1 => {
+                    let field = fields[0].ident.as_ref().unwrap();
+                    (quote!{ captured }, quote!{ #name::#ident { #field: captured } })
+                }
+                _ => panic!("serialize_pattern can only be used on variants with at most one field"),
+            };
+
+            for pattern in patterns {
+                let mut parts = pattern.split('*');
+                let (prefix, suffix) = match (parts.next(), parts.next(), parts.next()) {
+                    (Some(prefix), Some(suffix), None) => (prefix, suffix),
+                    _ => panic!("serialize_pattern needs exactly one `*`, found {:?}", pattern),
+                };
+                let (prefix_len, suffix_len) = (prefix.len(), suffix.len());
+                let matches = if variant_case_insensitive {
+                    quote!{
+                        matches!(default.get(..#prefix_len),
+                                 Some(head) if head.eq_ignore_ascii_case(#prefix)) &&
+                        matches!(default.get(default.len() - #suffix_len..),
+                                 Some(tail) if tail.eq_ignore_ascii_case(#suffix))
+                    }
+                } else {
+                    quote!{ default.starts_with(#prefix) && default.ends_with(#suffix) }
+                };
+                let construct = if variant.data == Unit {
+                    quote!{ return Ok(#value); }
+                } else {
+                    bounds.push((&variant.data.fields()[0].ty, "strum::__core::str::FromStr"));
+                    quote!{
+                        if let Ok(#binding) = strum::__core::str::FromStr::from_str(captured) {
+                            return Ok(#value);
+                        }
+                    }
+                };
+
+                pattern_parsers.push(quote!{
+                    if default.len() > #prefix_len + #suffix_len && #matches {
+                        #[allow(unused_variables)]
+                        let captured = &default[#prefix_len..default.len() - #suffix_len];
+                        #construct
+                    }
+                });
+            }
+
+            continue;
+        }
+
+        // The function gets whatever follows the variant's name and builds the whole value.
+        if let Some(parse_with) = unique_attr(&variant.attrs, "strum", "parse_with") {
+            let parse_with = quote::Ident::from(parse_with);
+            for (attr, string) in attrs.iter().zip(&strs) {
+                let len = attr.len();
+                prefix_parsers.push(if variant_case_insensitive {
+                    quote!{
+                        let head = default.get(..#len);
+                        if matches!(head, Some(head) if head.eq_ignore_ascii_case(#string)) {
+                            return #parse_with(&default[#len..]);
+                        }
+                    }
+                } else {
+                    quote!{
+                        if let Some(rest) = default.strip_prefix(#string) {
+                            return #parse_with(rest);
+                        }
+                    }
+                });
+            }
+
+            continue;
+        }
+
+        // With a delimiter, "Name:1:2" fills in the fields instead of using their defaults.
+        if let (Some(delimiter), false) = (field_delimiter, variant.data.fields().is_empty()) {
+            let names = strs.iter();
+            let head_matches = if variant_case_insensitive {
+                quote!{ #(head.eq_ignore_ascii_case(#names))||* }
+            } else {
+                quote!{ #(head == #names)||* }
+            };
+            let bindings = (0..variant.data.fields().len())
+                .map(|i| quote::Ident::from(format!("field{}", i)))
+                .collect::<Vec<_>>();
+            let value = match variant.data {
+                Struct(ref fields) => {
+                    let names = fields.iter().map(|field| field.ident.as_ref().unwrap());
+                    let values = bindings.iter();
+                    quote!{ #name::#ident { #(#names: #values),* } }
+                }
+                _ => {
+                    let values = bindings.iter();
+                    quote!{ #name::#ident(#(#values),*) }
+                }
+            };
+            bounds.extend(variant.data
+                .fields()
+                .iter()
+                .map(|field| (&field.ty, "strum::__core::str::FromStr")));
+            let parse_fields = bindings.iter().map(|binding| {
+                quote!{
+                    let #binding = match fields.next().map(strum::__core::str::FromStr::from_str) {
+                        Some(Ok(value)) => value,
+                        _ => return Err(#not_found),
+                    };
+                }
+            });
+
+            field_parsers.push(quote!{
+                if #head_matches {
+                    let mut fields = rest.split(#delimiter);
+                    #(#parse_fields)*
+                    if fields.next().is_some() {
+                        return Err(#not_found);
+                    }
+
+                    return Ok(#value);
+                }
+            });
+        }
+
+        bounds.extend(variant.data.fields().iter().map(|field| (&field.ty, "Default")));
+        parsers.push((attrs, variant_case_insensitive, quote!{ #name::#ident #params }));
+        parser_idents.push(ident);
+        parser_strs.push(strs);
+    }
+
+    // A string that two variants parse from would leave the second arm unreachable. Strings are
+    // compared the way the generated matcher compares them: `exact` is what a case sensitive arm
+    // matches, and two strings with the same `folded` key collide if either arm ignores case.
+    let mut claimed: HashMap<String, Vec<(String, bool, &syn::Ident)>> = HashMap::new();
+    for (&(ref attrs, case_insensitive, _), &ident) in parsers.iter().zip(&parser_idents) {
+        for attr in attrs {
+            let exact = if normalize_separators {
+                attr.replace(['-', ' '], "_")
+            } else {
+                attr.clone()
+            };
+            let folded = if unicode_case {
+                unicode::fold(&exact)
+            } else {
+                exact.to_ascii_lowercase()
+            };
+            let claims = claimed.entry(folded).or_default();
+            for &(ref other, other_case_insensitive, other_ident) in claims.iter() {
+                if other_ident != ident &&
+                   (*other == exact || case_insensitive || other_case_insensitive) {
+                    panic!("{}::{} and {}::{} both parse from {:?}",
+                           name, other_ident, name, ident, attr);
+                }
+            }
+            claims.push((exact, case_insensitive, ident));
+        }
+    }
+
+    // `parse_discriminant` falls back to reading the input as the integer value of a variant.
+    if extract_flag(&ast.attrs, "strum", "parse_discriminant").unwrap_or(false) {
+        let repr = match get_repr_type(&ast.attrs) {
+            Some(ty) => quote!{ #ty },
+            None => quote!{ isize },
+        };
+        let constants = discriminant_constants(variants, &repr);
+        let arms = variants.iter()
+            .enumerate()
+            .filter(|&(_, variant)| !is_disabled(&variant.attrs, "EnumString"))
+            .filter(|&(_, variant)| unique_attr(&variant.attrs, "strum", "default") != Some("true"))
+            .filter(|&(_, variant)| !extract_flag(&variant.attrs, "strum", "flatten").unwrap_or(false))
+            .map(|(idx, variant)| {
+                let ident = &variant.ident;
+                let const_name = quote::Ident::from(&*format!("DISCRIMINANT_{}", idx));
+                let params = default_fields(variant);
+                quote!{ #const_name => return Ok(#name::#ident #params), }
+            });
+
+        flattened.push(quote!{
+            if let Ok(discriminant) = default.parse::<#repr>() {
+                #(#constants)*
+
+                match discriminant {
+                    #(#arms)*
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    // `abbreviations` accepts any prefix that only one variant's serializations start with.
+    if extract_flag(&ast.attrs, "strum", "abbreviations").unwrap_or(false) {
+        if !plain_error {
+            panic!("abbreviations can't be combined with capture_input, suggest or parse_err_ty, \
+                    since it returns ParseError::Ambiguous");
+        }
+        if unicode_case || normalize_separators {
+            panic!("abbreviations can't be combined with case_insensitive=\"unicode\" or \
+                    normalize_separators");
+        }
+
+        let ambiguous = parse_error(quote!{ strum::ParseError::Ambiguous });
+        let mut candidates = Vec::new();
+        let mut found_arms = Vec::new();
+        for (idx, (&(_, case_insensitive, ref value), strs)) in
+            parsers.iter().zip(&parser_strs).enumerate() {
+            for attr in strs {
+                let starts_with = if case_insensitive {
+                    quote!{
+                        matches!(#attr.get(..default.len()),
+                                 Some(head) if head.eq_ignore_ascii_case(default))
+                    }
+                } else {
+                    quote!{ #attr.starts_with(default) }
+                };
+                candidates.push(quote!{
+                    if #starts_with {
+                        if found.is_some() && found != Some(#idx) {
+                            return Err(#ambiguous);
+                        }
+                        found = Some(#idx);
+                    }
+                });
+            }
+            found_arms.push(quote!{ Some(#idx) => return Ok(#value), });
+        }
+
+        flattened.push(quote!{
+            if !default.is_empty() {
+                let mut found = None;
+                #(#candidates)*
+                match found {
+                    #(#found_arms)*
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    if !pattern_parsers.is_empty() {
+        flattened.insert(0, quote!{ #(#pattern_parsers)* });
+    }
+
+    if !field_parsers.is_empty() {
+        let delimiter = field_delimiter.unwrap();
+        flattened.insert(0, quote!{
+            if let Some((head, rest)) = default.split_once(#delimiter) {
+                #(#field_parsers)*
+            }
+        });
+    }
+
+    if !prefix_parsers.is_empty() {
+        flattened.insert(0, quote!{ #(#prefix_parsers)* });
+    }
+
+    if let Some(default_with) = unique_attr(&ast.attrs, "strum", "default_with") {
+        if has_default {
+            panic!("default_with can't be combined with a default variant");
+        }
+
+        let default_with = quote::Ident::from(default_with);
+        default_value = quote!{ #default_with(default) };
+        has_default = true;
+    }
+
+    let generics = bound_generic_types(ast, &bounds);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    // Enums whose every variant parses from single characters also convert to and from `char`.
+    let single_chars = parsers.iter().all(|parser| parser.0.iter().all(|s| s.chars().count() == 1));
+    let char_conversions = if single_chars && !has_default && flattened.is_empty() &&
+                              !normalize_separators && !unicode_case && !parsers.is_empty() &&
+                              parsers.len() == variants.len() {
+        char_conversions(ast, &generics, &parsers, &err_ty, &not_found, naming)
+    } else {
+        quote!{}
+    };
+
+    let default = if has_default || !flattened.is_empty() {
+        quote!{
+            default => {
+                #(#flattened)*
+                #default_value
+            }
+        }
+    } else {
+        quote!{ _ => #default_value }
+    };
+
+    let serialization_count = parsers.iter().map(|parser| parser.0.len()).sum::<usize>();
+    let self_ty = quote!{ #name #ty_generics };
+    let generic = !ast.generics.lifetimes.is_empty() || !ast.generics.ty_params.is_empty();
+    let body = if use_phf {
+        from_string_phf(&parsers, &default, &self_ty, generic)
+    } else if layout.is_none() && serialization_count > TABLE_THRESHOLD && !unicode_case {
+        from_string_table(&parsers, &default, &self_ty, normalize_separators, generic)
+    } else if layout.is_none() && serialization_count > BUCKET_THRESHOLD && !unicode_case {
+        from_string_buckets(&parsers, &default, normalize_separators)
+    } else {
+        let mut arms = Vec::new();
+        for (&(_, case_insensitive, ref value), strs) in parsers.iter().zip(&parser_strs) {
+            if normalize_separators {
+                let case_insensitive = strs.iter().map(|_| case_insensitive);
+                arms.push(quote!{
+                    s if #(strum::__eq_ignore_separators(s, #strs, #case_insensitive))||* => {
+                        Ok(#value)
+                    }
+                });
+            } else if case_insensitive && unicode_case {
+                arms.push(quote!{
+                    s if #(strum::unicode::eq_folded(s, #strs))||* => Ok(#value)
+                });
+            } else if case_insensitive {
+                arms.push(quote!{
+                    s if #(s.eq_ignore_ascii_case(#strs))||* => Ok(#value)
+                });
+            } else if layout.is_some() {
+                arms.push(quote!{ s if #(s == #strs)||* => Ok(#value) });
+            } else {
+                arms.push(quote!{ #(#strs)|* => Ok(#value) });
+            }
+        }
+
+        arms.push(default);
+        quote!{
+            match s {
+                #(#arms),*
+            }
+        }
+    };
+
+    // `try_from` adds `TryFrom` impls that parse the same way, for APIs that want those instead.
+    // They're opt in because they conflict with the blanket impl when the enum has `From<&str>`.
+    let try_from = if extract_flag(&ast.attrs, "strum", "try_from").unwrap_or(false) {
+        if borrowed.is_some() {
+            panic!("try_from can't be combined with a default variant that borrows from the \
+                    input, use strum::TryFromStr instead");
+        }
+
+        let mut ref_generics = generics.clone();
+        ref_generics.lifetimes.insert(0, syn::LifetimeDef::new("'_strum"));
+        let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+
+        // Bytes are matched against the serializations directly, so only input that none of them
+        // match has to be checked for UTF-8 and go through `from_str`.
+        let byte_parsers = if normalize_separators || unicode_case || layout.is_some() ||
+                              serialization_count > TABLE_THRESHOLD {
+            &parsers[..0]
+        } else {
+            &parsers[..]
+        };
+        let byte_arms = byte_parsers.iter().map(|&(ref attrs, case_insensitive, ref value)| {
+            let bytes = attrs.iter().map(|s| quote::ByteStr(s)).collect::<Vec<_>>();
+            if case_insensitive {
+                quote!{ b if #(b.eq_ignore_ascii_case(#bytes))||* => return Ok(#value) }
+            } else {
+                quote!{ #(#bytes)|* => return Ok(#value) }
+            }
+        });
+
+        quote!{
+            impl #ref_impl_generics strum::__core::convert::TryFrom<&'_strum [u8]>
+                for #name #ty_generics #where_clause
+            {
+                type Error = #err_ty;
+                fn try_from(bytes: &'_strum [u8]) -> Result< #name #ty_generics , #err_ty> {
+                    #trim_bytes
+                    match bytes {
+                        #(#byte_arms,)*
+                        _ => {}
+                    }
+
+                    match strum::__core::str::from_utf8(bytes) {
+                        Ok(s) => strum::__core::str::FromStr::from_str(s),
+                        Err(err) => {
+                            // Nothing matches invalid UTF-8, so the error gets the valid part.
+                            #[allow(unused_variables)]
+                            let s = strum::__core::str::from_utf8(&bytes[..err.valid_up_to()])
+                                .unwrap_or_default();
+                            Err(#not_found)
+                        }
+                    }
+                }
+            }
+
+            impl #ref_impl_generics strum::__core::convert::TryFrom<&'_strum str>
+                for #name #ty_generics #where_clause
+            {
+                type Error = #err_ty;
+                fn try_from(s: &'_strum str) -> Result< #name #ty_generics , #err_ty> {
+                    strum::__core::str::FromStr::from_str(s)
+                }
+            }
+
+            // `std::env::args_os` hands out strings that don't have to be UTF-8. Their encoded
+            // bytes are always a superset of UTF-8, so they can be matched like any other bytes.
+            strum::__if_std! {
+                impl #ref_impl_generics strum::__core::convert::TryFrom<&'_strum strum::__OsStr>
+                    for #name #ty_generics #where_clause
+                {
+                    type Error = #err_ty;
+                    fn try_from(s: &'_strum strum::__OsStr) -> Result<Self, Self::Error> {
+                        strum::__core::convert::TryFrom::try_from(s.as_encoded_bytes())
+                    }
+                }
+            }
+
+            strum::__if_alloc! {
+                impl #impl_generics strum::__core::convert::TryFrom<strum::__String>
+                    for #name #ty_generics #where_clause
+                {
+                    type Error = #err_ty;
+                    fn try_from(s: strum::__String) -> Result< #name #ty_generics , #err_ty> {
+                        strum::__core::str::FromStr::from_str(&s)
+                    }
+                }
+            }
+        }
+    } else {
+        quote!{}
+    };
+
+    let parse_impl = match borrowed {
+        Some(lifetime) => {
+            quote!{
+                impl #impl_generics strum::TryFromStr<#lifetime> for #name #ty_generics
+                    #where_clause
+                {
+                    type Error = #err_ty;
+                    fn try_from_str(s: &#lifetime str) -> Result< #name #ty_generics , #err_ty> {
+                        #trim
+                        #qualified
+                        #body
+                    }
+                }
+            }
+        }
+        None => {
+            quote!{
+                impl #impl_generics strum::__core::str::FromStr for #name #ty_generics
+                    #where_clause
+                {
+                    type Err = #err_ty;
+                    fn from_str(s: &str) -> Result< #name #ty_generics , #err_ty> {
+                        #trim
+                        #qualified
+                        #body
+                    }
+                }
+            }
+        }
+    };
+
+    quote!{
+        #parse_impl
+
+        #try_from
+
+        #char_conversions
+
+        #rich_error_def
+    }
+}
+
+/// Generates `TryFrom<char>` and `From<YourEnum> for char`, for enums whose serializations are all
+/// a single character.
+fn char_conversions(ast: &syn::DeriveInput,
+                    generics: &syn::Generics,
+                    parsers: &[(Vec<String>, bool, quote::Tokens)],
+                    err_ty: &quote::Tokens,
+                    not_found: &quote::Tokens,
+                    naming: Naming)
+                    -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("FromString only works on Enums"),
+    };
+
+    let from_char_arms = parsers.iter().map(|&(ref attrs, case_insensitive, ref value)| {
+        let chars = attrs.iter().map(|s| s.chars().next().unwrap()).collect::<Vec<_>>();
+        if case_insensitive {
+            quote!{ c if #(c.eq_ignore_ascii_case(&#chars))||* => Ok(#value) }
+        } else {
+            quote!{ #(#chars)|* => Ok(#value) }
+        }
+    });
+
+    let to_char_arms = variants.iter().map(|variant| {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+        let output = get_preferred_name(ident, &variant.attrs, naming).chars().next().unwrap();
+        quote!{ #name::#ident #params => #output }
+    });
+
+    quote!{
+        impl #impl_generics strum::__core::convert::TryFrom<char> for #name #ty_generics
+            #where_clause
+        {
+            type Error = #err_ty;
+            fn try_from(c: char) -> Result< #name #ty_generics , #err_ty> {
+                match c {
+                    #(#from_char_arms,)*
+                    _ => {
+                        let mut buf = [0; 4];
+                        #[allow(unused_variables)]
+                        let s: &str = c.encode_utf8(&mut buf);
+                        Err(#not_found)
+                    }
+                }
+            }
+        }
+
+        impl #impl_generics From<#name #ty_generics> for char #where_clause {
+            fn from(value: #name #ty_generics) -> char {
+                match value {
+                    #(#to_char_arms),*
+                }
+            }
+        }
+    }
+}
+
+/// Above this many serializations, `from_str` branches on the length of the input before comparing
+/// it against anything, so only the strings of that length are compared.
+const BUCKET_THRESHOLD: usize = 16;
+
+/// Generates a `from_str` body that matches on the input's length first and then only against the
+/// serializations with that length. Every comparison strum makes, including the case insensitive
+/// and separator insensitive ones, can only succeed between strings of the same length.
+fn from_string_buckets(parsers: &[(Vec<String>, bool, quote::Tokens)],
+                       default: &quote::Tokens,
+                       normalize_separators: bool)
+                       -> quote::Tokens {
+    let mut lengths = parsers.iter()
+        .flat_map(|parser| parser.0.iter().map(|attr| attr.len()))
+        .collect::<Vec<_>>();
+    lengths.sort();
+    lengths.dedup();
+
+    let buckets = lengths.iter().map(|&len| {
+        let mut arms = Vec::new();
+        for &(ref attrs, case_insensitive, ref value) in parsers {
+            let attrs = attrs.iter().filter(|attr| attr.len() == len).collect::<Vec<_>>();
+            if attrs.is_empty() {
+                continue;
+            }
+
+            if normalize_separators {
+                let case_insensitive = attrs.iter().map(|_| case_insensitive);
+                let attrs = attrs.iter();
+                arms.push(quote!{
+                    s if #(strum::__eq_ignore_separators(s, #attrs, #case_insensitive))||* => {
+                        return Ok(#value)
+                    }
+                });
+            } else if case_insensitive {
+                let attrs = attrs.iter();
+                arms.push(quote!{
+                    s if #(s.eq_ignore_ascii_case(#attrs))||* => return Ok(#value)
+                });
+            } else {
+                let attrs = attrs.iter();
+                arms.push(quote!{ #(#attrs)|* => return Ok(#value) });
+            }
+        }
+
+        quote!{
+            #len => match s {
+                #(#arms,)*
+                _ => {}
+            },
+        }
+    });
+
+    quote!{
+        match s.len() {
+            #(#buckets)*
+            _ => {}
+        }
+
+        match s {
+            #default
+        }
+    }
+}
+
+/// Above this many serializations, `from_str` binary searches sorted tables instead of matching.
+const TABLE_THRESHOLD: usize = 256;
+
+/// Generates a `from_str` body that binary searches for the input in sorted static tables, one for
+/// exact serializations and one for lowercased `ascii_case_insensitive` ones, and then builds the
+/// variant from its index. Nothing here grows a match by more than a handful of arms per variant.
+fn from_string_table(parsers: &[(Vec<String>, bool, quote::Tokens)],
+                     default: &quote::Tokens,
+                     ty: &quote::Tokens,
+                     normalize_separators: bool,
+                     generic: bool)
+                     -> quote::Tokens {
+    let mut sensitive = Vec::new();
+    let mut insensitive = Vec::new();
+    for (idx, &(ref attrs, case_insensitive, _)) in parsers.iter().enumerate() {
+        for attr in attrs {
+            if normalize_separators {
+                // Separators can be spelled several ways, so these are compared one by one.
+                insensitive.push((attr.clone(), idx));
+            } else if case_insensitive {
+                insensitive.push((attr.to_ascii_lowercase(), idx));
+            } else {
+                sensitive.push((attr.clone(), idx));
+            }
+        }
+    }
+
+    let mut statics = Vec::new();
+    let mut lookup = quote!{ None };
+    if !sensitive.is_empty() {
+        statics.push(table::sorted_table("SENSITIVE", sensitive));
+        lookup = quote!{
+            SENSITIVE.binary_search_by(|&(key, _)| key.cmp(s)).ok().map(|i| SENSITIVE[i].1)
+        };
+    }
+
+    if normalize_separators {
+        let case_insensitive = parsers.iter().map(|parser| parser.1);
+        statics.push(quote!{ static CASE_INSENSITIVE: &[bool] = &[#(#case_insensitive),*]; });
+        statics.push(table::sorted_table("INSENSITIVE", insensitive));
+        lookup = quote!{
+            #lookup.or_else(|| {
+                INSENSITIVE.iter()
+                    .find(|&&(key, idx)| {
+                        strum::__eq_ignore_separators(s, key, CASE_INSENSITIVE[idx])
+                    })
+                    .map(|&(_, idx)| idx)
+            })
+        };
+    } else if !insensitive.is_empty() {
+        statics.push(table::sorted_table("INSENSITIVE", insensitive));
+        lookup = quote!{
+            #lookup.or_else(|| {
+                INSENSITIVE.binary_search_by(|&(key, _)| {
+                        key.bytes().cmp(s.bytes().map(|b| b.to_ascii_lowercase()))
+                    })
+                    .ok()
+                    .map(|i| INSENSITIVE[i].1)
+            })
+        };
+    }
+
+    let values = parsers.iter().map(|parser| parser.2.clone()).collect::<Vec<_>>();
+    let value = table::index_lookup(&quote!{ idx }, ty, &values, generic);
+
+    quote!{
+        #(#statics)*
+
+        if let Some(idx) = #lookup {
+            if let Some(value) = #value {
+                return Ok(value);
+            }
+        }
+
+        match s {
+            #default
+        }
+    }
+}
+
+/// Generates a `from_str` body that finds the variant with a perfect hash table instead of
+/// comparing against every serialization. Exact matches are tried before case insensitive ones.
+fn from_string_phf(parsers: &[(Vec<String>, bool, quote::Tokens)],
+                   default: &quote::Tokens,
+                   ty: &quote::Tokens,
+                   generic: bool)
+                   -> quote::Tokens {
+    let mut sensitive: (Vec<String>, Vec<usize>) = (Vec::new(), Vec::new());
+    let mut insensitive: (Vec<String>, Vec<usize>) = (Vec::new(), Vec::new());
+    let mut seen = HashSet::new();
+    for (idx, &(ref attrs, case_insensitive, _)) in parsers.iter().enumerate() {
+        for attr in attrs {
+            let (keys, indices) = if case_insensitive {
+                (&mut insensitive.0, &mut insensitive.1)
+            } else {
+                (&mut sensitive.0, &mut sensitive.1)
+            };
+
+            // The first variant to claim a string wins, just like the arms of a match.
+            let key = if case_insensitive { attr.to_ascii_lowercase() } else { attr.clone() };
+            if seen.insert((case_insensitive, key.clone())) {
+                keys.push(key);
+                indices.push(idx);
+            }
+        }
+    }
+
+    let map = |keys: &[String], indices: &[usize], lowercase: bool| {
+        let table = phf::generate(keys, lowercase);
+        let key = table.key;
+        let disps = table.disps.iter().map(|&(d1, d2)| quote!{ (#d1, #d2) });
+        let entries = table.order.iter().map(|&i| {
+            let (k, v) = (&keys[i], indices[i]);
+            quote!{ (#k, #v) }
+        });
+
+        quote!{
+            strum::phf::Map {
+                key: #key,
+                disps: &[#(#disps),*],
+                entries: &[#(#entries),*],
+            }
+        }
+    };
+
+    let mut statics = Vec::new();
+    let mut lookup = quote!{ None };
+    if !sensitive.0.is_empty() {
+        let table = map(&sensitive.0, &sensitive.1, false);
+        statics.push(quote!{ static SENSITIVE: strum::phf::Map = #table; });
+        lookup = quote!{ SENSITIVE.get(s) };
+    }
+
+    if !insensitive.0.is_empty() {
+        let table = map(&insensitive.0, &insensitive.1, true);
+        statics.push(quote!{ static INSENSITIVE: strum::phf::Map = #table; });
+        lookup = quote!{ #lookup.or_else(|| INSENSITIVE.get_ignore_ascii_case(s)) };
+    }
+
+    let values = parsers.iter().map(|parser| parser.2.clone()).collect::<Vec<_>>();
+    let value = table::index_lookup(&quote!{ idx }, ty, &values, generic);
+
+    quote!{
+        #(#statics)*
+
+        if let Some(idx) = #lookup {
+            if let Some(value) = #value {
+                return Ok(value);
+            }
+        }
+
+        match s {
+            #default
+        }
+    }
+}
+
+fn enum_iter_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+
+    if !ast.generics.lifetimes.is_empty() {
+        panic!("Enum Iterator isn't supported on Enums with lifetimes. The resulting enums would \
+                be unbounded.");
+    }
+
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumIter only works on Enums"),
+    };
+
+    // Fields are filled in with `Default::default()`, so any field that mentions a type parameter
+    // needs a `Default` bound on the generated impls.
+    let gen = bound_generic_fields(ast, "EnumIter", "Default");
+
+    let (impl_generics, ty_generics, where_clause) = gen.split_for_impl();
+    let phantom_data = if !gen.ty_params.is_empty() {
+        let params = gen.ty_params.iter().map(|param| &param.ident);
+        quote!{ <(#(#params,)*)> }
+    } else {
+        quote! { < () > }
+    };
+
+    let values = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, "EnumIter"))
+        .map(|variant| {
+            let ident = &variant.ident;
+            let params = default_fields(variant);
+            quote!{ #name::#ident #params }
+        })
+        .collect::<Vec<_>>();
+    let variant_count = values.len();
+    let get = table::index_lookup(&quote!{ idx },
+                                  &quote!{ #name #ty_generics },
+                                  &values,
+                                  !gen.ty_params.is_empty());
+
+    // `first()` and `last()` are `const` when there are no fields to fill in with defaults.
+    let enabled = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, "EnumIter"))
+        .collect::<Vec<_>>();
+    let ends = match (enabled.first(), enabled.last()) {
+        (Some(first), Some(last)) => {
+            let end_fn = |fn_name: &str, variant: &syn::Variant| {
+                let fn_name = quote::Ident::from(fn_name);
+                let ident = &variant.ident;
+                let params = default_fields(variant);
+                let constness = if variant.data == syn::VariantData::Unit {
+                    quote!{ const }
+                } else {
+                    quote!{}
+                };
+                quote!{
+                    pub #constness fn #fn_name() -> #name #ty_generics {
+                        #name::#ident #params
+                    }
+                }
+            };
+            let first = end_fn("first", first);
+            let last = end_fn("last", last);
+            quote!{ #first #last }
+        }
+        _ => quote!{},
+    };
+
+    // Unit-only enums can also be iterated by reference out of a static array.
+    let unit_only = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, "EnumIter"))
+        .all(|variant| variant.data == syn::VariantData::Unit);
+    let iter_refs = if unit_only && gen.ty_params.is_empty() {
+        let values = variants.iter()
+            .filter(|variant| !is_disabled(&variant.attrs, "EnumIter"))
+            .map(|variant| {
+                let ident = &variant.ident;
+                quote!{ #name::#ident }
+            });
+        quote!{
+            impl #name {
+                pub fn iter_refs() -> strum::__core::slice::Iter<'static, #name> {
+                    static VARIANTS: &'static [#name] = &[#(#values),*];
+                    VARIANTS.iter()
+                }
+            }
+
+            impl strum::IntoEnumIterator for &'static #name {
+                type Iterator = strum::__core::slice::Iter<'static, #name>;
+                fn iter() -> strum::__core::slice::Iter<'static, #name> {
+                    #name::iter_refs()
+                }
+            }
+        }
+    } else {
+        quote!{}
+    };
+    // Where each variant falls among the iterated ones, as the position of the first iterated
+    // variant at or after it and the position just past it. Disabled variants are empty ranges.
+    let mut positions = Vec::new();
+    let mut position = 0usize;
+    for variant in variants {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+
+        let start = position;
+        if !is_disabled(&variant.attrs, "EnumIter") {
+            position += 1;
+        }
+        positions.push(quote!{ #name::#ident #params => (#start, #position) });
+    }
+
+    let iter_name = match unique_attr(&ast.attrs, "strum", "iter_name") {
+        Some(iter_name) => quote::Ident::from(iter_name),
+        None => quote::Ident::from(&*format!("{}Iter", name)),
+    };
+    let iter_name_str = iter_name.as_ref();
+
+    // `iter()` gets the same visibility so it never exposes a more private type. The iterator
+    // still can't be more private than the enum because it's named by the trait impl.
+    let (vis, fn_vis) = match unique_attr(&ast.attrs, "strum", "iter_vis") {
+        Some(iter_vis) => {
+            let iter_vis = quote::Ident::from(iter_vis);
+            (quote!{ #iter_vis }, quote!{ #iter_vis })
+        }
+        None => (quote!{ #vis }, quote!{ pub }),
+    };
+    let range = quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Iterates over the variants within `range`, in declaration order.
+            #fn_vis fn range<R>(range: R) -> #iter_name #ty_generics
+                where R: strum::__core::ops::RangeBounds<#name #ty_generics>
+            {
+                // A closure rather than a helper fn so nothing else is added to the enum.
+                let positions = |value: &#name #ty_generics| -> (usize, usize) {
+                    match *value {
+                        #(#positions),*
+                    }
+                };
+                let start = match range.start_bound() {
+                    strum::__core::ops::Bound::Included(value) => positions(value).0,
+                    strum::__core::ops::Bound::Excluded(value) => positions(value).1,
+                    strum::__core::ops::Bound::Unbounded => 0,
+                };
+                let end = match range.end_bound() {
+                    strum::__core::ops::Bound::Included(value) => positions(value).1,
+                    strum::__core::ops::Bound::Excluded(value) => positions(value).0,
+                    strum::__core::ops::Bound::Unbounded => #variant_count,
+                };
+                #iter_name {
+                    idx: start,
+                    back_idx: #variant_count - strum::__core::cmp::max(start, end),
+                    marker: strum::__core::marker::PhantomData,
+                }
+            }
+        }
+    };
+
+    quote!{
+        #vis struct #iter_name #ty_generics {
+            idx: usize,
+            back_idx: usize,
+            marker: strum::__core::marker::PhantomData #phantom_data,
+        }
+
+        impl #impl_generics #iter_name #ty_generics #where_clause {
+            fn get(&self, idx: usize) -> Option<#name #ty_generics> {
+                #get
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #fn_vis fn iter() -> #iter_name #ty_generics {
+                #iter_name {
+                    idx: 0,
+                    back_idx: 0,
+                    marker: strum::__core::marker::PhantomData,
+                }
+            }
+
+            #ends
+        }
+
+        #range
+
+        impl #impl_generics strum::IntoEnumIterator for #name #ty_generics #where_clause {
+            type Iterator = #iter_name #ty_generics;
+            fn iter() -> #iter_name #ty_generics {
+                #name::iter()
+            }
+        }
+
+        impl #impl_generics Iterator for #iter_name #ty_generics #where_clause {
+            type Item = #name #ty_generics;
+
+            fn next(&mut self) -> Option<#name #ty_generics> {
+                if self.idx + self.back_idx >= #variant_count {
+                    return None;
+                }
+
+                let output = self.get(self.idx);
+                self.idx += 1;
+                output
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = #variant_count - (self.idx + self.back_idx);
+                (len, Some(len))
+            }
+
+            fn nth(&mut self, n: usize) -> Option<#name #ty_generics> {
+                if n >= self.len() {
+                    self.idx = #variant_count - self.back_idx;
+                    return None;
+                }
+
+                self.idx += n + 1;
+                self.get(self.idx - 1)
+            }
+
+            fn count(self) -> usize {
+                self.len()
+            }
+
+            fn last(mut self) -> Option<#name #ty_generics> {
+                self.next_back()
+            }
+        }
+
+        impl #impl_generics DoubleEndedIterator for #iter_name #ty_generics #where_clause {
+            fn next_back(&mut self) -> Option<#name #ty_generics> {
+                if self.idx + self.back_idx >= #variant_count {
+                    return None;
+                }
+
+                self.back_idx += 1;
+                self.get(#variant_count - self.back_idx)
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<#name #ty_generics> {
+                if n >= self.len() {
+                    self.back_idx = #variant_count - self.idx;
+                    return None;
+                }
+
+                self.back_idx += n + 1;
+                self.get(#variant_count - self.back_idx)
+            }
+        }
+
+        impl #impl_generics ExactSizeIterator for #iter_name #ty_generics #where_clause {
+            fn len(&self) -> usize {
+                self.size_hint().0
+            }
+        }
+
+        impl #impl_generics strum::__core::iter::FusedIterator for #iter_name #ty_generics
+            #where_clause
+        {}
+
+        impl #impl_generics Clone for #iter_name #ty_generics #where_clause {
+            fn clone(&self) -> #iter_name #ty_generics {
+                #iter_name {
+                    idx: self.idx,
+                    back_idx: self.back_idx,
+                    marker: strum::__core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl #impl_generics strum::__core::fmt::Debug for #iter_name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut strum::__core::fmt::Formatter) -> strum::__core::fmt::Result {
+                f.debug_struct(#iter_name_str)
+                    .field("len", &self.len())
+                    .finish()
+            }
+        }
+
+        #iter_refs
+    }
+}
+
+fn enum_rotate_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumRotate only works on Enums"),
+    };
+
+    let enabled = variants.iter()
+        .map(|variant| !is_disabled(&variant.attrs, "EnumRotate"))
+        .collect::<Vec<_>>();
+    if !enabled.contains(&true) {
+        panic!("EnumRotate needs at least one variant that isn't disabled");
+    }
+
+    // Each variant steps to the nearest enabled variant in that direction, wrapping around at
+    // either end. Disabled variants still step, they're just never stepped to.
+    let count = variants.len();
+    let step = |from: usize, forward: bool| {
+        (1..count + 1)
+            .map(|offset| if forward {
+                (from + offset) % count
+            } else {
+                (from + count - offset) % count
+            })
+            .find(|&idx| enabled[idx])
+            .unwrap()
+    };
+
+    let mut next_arms = Vec::new();
+    let mut previous_arms = Vec::new();
+    for (idx, variant) in variants.iter().enumerate() {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+
+        let next = &variants[step(idx, true)];
+        let next_ident = &next.ident;
+        let next_params = default_fields(next);
+        next_arms.push(quote!{ #name::#ident #params => #name::#next_ident #next_params });
+
+        let previous = &variants[step(idx, false)];
+        let previous_ident = &previous.ident;
+        let previous_params = default_fields(previous);
+        previous_arms.push(quote!{
+            #name::#ident #params => #name::#previous_ident #previous_params
+        });
+    }
+
+    // Fields are filled in with `Default::default()`, the same way `EnumIter` does it.
+    let generics = bound_generic_fields(ast, "EnumRotate", "Default");
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// The variant after this one in declaration order, wrapping around to the first.
+            pub fn next(self) -> #name #ty_generics {
+                match self {
+                    #(#next_arms),*
+                }
+            }
+
+            /// The variant before this one in declaration order, wrapping around to the last.
+            pub fn previous(self) -> #name #ty_generics {
+                match self {
+                    #(#previous_arms),*
+                }
+            }
+        }
+    }
+}
+
+fn enum_message_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumHelp only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+    let layout = shared_table::layout(ast, naming);
+
+    let mut arms = Vec::new();
+    let mut detailed_arms = Vec::new();
+    let mut serializations = Vec::new();
+    let mut documentation_arms = Vec::new();
+    let mut owned_arms = Vec::new();
+    let mut locale_arms = Vec::new();
+    let mut key_arms = Vec::new();
+
+    for (idx, variant) in variants.iter().enumerate() {
+        let messages = unique_attr(&variant.attrs, "strum", "message");
+        let detailed_messages = unique_attr(&variant.attrs, "strum", "detailed_message");
+        let ident = &variant.ident;
+
+        use syn::VariantData::*;
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+
+        // You can't disable getting the serializations.
+        if let Some(ref layout) = layout {
+            let table = shared_table::table(ast);
+            let start = layout.variants[idx].start;
+            let end = start + layout.variants[idx].serializations;
+            serializations.push(quote!{ &#name::#ident #params => &#table[#start..#end] });
+        } else {
+            let serialization_variants = get_serializations(ident, &variant.attrs, naming);
+            let count = serialization_variants.len();
+            serializations.push(quote!{
+                &#name::#ident #params => {
+                    static ARR: [&'static str; #count] = [#(#serialization_variants),*];
+                    &ARR
+                }
+            });
+        }
+
+        // But you can disable the messages.
+        if is_disabled(&variant.attrs, "EnumMessage") {
+            continue;
+        }
+
+        if let Some(msg) = messages {
+            let (template, fields) = parse_message_template(msg);
+            if !fields.is_empty() {
+                owned_arms.push(message_template_arm(name, variant, &template, &fields));
+            }
+
+            let params = params.clone();
+
+            // Push the simple message.
+            let tokens = quote!{ &#name::#ident #params => Some(#msg) };
+            arms.push(tokens.clone());
+
+            if detailed_messages.is_none() {
+                detailed_arms.push(tokens);
+            }
+        }
+
+        if let Some(msg) = detailed_messages {
+            let params = params.clone();
+            // Push the simple message.
+            detailed_arms.push(quote!{ &#name::#ident #params => Some(#msg) });
+        }
+
+        if let Some(key) = unique_attr(&variant.attrs, "strum", "message_key") {
+            key_arms.push(quote!{ &#name::#ident #params => Some(#key) });
+        }
+
+        let locales = get_message_locales(&variant.attrs);
+        if !locales.is_empty() {
+            let langs = locales.iter().map(|&(lang, _)| lang);
+            let msgs = locales.iter().map(|&(_, msg)| msg);
+            locale_arms.push(quote!{
+                &#name::#ident #params => match lang {
+                    #(#langs => Some(#msgs),)*
+                    _ => self.get_message(),
+                }
+            });
+        }
+
+        if let Some(doc) = get_documentation(&variant.attrs) {
+            documentation_arms.push(quote!{ &#name::#ident #params => Some(#doc) });
+        }
+    }
+
+    if arms.len() < variants.len() {
+        arms.push(quote!{ _ => None });
+    }
+
+    if detailed_arms.len() < variants.len() {
+        detailed_arms.push(quote!{ _ => None });
+    }
+
+    if documentation_arms.len() < variants.len() {
+        documentation_arms.push(quote!{ _ => None });
+    }
+
+    if key_arms.len() < variants.len() {
+        key_arms.push(quote!{ _ => None });
+    }
+
+    // Only emitted when it's needed, because it requires strum's alloc feature.
+    let get_message_owned = if owned_arms.is_empty() {
+        quote!{}
+    } else {
+        quote!{
+            fn get_message_owned(&self) -> Option<strum::__String> {
+                match self {
+                    #(#owned_arms,)*
+                    _ => self.get_message().map(strum::__String::from),
+                }
+            }
+        }
+    };
+
+    quote!{
+        impl #impl_generics strum::EnumMessage for #name #ty_generics #where_clause {
+            fn get_message(&self) -> Option<&str> {
+                match self {
+                    #(#arms),*
+                }
+            }
+
+            fn get_detailed_message(&self) -> Option<&str> {
+                match self {
+                    #(#detailed_arms),*
+                }
+            }
+
+            fn get_serializations(&self) -> &[&str] {
+                match self {
+                    #(#serializations),*
+                }
+            }
+
+            fn get_documentation(&self) -> Option<&'static str> {
+                match self {
+                    #(#documentation_arms),*
+                }
+            }
+
+            fn get_message_key(&self) -> Option<&'static str> {
+                match self {
+                    #(#key_arms),*
+                }
+            }
+
+            fn get_message_lang(&self, lang: &str) -> Option<&str> {
+                match self {
+                    #(#locale_arms,)*
+                    _ => self.get_message(),
+                }
+            }
+
+            #get_message_owned
+        }
+    }
+}
+
+/// Builds the pattern that binds the fields used by a `message` or `to_string` template, along
+/// with the `format_args!` call that fills the template in.
+fn template_format_args(variant: &syn::Variant,
+                        attr: &str,
+                        template: &str,
+                        fields: &[String])
+                        -> (quote::Ident, quote::Tokens) {
+    let ident = &variant.ident;
+    let missing = |field: &str| -> ! {
+        panic!("{} on {} refers to {{{}}}, which isn't a field of the variant", attr, ident, field)
+    };
+
+    use syn::VariantData::*;
+    let pattern = match variant.data {
+        Unit => missing(&fields[0]),
+        Tuple(ref tuple_fields) => {
+            let in_range = |field: &&String| {
+                matches!(field[1..].parse::<usize>(), Ok(i) if i < tuple_fields.len())
+            };
+            if let Some(field) = fields.iter().find(|field| !in_range(field)) {
+                missing(field.strip_prefix('_').unwrap_or(field));
+            }
+
+            let bindings = (0..tuple_fields.len())
+                .map(|i| {
+                    let binding = format!("_{}", i);
+                    if fields.contains(&binding) { format!("ref {}", binding) } else { "_".into() }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            quote::Ident::from(&*format!("({})", bindings))
+        }
+        Struct(ref struct_fields) => {
+            if let Some(field) = fields.iter()
+                .find(|field| !struct_fields.iter().any(|f| f.ident.as_ref().unwrap() == *field)) {
+                missing(field);
+            }
+
+            let bindings = fields.iter()
+                .map(|field| format!("ref {}, ", field))
+                .collect::<String>();
+            quote::Ident::from(&*format!("{{ {}.. }}", bindings))
+        }
+    };
+
+    let args = fields.iter().map(|field| {
+        let field = quote::Ident::from(&**field);
+        quote!{ #field = #field }
+    });
+    (pattern, quote!{ format_args!(#template, #(#args),*) })
+}
+
+/// Builds the `get_message_owned` arm that formats a message template with the variant's fields.
+fn message_template_arm(name: &syn::Ident,
+                        variant: &syn::Variant,
+                        template: &str,
+                        fields: &[String])
+                        -> quote::Tokens {
+    let ident = &variant.ident;
+    let (pattern, format_args) = template_format_args(variant, "message", template, fields);
+    quote!{
+        &#name::#ident #pattern => {
+            let mut output = strum::__String::new();
+            let _ = strum::__core::fmt::Write::write_fmt(&mut output, #format_args);
+            Some(output)
+        }
+    }
+}
+
+fn display_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("Display only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+
+    let names = preferred_names(ast, naming);
+    let mut arms = Vec::new();
+    let mut bounds = Vec::new();
+    for (variant, output) in variants.iter().zip(&names) {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+
+        if is_disabled(&variant.attrs, "Display") {
+            continue;
+        }
+
+        if extract_flag(&variant.attrs, "strum", "transparent").unwrap_or(false) {
+            let pattern = match variant.data {
+                Tuple(ref fields) if fields.len() == 1 => quote::Ident::from("(ref inner)"),
+                Struct(ref fields) if fields.len() == 1 => {
+                    let field = fields[0].ident.as_ref().unwrap();
+                    quote::Ident::from(&*format!("{{ {}: ref inner }}", field))
+                }
+                _ => panic!("transparent can only be used on variants with exactly one field"),
+            };
+            bounds.push((&variant.data.fields()[0].ty, "strum::__core::fmt::Display"));
+
+            arms.push(quote!{
+                &#name::#ident #pattern => strum::__core::fmt::Display::fmt(inner, f)
+            });
+            continue;
+        }
+
+        // A `to_string` on a variant with data can fill in the fields, e.g. "{0} items".
+        if variant.data != Unit {
+            if let Some(to_string) = unique_attr(&variant.attrs, "strum", "to_string") {
+                let (template, fields) = parse_message_template(to_string);
+                if !fields.is_empty() {
+                    let (pattern, format_args) =
+                        template_format_args(variant, "to_string", &template, &fields);
+                    for (field, format_trait) in template_traits(to_string) {
+                        if let Some(ty) = template_field_ty(variant, &field) {
+                            bounds.push((ty, format_trait));
+                        }
+                    }
+                    arms.push(quote!{ &#name::#ident #pattern => f.write_fmt(#format_args) });
+                    continue;
+                }
+            }
+        }
+
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+
+        arms.push(quote!{ &#name::#ident #params => f.pad(#output) });
+    }
+
+    if arms.len() < variants.len() {
+        arms.push(quote!{ _ => panic!("fmt() called on disabled variant.") });
+    }
+
+    let generics = bound_generic_types(ast, &bounds);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    quote!{
+        impl #impl_generics strum::__core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut strum::__core::fmt::Formatter) -> strum::__core::fmt::Result {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Writes what `Display` prints into `w`, without needing a `String`.
+            pub fn write_str<W: strum::__core::fmt::Write>(&self, w: &mut W)
+                -> strum::__core::fmt::Result
+            {
+                strum::__core::fmt::Write::write_fmt(w, format_args!("{}", self))
+            }
+        }
+    }
+}
+
+fn enum_count_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let n = match ast.body {
+        syn::Body::Enum(ref v) => v.len(),
+        _ => panic!("EnumCount only works on Enums"),
+    };
+
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub const COUNT: usize = #n;
+        }
+
+        impl #impl_generics strum::EnumCount for #name #ty_generics #where_clause {
+            const COUNT: usize = #n;
+        }
+    }
+}
+
+fn enum_index_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumIndex only works on Enums"),
+    };
+
+    let mut arms = Vec::new();
+    for (idx, variant) in variants.iter().enumerate() {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+
+        arms.push(quote!{ #name::#ident #params => #idx });
+    }
+    let count = variants.len();
+
+    // The array impls need a parameter for the element type in addition to the enum's generics.
+    let mut array_generics = ast.generics.clone();
+    array_generics.ty_params.push(syn::TyParam {
+        attrs: Vec::new(),
+        ident: syn::Ident::new("__StrumT"),
+        bounds: Vec::new(),
+        default: None,
+    });
+    let (array_impl_generics, _, _) = array_generics.split_for_impl();
+
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub const fn to_index(&self) -> usize {
+                match *self {
+                    #(#arms),*
+                }
+            }
+        }
+
+        impl #impl_generics strum::EnumIndex for #name #ty_generics #where_clause {
+            fn to_index(&self) -> usize {
+                #name::to_index(self)
+            }
+        }
+
+        impl #array_impl_generics strum::__core::ops::Index<#name #ty_generics>
+            for [__StrumT; #count] #where_clause
+        {
+            type Output = __StrumT;
+
+            fn index(&self, idx: #name #ty_generics) -> &__StrumT {
+                &self[idx.to_index()]
+            }
+        }
+
+        impl #array_impl_generics strum::__core::ops::IndexMut<#name #ty_generics>
+            for [__StrumT; #count] #where_clause
+        {
+            fn index_mut(&mut self, idx: #name #ty_generics) -> &mut __StrumT {
+                &mut self[idx.to_index()]
+            }
+        }
+    }
+}
+
+fn enum_discriminants_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumDiscriminants only works on Enums"),
+    };
+
+    // `#[strum_discriminants(derive(..), name(..), vis="..")]` customizes the generated enum. The
+    // visibility is a string, like `iter_vis`, because `pub` can't be parsed as a nested word.
+    // `vis(..)` is accepted too, and turned into the string by `quote_discriminants_vis`.
+    // `forward_attrs(..)` lists the attributes that are copied from each variant.
+    let mut derives = ["Clone", "Copy", "Debug", "PartialEq", "Eq", "Hash"]
+        .iter()
+        .map(|derive| syn::Ident::new(*derive))
+        .collect::<Vec<_>>();
+    let mut custom_name = None;
+    let mut custom_vis = None;
+    let mut forwarded = Vec::new();
+    let options = ast.attrs
+        .iter()
+        .filter_map(|attribute| {
+            use syn::MetaItem::*;
+            if let List(ref i, ref nested) = attribute.value {
+                if i == "strum_discriminants" { Some(nested) } else { None }
+            } else {
+                None
+            }
+        })
+        .flatten();
+    for option in options {
+        use syn::NestedMetaItem::*;
+        use syn::MetaItem::*;
+        match *option {
+            MetaItem(List(ref i, ref items)) if i == "derive" => {
+                for item in items {
+                    match *item {
+                        MetaItem(Word(ref derive)) => {
+                            if !derives.contains(derive) {
+                                derives.push(derive.clone());
+                            }
+                        }
+                        _ => panic!("strum_discriminants(derive(..)) expects a list of derives"),
+                    }
+                }
+            }
+            MetaItem(List(ref i, ref items)) if i == "name" => {
+                match (items.len(), items.first()) {
+                    (1, Some(&MetaItem(Word(ref ident)))) if custom_name.is_none() => {
+                        custom_name = Some(ident.clone());
+                    }
+                    (1, Some(&MetaItem(Word(_)))) => {
+                        panic!("More than one property: name found on strum_discriminants")
+                    }
+                    _ => panic!("strum_discriminants(name(..)) expects a single identifier"),
+                }
+            }
+            MetaItem(List(ref i, ref items)) if i == "forward_attrs" => {
+                for item in items {
+                    match *item {
+                        MetaItem(Word(ref attr)) => forwarded.push(attr.as_ref()),
+                        _ => {
+                            panic!("strum_discriminants(forward_attrs(..)) expects a list of \
+                                    attribute names")
+                        }
+                    }
+                }
+            }
+            MetaItem(NameValue(ref i, syn::Lit::Str(ref value, ..))) if i == "vis" => {
+                if custom_vis.is_some() {
+                    panic!("More than one property: vis found on strum_discriminants");
+                }
+                custom_vis = Some(quote::Ident::from(&**value));
+            }
+            _ => {
+                panic!("strum_discriminants expects derive(..), name(..), vis(..) or \
+                        forward_attrs(..)")
+            }
+        }
+    }
+
+    let discriminants_name = match custom_name {
+        Some(ident) => quote::Ident::from(ident.as_ref()),
+        None => quote::Ident::from(&*format!("{}Discriminants", name)),
+    };
+    let vis = custom_vis.map(|vis| quote!{ #vis }).unwrap_or_else(|| quote!{ #vis });
+
+    let mut discriminants = Vec::new();
+    let mut arms = Vec::new();
+    for variant in variants {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+
+        let attrs = variant.attrs
+            .iter()
+            .filter(|attr| forwarded.contains(&attr.name()));
+        discriminants.push(quote!{ #(#attrs)* #ident });
+        arms.push(quote!{ &#name::#ident #params => #discriminants_name::#ident });
+    }
+
+    // If other crates can't match the enum exhaustively, they shouldn't be able to match its
+    // discriminants exhaustively either, or adding a variant would break them all the same.
+    let non_exhaustive = ast.attrs.iter().filter(|attr| attr.name() == "non_exhaustive");
+
+    // The impl for references needs its own lifetime in addition to the enum's generics.
+    let mut ref_generics = ast.generics.clone();
+    ref_generics.lifetimes.insert(0, syn::LifetimeDef::new("'_enum"));
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+
+    quote!{
+        #[derive(#(#derives),*)]
+        #(#non_exhaustive)*
+        #vis enum #discriminants_name {
+            #(#discriminants),*
+        }
+
+        impl #ref_impl_generics From<&'_enum #name #ty_generics> for #discriminants_name #where_clause {
+            fn from(val: &'_enum #name #ty_generics) -> #discriminants_name {
+                match val {
+                    #(#arms),*
+                }
+            }
+        }
+
+        impl #impl_generics From<#name #ty_generics> for #discriminants_name #where_clause {
+            fn from(val: #name #ty_generics) -> #discriminants_name {
+                #discriminants_name::from(&val)
+            }
+        }
+
+        impl #impl_generics strum::IntoDiscriminant for #name #ty_generics #where_clause {
+            type Discriminant = #discriminants_name;
+
+            fn discriminant(&self) -> #discriminants_name {
+                #discriminants_name::from(self)
+            }
+        }
+    }
+}
+
+/// A `DISCRIMINANT_n` constant for every variant, so matches can compare against them.
+fn discriminant_constants(variants: &[syn::Variant], repr: &quote::Tokens) -> Vec<quote::Tokens> {
+    variants.iter()
+        .enumerate()
+        .map(|(idx, variant)| {
+            // Discriminants count up from the last explicit value, just like the compiler does.
+            let const_name = quote::Ident::from(&*format!("DISCRIMINANT_{}", idx));
+            let value = match variant.discriminant {
+                Some(ref expr) => quote!{ #expr },
+                None if idx == 0 => quote!{ 0 },
+                None => {
+                    let prev = quote::Ident::from(&*format!("DISCRIMINANT_{}", idx - 1));
+                    quote!{ #prev + 1 }
+                }
+            };
+            quote!{ const #const_name: #repr = #value; }
+        })
+        .collect()
+}
+
+fn from_repr_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("FromRepr only works on Enums"),
+    };
+
+    let repr_type = get_repr_type(&ast.attrs);
+    let repr = match repr_type {
+        Some(ty) => quote!{ #ty },
+        None => quote!{ usize },
+    };
+
+    let constants = discriminant_constants(variants, &repr);
+    let mut arms = Vec::new();
+    let mut to_repr_arms = Vec::new();
+    for (idx, variant) in variants.iter().enumerate() {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let const_name = quote::Ident::from(&*format!("DISCRIMINANT_{}", idx));
+
+        let fields = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+        to_repr_arms.push(quote!{ #name::#ident #fields => #const_name });
+
+        if is_disabled(&variant.attrs, "FromRepr") {
+            continue;
+        }
+
+        let params = default_fields(variant);
+
+        arms.push(quote!{ #const_name => Some(#name::#ident #params) });
+    }
+
+    arms.push(quote!{ _ => None });
+
+    // Only enums that spell out their integer representation get conversions to and from it.
+    let explicit = repr_type.is_some() || variants.iter().any(|v| v.discriminant.is_some());
+    let conversions = if explicit {
+        let constants = constants.iter();
+        quote!{
+            impl #impl_generics From<#name #ty_generics> for #repr #where_clause {
+                fn from(value: #name #ty_generics) -> #repr {
+                    #(#constants)*
+
+                    match value {
+                        #(#to_repr_arms),*
+                    }
+                }
+            }
+
+            impl #impl_generics strum::__core::convert::TryFrom<#repr> for #name #ty_generics
+                #where_clause
+            {
+                type Error = strum::FromReprError<#repr>;
+
+                fn try_from(value: #repr) -> Result<#name #ty_generics, Self::Error> {
+                    #name::from_repr(value).ok_or(strum::FromReprError { value })
+                }
+            }
+        }
+    } else {
+        quote!{}
+    };
+
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub fn from_repr(discriminant: #repr) -> Option<#name #ty_generics> {
+                #(#constants)*
+
+                match discriminant {
+                    #(#arms),*
+                }
+            }
+        }
+
+        #conversions
+    }
+}
+
+/// Returns what each variant is converted into by `Display` and `IntoStaticStr`. Usually that's the
+/// string itself, but with `#[strum(shared_table)]` it's read out of the enum's shared table.
+fn preferred_names(ast: &syn::DeriveInput, naming: Naming) -> Vec<quote::Tokens> {
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => return Vec::new(),
+    };
+
+    if let Some(layout) = shared_table::layout(ast, naming) {
+        let table = shared_table::table(ast);
+        return layout.variants
+            .iter()
+            .map(|strings| {
+                let preferred = strings.preferred;
+                quote!{ #table[#preferred] }
+            })
+            .collect();
+    }
+
+    variants.iter()
+        .map(|variant| {
+            let output = get_preferred_name(&variant.ident, &variant.attrs, naming);
+            quote!{ #output }
+        })
+        .collect()
+}
+
+fn variant_names_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumVariantNames only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+
+    // With `shared_table`, the names are indices into the table this derive adds.
+    let layout = shared_table::layout(ast, naming);
+    let names = preferred_names(ast, naming)
+        .into_iter()
+        .zip(variants)
+        .filter(|&(_, variant)| !is_disabled(&variant.attrs, "EnumVariantNames"))
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    let table = layout.map(|layout| shared_table::table_impl(ast, &layout));
+
+    quote!{
+        impl #impl_generics strum::VariantNames for #name #ty_generics #where_clause {
+            const VARIANTS: &'static [&'static str] = &[#(#names),*];
+        }
+
+        #table
+    }
+}
+
+fn variant_array_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("VariantArray only works on Enums"),
+    };
+
+    let mut values = Vec::new();
+    for variant in variants {
+        let ident = &variant.ident;
+        if variant.data != syn::VariantData::Unit {
+            panic!("VariantArray only works on Enums with unit variants");
+        }
+
+        if is_disabled(&variant.attrs, "VariantArray") {
+            continue;
+        }
+
+        values.push(quote!{ #name::#ident });
+    }
+
+    quote!{
+        impl #impl_generics strum::VariantArray for #name #ty_generics #where_clause {
+            const VARIANTS: &'static [#name #ty_generics] = &[#(#values),*];
+        }
+    }
+}
+
+fn enum_set_type_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumSetType only works on Enums"),
+    };
+
+    let mut to_index = Vec::new();
+    let mut from_index = Vec::new();
+    for (idx, variant) in variants.iter().enumerate() {
+        let ident = &variant.ident;
+        if variant.data != syn::VariantData::Unit {
+            panic!("EnumSetType only works on Enums with unit variants");
+        }
+
+        to_index.push(quote!{ #name::#ident => #idx });
+        from_index.push(quote!{ #idx => Some(#name::#ident) });
     }
+    from_index.push(quote!{ _ => None });
 
-    curr.pop()
-}
+    // The smallest storage with a bit for every variant.
+    let count = variants.len();
+    let repr = match count {
+        0..=64 => quote!{ u64 },
+        65..=128 => quote!{ u128 },
+        _ => {
+            let words = quote::Ident::from(format!("{}", count.div_ceil(64)));
+            quote!{ [u64; #words] }
+        }
+    };
 
-fn is_disabled(attrs: &[Attribute]) -> bool {
-    let v = extract_attrs(attrs, "strum", "disabled");
-    match v.len() {
-        0 => false,
-        1 => v[0] == "true",
-        _ => panic!("Can't have multiple values for 'disabled'"),
+    quote!{
+        impl #impl_generics strum::EnumSetType for #name #ty_generics #where_clause {
+            type Repr = #repr;
+            const VARIANT_COUNT: usize = #count;
+
+            fn to_index(self) -> usize {
+                match self {
+                    #(#to_index),*
+                }
+            }
+
+            fn from_index(idx: usize) -> Option<Self> {
+                match idx {
+                    #(#from_index),*
+                }
+            }
+        }
     }
 }
 
-fn from_string_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+fn into_static_str_inner(ast: &syn::DeriveInput) -> quote::Tokens {
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let variants = match ast.body {
         syn::Body::Enum(ref v) => v,
-        _ => panic!("FromString only works on Enums"),
+        _ => panic!("IntoStaticStr only works on Enums"),
     };
+    let naming = Naming::from_attrs(&ast.attrs);
 
-    let mut has_default = false;
-    let mut default = quote! { _ => Err(strum::ParseError::VariantNotFound) };
+    let names = preferred_names(ast, naming);
     let mut arms = Vec::new();
-    for variant in variants {
+    let mut eq_arms = Vec::new();
+    let mut max_len = 0;
+    for (variant, output) in variants.iter().zip(&names) {
         use syn::VariantData::*;
         let ident = &variant.ident;
 
-        // Look at all the serialize attributes.
-        let mut attrs = extract_attrs(&variant.attrs, "strum", "serialize");
-        if is_disabled(&variant.attrs) {
+        if is_disabled(&variant.attrs, "IntoStaticStr") {
             continue;
         }
 
-        if let Some("true") = unique_attr(&variant.attrs, "strum", "default") {
-            if has_default {
-                panic!("Can't have multiple default variants");
-            }
+        let len = get_preferred_name(ident, &variant.attrs, naming).len();
+        max_len = std::cmp::max(max_len, len);
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
 
-            if let Tuple(ref fields) = variant.data {
-                if fields.len() != 1 {
-                    panic!("Default only works on unit structs with a single String parameter");
-                }
+        arms.push(quote!{ &#name::#ident #params => #output });
+        eq_arms.push(quote!{ &#name::#ident #params => other == #output });
+    }
 
-                default = quote!{
-                    default => Ok(#name::#ident (default.into()))
-                };
-            } else {
-                panic!("Default only works on unit structs with a single String parameter");
-            }
+    if arms.len() < variants.len() {
+        arms.push(quote!{ _ => panic!("into() called on disabled variant.") });
+        eq_arms.push(quote!{ _ => false });
+    }
 
-            has_default = true;
-            continue;
-        }
+    let mut ref_generics = ast.generics.clone();
+    ref_generics.lifetimes.insert(0, syn::LifetimeDef::new("'_enum"));
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
 
-        // If we don't have any custom variants, add the default name.
-        if attrs.len() == 0 {
-            attrs.push(ident.as_ref());
+    let const_fns = if extract_flag(&ast.attrs, "strum", "const_into_str").unwrap_or(false) {
+        const_str_fns(ast, variants, &arms, naming)
+    } else {
+        quote!{}
+    };
+
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// The length in bytes of the longest string this enum converts to.
+            pub const MAX_SERIALIZED_LEN: usize = #max_len;
         }
 
-        let params = match variant.data {
-            Unit => quote::Ident::from(""),
-            Tuple(ref fields) => {
-                let default = fields.iter()
-                    .map(|_| "Default::default()")
-                    .collect::<Vec<_>>()
-                    .join(", ");
+        impl #ref_impl_generics From<&'_enum #name #ty_generics> for &'static str #where_clause {
+            fn from(val: &'_enum #name #ty_generics) -> &'static str {
+                match val {
+                    #(#arms),*
+                }
+            }
+        }
 
-                quote::Ident::from(&*format!("({})", default))
+        impl #impl_generics From<#name #ty_generics> for &'static str #where_clause {
+            fn from(val: #name #ty_generics) -> &'static str {
+                From::from(&val)
             }
-            Struct(ref fields) => {
-                let default = fields.iter()
-                    .map(|field| {
-                        format!("{}:{}", field.ident.as_ref().unwrap(), "Default::default()")
-                    })
-                    .collect::<Vec<_>>()
-                    .join(", ");
+        }
 
-                quote::Ident::from(&*format!("{{{}}}", default))
+        impl #impl_generics PartialEq<str> for #name #ty_generics #where_clause {
+            fn eq(&self, other: &str) -> bool {
+                match self {
+                    #(#eq_arms),*
+                }
             }
-        };
+        }
 
-        arms.push(quote!{ #(#attrs)|* => Ok(#name::#ident #params) });
-    }
+        impl #ref_impl_generics PartialEq<&'_enum str> for #name #ty_generics #where_clause {
+            fn eq(&self, other: &&'_enum str) -> bool {
+                <#name #ty_generics as PartialEq<str>>::eq(self, *other)
+            }
+        }
 
-    arms.push(default);
+        impl #impl_generics PartialEq<#name #ty_generics> for str #where_clause {
+            fn eq(&self, other: &#name #ty_generics) -> bool {
+                <#name #ty_generics as PartialEq<str>>::eq(other, self)
+            }
+        }
 
-    quote!{
-        impl #impl_generics std::str::FromStr for #name #ty_generics #where_clause {
-            type Err = strum::ParseError;
-            fn from_str(s: &str) -> Result< #name #ty_generics , strum::ParseError> {
-                match s {
-                    #(#arms),*
-                }
+        impl #ref_impl_generics PartialEq<#name #ty_generics> for &'_enum str #where_clause {
+            fn eq(&self, other: &#name #ty_generics) -> bool {
+                <#name #ty_generics as PartialEq<str>>::eq(other, *self)
             }
         }
+
+        #const_fns
     }
 }
 
-fn enum_iter_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+/// Generates `const fn as_str` and, when every variant is a unit variant, `const fn from_str_const`
+/// so the conversions can be used in `const` contexts.
+fn const_str_fns(ast: &syn::DeriveInput,
+                 variants: &[syn::Variant],
+                 as_str_arms: &[quote::Tokens],
+                 naming: Naming)
+                 -> quote::Tokens {
     let name = &ast.ident;
-    let gen = &ast.generics;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
-    let vis = &ast.vis;
+    let case_mode = CaseMode::from_attrs(&ast.attrs);
 
-    if gen.lifetimes.len() > 0 {
-        panic!("Enum Iterator isn't supported on Enums with lifetimes. The resulting enums would \
-                be unbounded.");
-    }
+    let enabled = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, "IntoStaticStr"))
+        .collect::<Vec<_>>();
+    // Unicode case folding can't be done in a `const fn`, so those enums only get `as_str`.
+    let from_str_const = if case_mode != CaseMode::Unicode &&
+                            enabled.iter().all(|variant| variant.data == syn::VariantData::Unit) {
+        let mut arms = Vec::new();
+        for variant in enabled {
+            let ident = &variant.ident;
+            let serializations = get_serializations(ident, &variant.attrs, naming);
+            let bytes = serializations.iter().map(|s| quote::ByteStr(s)).collect::<Vec<_>>();
+            if case_mode.ignores_case(&variant.attrs) {
+                arms.push(quote!{
+                    b if #(b.eq_ignore_ascii_case(#bytes))||* => Some(#name::#ident)
+                });
+            } else {
+                arms.push(quote!{ #(#bytes)|* => Some(#name::#ident) });
+            }
+        }
 
-    let phantom_data = if gen.ty_params.len() > 0 {
-        quote!{ #ty_generics}
+        quote!{
+            pub const fn from_str_const(s: &str) -> Option<#name #ty_generics> {
+                match s.as_bytes() {
+                    #(#arms,)*
+                    _ => None,
+                }
+            }
+        }
     } else {
-        quote! { < () > }
+        quote!{}
     };
 
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    #(#as_str_arms),*
+                }
+            }
+
+            #from_str_const
+        }
+    }
+}
+
+fn enum_is_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let variants = match ast.body {
         syn::Body::Enum(ref v) => v,
-        _ => panic!("EnumIter only works on Enums"),
+        _ => panic!("EnumIs only works on Enums"),
     };
 
-    let mut arms = Vec::new();
-    let enabled = variants.iter().filter(|variant| !is_disabled(&variant.attrs));
-
-    for (idx, variant) in enabled.enumerate() {
+    let mut functions = Vec::new();
+    for variant in variants {
         use syn::VariantData::*;
         let ident = &variant.ident;
-        let params = match variant.data {
-            Unit => quote::Ident::from(""),
-            Tuple(ref fields) => {
-                let default = fields.iter()
-                    .map(|_| "Default::default()")
-                    .collect::<Vec<_>>()
-                    .join(", ");
 
-                quote::Ident::from(&*format!("({})", default))
-            }
-            Struct(ref fields) => {
-                let default = fields.iter()
-                    .map(|field| {
-                        format!("{}:{}", field.ident.as_ref().unwrap(), "Default::default()")
-                    })
-                    .collect::<Vec<_>>()
-                    .join(", ");
+        if is_disabled(&variant.attrs, "EnumIs") {
+            continue;
+        }
 
-                quote::Ident::from(&*format!("{{{}}}", default))
-            }
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
         };
 
-        arms.push(quote!{#idx => Some(#name::#ident #params)});
+        let snake = CaseStyle::Snake.apply(unraw(ident.as_ref()));
+        let fn_name = quote::Ident::from(&*format!("is_{}", snake));
+        functions.push(quote!{
+            pub const fn #fn_name(&self) -> bool {
+                matches!(self, &#name::#ident #params)
+            }
+        });
     }
 
-    arms.push(quote! { _ => None });
-    let iter_name = quote::Ident::from(&*format!("{}Iter", name));
     quote!{
-        #vis struct #iter_name #ty_generics {
-            idx: usize,
-            marker: std::marker::PhantomData #phantom_data,
-        }
-
-        impl #impl_generics strum::IntoEnumIterator for #name #ty_generics #where_clause {
-            type Iterator = #iter_name #ty_generics;
-            fn iter() -> #iter_name #ty_generics {
-                #iter_name {
-                    idx:0,
-                    marker: std::marker::PhantomData,
-                }
-            }
-        }
-        
-        impl #impl_generics Iterator for #iter_name #ty_generics #where_clause {
-            type Item = #name #ty_generics;
-            
-            fn next(&mut self) -> Option<#name #ty_generics> {
-                use std::default::Default;
-                let output = match self.idx {
-                    #(#arms),*
-                };
-
-                self.idx += 1;
-                output
-            }
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#functions)*
         }
     }
 }
 
-fn enum_message_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+fn enum_properties_inner(ast: &syn::DeriveInput) -> quote::Tokens {
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let variants = match ast.body {
         syn::Body::Enum(ref v) => v,
-        _ => panic!("EnumHelp only works on Enums"),
+        _ => panic!("EnumProperty only works on Enums"),
     };
 
-    let mut arms = Vec::new();
-    let mut detailed_arms = Vec::new();
-    let mut serializations = Vec::new();
-
+    let mut str_arms = Vec::new();
+    let mut int_arms = Vec::new();
+    let mut bool_arms = Vec::new();
+    let mut all_arms = Vec::new();
+    // (key, return type, match arms) for each `prop_accessors` method, in order of first use.
+    let mut accessors: Vec<(&str, &str, Vec<quote::Tokens>)> = Vec::new();
+    let required = get_required_props(&ast.attrs);
+    let mut has_disabled = false;
     for variant in variants {
-        let messages = unique_attr(&variant.attrs, "strum", "message");
-        let detailed_messages = unique_attr(&variant.attrs, "strum", "detailed_message");
+        use syn::VariantData::*;
         let ident = &variant.ident;
 
-        use syn::VariantData::*;
+        if is_disabled(&variant.attrs, "EnumProperty") {
+            has_disabled = true;
+            continue;
+        }
+
+        let props = get_props(&variant.attrs);
+        for key in &required {
+            if !props.iter().any(|&(prop, _)| prop == key) {
+                panic!("{} is missing the required property {}", ident, key);
+            }
+        }
+
         let params = match variant.data {
             Unit => quote::Ident::from(""),
             Tuple(..) => quote::Ident::from("(..)"),
             Struct(..) => quote::Ident::from("{..}"),
         };
 
-        // You can't disable getting the serializations.
-        {
-            let mut serialization_variants = extract_attrs(&variant.attrs, "strum", "serialize");
-            if serialization_variants.len() == 0 {
-                serialization_variants.push(ident.as_ref());
-            }
+        let mut str_props = Vec::new();
+        let mut int_props = Vec::new();
+        let mut bool_props = Vec::new();
+        let mut all_props = Vec::new();
+        for (key, value) in props {
+            let key = key.as_ref();
+            let (text, literal, ty) = match *value {
+                syn::Lit::Str(ref value, ..) => {
+                    str_props.push((key, quote!{ (#key, #value) }));
+                    (value.clone(), quote!{ #value }, "&'static str")
+                }
+                syn::Lit::Int(value, ..) => {
+                    if value > i64::MAX as u64 {
+                        panic!("The value of {} on {} doesn't fit in an i64", key, ident);
+                    }
 
-            let count = serialization_variants.len();
-            serializations.push(quote!{
-                &#name::#ident #params => {
-                    static ARR: [&'static str; #count] = [#(#serialization_variants),*];
-                    &ARR
+                    let literal = quote::Ident::from(value.to_string());
+                    int_props.push((key, quote!{ (#key, #literal) }));
+                    (value.to_string(), quote!{ #literal }, "i64")
                 }
-            });
-        }
+                syn::Lit::Bool(value) => {
+                    bool_props.push((key, quote!{ (#key, #value) }));
+                    (value.to_string(), quote!{ #value }, "bool")
+                }
+                _ => panic!("props only support string, integer and bool values"),
+            };
+            all_props.push(quote!{ (#key, #text) });
 
-        // But you can disable the messages.
-        if is_disabled(&variant.attrs) {
-            continue;
+            let arm = if required.contains(&key) {
+                quote!{ &#name::#ident #params => #literal }
+            } else {
+                quote!{ &#name::#ident #params => Some(#literal) }
+            };
+            match accessors.iter_mut().find(|accessor| accessor.0 == key) {
+                Some(accessor) => {
+                    if accessor.1 != ty {
+                        panic!("props has both {} and {} values for {}", accessor.1, ty, key);
+                    }
+
+                    accessor.2.push(arm);
+                }
+                None => accessors.push((key, ty, vec![arm])),
+            }
         }
 
-        if let Some(msg) = messages {
-            let params = params.clone();
+        if !all_props.is_empty() {
+            all_arms.push(quote!{
+                &#name::#ident #params => &[#(#all_props),*]
+            });
+        }
 
-            // Push the simple message.
-            let tokens = quote!{ &#name::#ident #params => Some(#msg) };
-            arms.push(tokens.clone());
+        // Each table is sorted by key so the lookups can binary search it.
+        for (mut table, arms) in [(str_props, &mut str_arms),
+                                  (int_props, &mut int_arms),
+                                  (bool_props, &mut bool_arms)] {
+            if table.is_empty() {
+                continue;
+            }
 
-            if detailed_messages.is_none() {
-                detailed_arms.push(tokens);
+            table.sort_by_key(|&(key, _)| key);
+            if let Some(pair) = table.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+                panic!("{} has more than one value for the property {}", ident, pair[0].0);
             }
-        }
 
-        if let Some(msg) = detailed_messages {
-            let params = params.clone();
-            // Push the simple message.
-            detailed_arms.push(quote!{ &#name::#ident #params => Some(#msg) });
+            let entries = table.into_iter().map(|(_, entry)| entry);
+            arms.push(quote!{ &#name::#ident #params => &[#(#entries),*] });
         }
     }
 
-    if arms.len() < variants.len() {
-        arms.push(quote!{ _ => None });
-    }
+    // Required keys always get accessors, which don't need to return an `Option`.
+    let all_accessors = extract_flag(&ast.attrs, "strum", "prop_accessors").unwrap_or(false);
+    let accessor_fns = if all_accessors || !required.is_empty() {
+        let functions = accessors.iter()
+            .filter(|&&(key, _, _)| all_accessors || required.contains(&key))
+            .map(|&(key, ty, ref arms)| {
+                let fn_name = quote::Ident::from(CaseStyle::Snake.apply(key));
+                let ty = quote::Ident::from(ty);
+                if !required.contains(&key) {
+                    return quote!{
+                        pub fn #fn_name(&self) -> Option<#ty> {
+                            match self {
+                                #(#arms,)*
+                                _ => None,
+                            }
+                        }
+                    };
+                }
 
-    if detailed_arms.len() < variants.len() {
-        detailed_arms.push(quote!{ _ => None });
-    }
+                let disabled = if has_disabled {
+                    quote!{ _ => panic!("Property accessor called on disabled variant.") }
+                } else {
+                    quote!{}
+                };
+                quote!{
+                    pub fn #fn_name(&self) -> #ty {
+                        match self {
+                            #(#arms,)*
+                            #disabled
+                        }
+                    }
+                }
+            });
+
+        quote!{
+            impl #impl_generics #name #ty_generics #where_clause {
+                #(#functions)*
+            }
+        }
+    } else {
+        quote!{}
+    };
 
     quote!{
-        impl #impl_generics strum::EnumMessage for #name #ty_generics #where_clause {
-            fn get_message(&self) -> Option<&str> {
-                match self {
-                    #(#arms),*
-                }
+        impl #impl_generics strum::EnumProperty for #name #ty_generics #where_clause {
+            fn get_str(&self, prop: &str) -> Option<&'static str> {
+                let props: &'static [(&'static str, &'static str)] = match self {
+                    #(#str_arms,)*
+                    _ => &[],
+                };
+                props.binary_search_by_key(&prop, |&(key, _)| key).ok().map(|i| props[i].1)
             }
 
-            fn get_detailed_message(&self) -> Option<&str> {
-                match self {
-                    #(#detailed_arms),*
-                }
+            fn get_int(&self, prop: &str) -> Option<i64> {
+                let props: &'static [(&'static str, i64)] = match self {
+                    #(#int_arms,)*
+                    _ => &[],
+                };
+                props.binary_search_by_key(&prop, |&(key, _)| key).ok().map(|i| props[i].1)
             }
 
-            fn get_serializations(&self) -> &[&str] {
+            fn get_bool(&self, prop: &str) -> Option<bool> {
+                let props: &'static [(&'static str, bool)] = match self {
+                    #(#bool_arms,)*
+                    _ => &[],
+                };
+                props.binary_search_by_key(&prop, |&(key, _)| key).ok().map(|i| props[i].1)
+            }
+
+            fn get_all_props(&self) -> &'static [(&'static str, &'static str)] {
                 match self {
-                    #(#serializations),*
+                    #(#all_arms,)*
+                    _ => &[],
                 }
             }
         }
+
+        #accessor_fns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mentions(ty: &str) -> (Vec<String>, Vec<String>) {
+        let ty = syn::parse_type(ty).unwrap();
+        let mentions = Mentions::of(&ty);
+        let sorted = |idents: &HashSet<&syn::Ident>| {
+            let mut names = idents.iter().map(|ident| ident.to_string()).collect::<Vec<_>>();
+            names.sort();
+            names
+        };
+        (sorted(&mentions.types), sorted(&mentions.lifetimes))
+    }
+
+    #[test]
+    fn type_mentions() {
+        assert_eq!(vec!["T"], mentions("T").0);
+        assert_eq!(vec!["T", "Vec"], mentions("Vec<T>").0);
+        assert_eq!(vec!["T"], mentions("T::Assoc").0);
+        assert_eq!(vec!["other"], mentions("other::T").0);
+        assert!(mentions("::std::string::String").0.is_empty());
+        assert_eq!(vec!["U"], mentions("<U as Trait>::T").0);
+        assert_eq!(vec!["T", "U"], mentions("fn([T; 2]) -> (U,)").0);
+    }
+
+    #[test]
+    fn lifetime_mentions() {
+        assert_eq!(vec!["'a"], mentions("&'a str").1);
+        assert_eq!(vec!["'a", "'b"], mentions("Cow<'a, &'b str>").1);
+        assert_eq!(vec!["'a"], mentions("Box<Fn(&'a str) + 'a>").1);
+        assert!(mentions("&str").1.is_empty());
+    }
+
+    #[test]
+    fn debug_output_filters() {
+        let debug = |value| debug_output(value, "Color", "Display");
+        assert_eq!(DebugOutput::Print, debug("1"));
+        assert_eq!(DebugOutput::Print, debug("Color"));
+        assert_eq!(DebugOutput::Print, debug("Color:Display"));
+        assert_eq!(DebugOutput::Print, debug("Shape, Color:Display"));
+        assert_eq!(DebugOutput::Skip, debug("Shape"));
+        assert_eq!(DebugOutput::Skip, debug("Color:EnumString"));
+        assert_eq!(DebugOutput::Skip, debug("Shape:Display"));
+        assert_eq!(DebugOutput::Skip, debug("0"));
+        assert_eq!(DebugOutput::Skip, debug(""));
+    }
+
+    #[test]
+    fn debug_output_files() {
+        let debug = |value| debug_output(value, "Color", "Display");
+        assert_eq!(DebugOutput::Append("gen.rs"), debug("1,out=gen.rs"));
+        assert_eq!(DebugOutput::Append("gen.rs"), debug("out=gen.rs, Color:Display"));
+        assert_eq!(DebugOutput::Append("gen.rs"), debug("Color,out=gen.rs"));
+        assert_eq!(DebugOutput::Skip, debug("Shape,out=gen.rs"));
+        // A file on its own doesn't pick anything to write to it.
+        assert_eq!(DebugOutput::Skip, debug("out=gen.rs"));
     }
 }
@@ -0,0 +1,152 @@
+//! Builds the perfect hash tables used by `#[strum(use_phf)]`. The tables are looked up at
+//! runtime by `strum::phf::Map`, so `hash`, `split` and `displace` must stay in sync with the
+//! copies in `strum/src/phf.rs`.
+
+/// Number of keys we aim to put in each bucket of the first-level hash.
+const LAMBDA: usize = 5;
+
+/// How many hash keys are tried before giving up. Nearly every set of keys works with the first
+/// few, so running out means something is wrong rather than unlucky.
+const MAX_ATTEMPTS: u64 = 1000;
+
+/// A perfect hash table. `order[slot]` is the index of the key that lives in `slot`.
+pub struct Table {
+    pub key: u64,
+    pub disps: Vec<(u32, u32)>,
+    pub order: Vec<usize>,
+}
+
+fn hash(key: u64, bytes: &[u8], lowercase: bool) -> u64 {
+    let mut h = 0xcbf2_9ce4_8422_2325 ^ key;
+    for &b in bytes {
+        let b = if lowercase { b.to_ascii_lowercase() } else { b };
+        h ^= u64::from(b);
+        h = h.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^ (h >> 33)
+}
+
+fn split(h: u64) -> (u32, u32, u32) {
+    ((h >> 42) as u32, ((h >> 21) & 0x1f_ffff) as u32, (h & 0x1f_ffff) as u32)
+}
+
+fn displace(f1: u32, f2: u32, d1: u32, d2: u32) -> u32 {
+    d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2)
+}
+
+/// Generates a table for the given keys. The keys must be unique (after lowercasing, if
+/// `lowercase` is set).
+pub fn generate(keys: &[String], lowercase: bool) -> Table {
+    (0..MAX_ATTEMPTS)
+        .filter_map(|key| try_generate(key, keys, lowercase))
+        .next()
+        .unwrap_or_else(|| {
+            panic!("use_phf couldn't build a perfect hash table for {} strings after {} attempts",
+                   keys.len(),
+                   MAX_ATTEMPTS)
+        })
+}
+
+fn try_generate(key: u64, keys: &[String], lowercase: bool) -> Option<Table> {
+    if keys.is_empty() {
+        return Some(Table { key, disps: Vec::new(), order: Vec::new() });
+    }
+
+    let hashes = keys.iter()
+        .map(|k| split(hash(key, k.as_bytes(), lowercase)))
+        .collect::<Vec<_>>();
+
+    let buckets_len = keys.len().div_ceil(LAMBDA);
+    let mut buckets = vec![Vec::new(); buckets_len];
+    for (i, &(g, _, _)) in hashes.iter().enumerate() {
+        buckets[g as usize % buckets_len].push(i);
+    }
+
+    // Place the biggest buckets first while the table is still empty.
+    let mut bucket_order = (0..buckets_len).collect::<Vec<_>>();
+    bucket_order.sort_by_key(|&b| std::cmp::Reverse(buckets[b].len()));
+
+    let table_len = keys.len();
+    let mut order = vec![None; table_len];
+    let mut disps = vec![(0, 0); buckets_len];
+    let mut slots = Vec::new();
+    for &b in &bucket_order {
+        let placed = (0..table_len as u32)
+            .flat_map(|d1| (0..table_len as u32).map(move |d2| (d1, d2)))
+            .find(|&(d1, d2)| {
+                slots.clear();
+                buckets[b].iter().all(|&i| {
+                    let (_, f1, f2) = hashes[i];
+                    let slot = displace(f1, f2, d1, d2) as usize % table_len;
+                    if order[slot].is_some() || slots.contains(&slot) {
+                        return false;
+                    }
+
+                    slots.push(slot);
+                    true
+                })
+            });
+
+        let (d1, d2) = placed?;
+        disps[b] = (d1, d2);
+        for (&i, &slot) in buckets[b].iter().zip(&slots) {
+            order[slot] = Some(i);
+        }
+    }
+
+    Some(Table {
+        key,
+        disps,
+        order: order.into_iter().map(Option::unwrap).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The same vectors are checked in `strum/src/phf.rs`, so the two copies of `hash` can't drift
+    /// apart without a test failing.
+    const HASHES: &[(u64, &str, bool, u64)] = &[
+        (0, "", false, 0xefd0_1f60_ba99_2926),
+        (0, "Red", false, 0x41e6_aa4b_52b1_d648),
+        (0, "Red", true, 0x9ec9_ba33_30a6_4fb2),
+        (7, "ascii_case_insensitive", false, 0xf70f_1732_9085_bae7),
+        (u64::MAX, "\u{e9}t\u{e9}", true, 0x54fc_10ce_5c2e_9144),
+    ];
+
+    #[test]
+    fn hash_vectors() {
+        for &(key, s, lowercase, expected) in HASHES {
+            assert_eq!(expected, hash(key, s.as_bytes(), lowercase), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn split_and_displace() {
+        assert_eq!((0x3f_ffff, 0x1f_ffff, 0x1f_ffff), split(u64::MAX));
+        assert_eq!(10, displace(1, 2, 3, 5));
+        assert_eq!(1, displace(0, 2, u32::MAX, u32::MAX));
+    }
+
+    #[test]
+    fn generates_perfect_tables() {
+        let keys = ["Red", "Green", "Blue", "Yellow", "b", "y"].iter()
+            .map(|&k| k.to_owned())
+            .collect::<Vec<_>>();
+        let table = generate(&keys, false);
+        let mut order = table.order.clone();
+        order.sort();
+        assert_eq!((0..keys.len()).collect::<Vec<_>>(), order);
+        for (slot, &i) in table.order.iter().enumerate() {
+            let (g, f1, f2) = split(hash(table.key, keys[i].as_bytes(), false));
+            let (d1, d2) = table.disps[g as usize % table.disps.len()];
+            assert_eq!(slot, displace(f1, f2, d1, d2) as usize % keys.len());
+        }
+    }
+}
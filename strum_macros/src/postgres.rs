@@ -0,0 +1,60 @@
+//! `EnumPostgres`, which implements postgres-types' `ToSql` and `FromSql` with the strings
+//! `IntoStaticStr` and `EnumString` use, for TEXT columns or a Postgres `ENUM` type.
+
+use quote;
+use syn;
+
+use super::{extend_generics, stored_str_arms, unique_attr};
+
+pub fn postgres_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let self_ty = quote!{ #name #ty_generics };
+
+    let store = |value: quote::Tokens, _| value;
+    let disabled = quote!{ return Err("to_sql() called on disabled variant.".into()) };
+    let arms = stored_str_arms(ast, "EnumPostgres", &store, disabled);
+
+    // With `postgres_type`, only the named `ENUM` type is accepted. Its labels are expected to be
+    // the strum serializations.
+    let accepts = match unique_attr(&ast.attrs, "strum", "postgres_type") {
+        Some(pg_name) => quote!{ ty.name() == #pg_name },
+        None => quote!{ <&str as postgres_types::ToSql>::accepts(ty) },
+    };
+
+    let from_sql_predicates = format!("<{} as strum::__core::str::FromStr>::Err: \
+                                       std::error::Error + Send + Sync + 'static",
+                                      self_ty.as_str());
+    let from_sql_generics = extend_generics(&ast.generics, &["'a"], &[], &from_sql_predicates);
+    let (from_sql_impl_generics, _, from_sql_where_clause) = from_sql_generics.split_for_impl();
+
+    quote!{
+        impl #impl_generics postgres_types::ToSql for #self_ty #where_clause {
+            fn to_sql(&self, ty: &postgres_types::Type, out: &mut strum::__bytes::BytesMut)
+                -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+                let value: &str = match *self {
+                    #(#arms),*
+                };
+                <&str as postgres_types::ToSql>::to_sql(&value, ty, out)
+            }
+
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                #accepts
+            }
+
+            postgres_types::to_sql_checked!();
+        }
+
+        impl #from_sql_impl_generics postgres_types::FromSql<'a> for #self_ty #from_sql_where_clause {
+            fn from_sql(ty: &postgres_types::Type, raw: &'a [u8])
+                -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                let value = <&str as postgres_types::FromSql>::from_sql(ty, raw)?;
+                Ok(strum::__core::str::FromStr::from_str(value)?)
+            }
+
+            fn accepts(ty: &postgres_types::Type) -> bool {
+                #accepts
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! `EnumProptest`, which implements proptest's `Arbitrary` by picking a variant uniformly and
+//! generating its fields with their own `Arbitrary` strategies.
+
+use quote;
+use syn;
+
+use super::{bound_generic_fields, is_disabled};
+
+pub fn arbitrary_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumProptest only works on Enums"),
+    };
+
+    let mut generics = bound_generic_fields(ast, "EnumProptest", "proptest::arbitrary::Arbitrary");
+    // Boxing the strategy needs the whole enum to be `'static`.
+    for param in &mut generics.ty_params {
+        param.bounds.push(syn::TyParamBound::Region(syn::Lifetime::new("'static")));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut strategies = Vec::new();
+    for variant in variants {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+
+        if is_disabled(&variant.attrs, "EnumProptest") {
+            continue;
+        }
+
+        let fields = variant.data.fields();
+        let any = fields.iter().map(|field| {
+            let ty = &field.ty;
+            quote!{ proptest::arbitrary::any::<#ty>() }
+        });
+        let bindings = (0..fields.len())
+            .map(|i| quote::Ident::from(format!("f{}", i)))
+            .collect::<Vec<_>>();
+        let strategy = match variant.data {
+            Unit => quote!{ proptest::strategy::LazyJust::new(|| #name::#ident).boxed() },
+            Tuple(..) => {
+                let pattern = bindings.iter();
+                let values = bindings.iter();
+                quote!{
+                    (#(#any,)*).prop_map(|(#(#pattern,)*)| #name::#ident(#(#values),*)).boxed()
+                }
+            }
+            Struct(..) => {
+                let names = fields.iter().map(|field| field.ident.as_ref().unwrap());
+                let pattern = bindings.iter();
+                let values = bindings.iter();
+                quote!{
+                    (#(#any,)*)
+                        .prop_map(|(#(#pattern,)*)| #name::#ident { #(#names: #values),* })
+                        .boxed()
+                }
+            }
+        };
+        strategies.push(strategy);
+    }
+
+    if strategies.is_empty() {
+        panic!("EnumProptest needs at least one variant that isn't disabled");
+    }
+
+    quote!{
+        impl #impl_generics proptest::arbitrary::Arbitrary for #name #ty_generics #where_clause {
+            type Parameters = ();
+            type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                use proptest::strategy::Strategy;
+                proptest::strategy::Union::new(vec![#(#strategies),*]).boxed()
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! `EnumQuickCheck`, which implements quickcheck's `Arbitrary` by picking a variant uniformly and
+//! generating its fields with their own `Arbitrary` impls.
+
+use quote;
+use syn;
+
+use super::{bound_generic_fields, is_disabled};
+
+pub fn arbitrary_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumQuickCheck only works on Enums"),
+    };
+
+    let generics = bound_generic_fields(ast, "EnumQuickCheck", "quickcheck::Arbitrary");
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut arms = Vec::new();
+    let enabled = variants.iter().filter(|variant| !is_disabled(&variant.attrs, "EnumQuickCheck"));
+    for (idx, variant) in enabled.enumerate() {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let fields = variant.data.fields();
+        let values = fields.iter().map(|_| quote!{ quickcheck::Arbitrary::arbitrary(g) });
+        let value = match variant.data {
+            Unit => quote!{ #name::#ident },
+            Tuple(..) => quote!{ #name::#ident(#(#values),*) },
+            Struct(..) => {
+                let names = fields.iter().map(|field| field.ident.as_ref().unwrap());
+                quote!{ #name::#ident { #(#names: #values),* } }
+            }
+        };
+        arms.push(quote!{ #idx => #value });
+    }
+
+    if arms.is_empty() {
+        panic!("EnumQuickCheck needs at least one variant that isn't disabled");
+    }
+
+    let indices = (0..arms.len()).collect::<Vec<_>>();
+    arms.push(quote!{ _ => unreachable!() });
+
+    quote!{
+        impl #impl_generics quickcheck::Arbitrary for #name #ty_generics #where_clause {
+            fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+                match *g.choose(&[#(#indices),*]).unwrap() {
+                    #(#arms),*
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! `EnumRandom`, which lets rand pick a variant with `rand::random()`, or by the variants'
+//! `weight` properties with `sample_weighted`.
+
+use quote;
+use syn;
+
+use super::{get_props, is_disabled};
+
+pub fn random_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumRandom only works on Enums"),
+    };
+
+    let mut arms = Vec::new();
+    let mut weighted = Vec::new();
+    let mut total = 0usize;
+    let enabled = variants.iter().filter(|variant| !is_disabled(&variant.attrs, "EnumRandom"));
+    for (idx, variant) in enabled.enumerate() {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(ref fields) => {
+                let default = fields.iter()
+                    .map(|_| "Default::default()")
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                quote::Ident::from(&*format!("({})", default))
+            }
+            Struct(ref fields) => {
+                let default = fields.iter()
+                    .map(|field| {
+                        format!("{}:{}", field.ident.as_ref().unwrap(), "Default::default()")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                quote::Ident::from(&*format!("{{{}}}", default))
+            }
+        };
+
+        arms.push(quote!{ #idx => #name::#ident #params });
+
+        let weight = variant_weight(variant);
+        if weight > 0 {
+            total += weight;
+            weighted.push(quote!{
+                if pick < #weight {
+                    return #name::#ident #params;
+                }
+                pick -= #weight;
+            });
+        }
+    }
+
+    if arms.is_empty() {
+        panic!("EnumRandom needs at least one variant that isn't disabled");
+    }
+
+    if total == 0 {
+        panic!("EnumRandom needs at least one variant with a weight above 0");
+    }
+
+    let variant_count = arms.len();
+    arms.push(quote!{ _ => unreachable!() });
+
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Picks a variant with a probability proportional to its `weight` property.
+            /// Variants without one have a weight of 1.
+            pub fn sample_weighted<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                let mut pick = rng.random_range(0..#total);
+                #(#weighted)*
+                unreachable!()
+            }
+        }
+
+        impl #impl_generics rand::distr::Distribution<#name #ty_generics>
+            for rand::distr::StandardUniform #where_clause {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> #name #ty_generics {
+                match rng.random_range(0..#variant_count) {
+                    #(#arms),*
+                }
+            }
+        }
+    }
+}
+
+/// Reads `props(weight=...)` off a variant, which can be an integer or a string holding one.
+fn variant_weight(variant: &syn::Variant) -> usize {
+    let weight = get_props(&variant.attrs)
+        .into_iter()
+        .find(|&(key, _)| key == "weight");
+
+    match weight {
+        None => 1,
+        Some((_, value)) => match *value {
+            syn::Lit::Int(value, ..) => value as usize,
+            syn::Lit::Str(ref value, ..) => {
+                value.parse().unwrap_or_else(|_| {
+                    panic!("The weight of {} should be a whole number, not {:?}",
+                           variant.ident,
+                           value)
+                })
+            }
+            _ => panic!("The weight of {} should be a whole number", variant.ident),
+        },
+    }
+}
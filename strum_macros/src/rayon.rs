@@ -0,0 +1,57 @@
+//! `EnumParIter`, which adds `par_iter()` next to the `iter()` from `EnumIter`. It's backed by
+//! rayon's range producer, so the variants are split by index rather than collected first.
+
+use quote;
+use syn;
+
+use super::{bound_generic_fields, is_disabled, unique_attr};
+
+pub fn par_iter_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumParIter only works on Enums"),
+    };
+
+    // The same bounds as `EnumIter`'s impls, plus `Send` so the variants can cross threads.
+    let mut generics = bound_generic_fields(ast, "EnumIter", "Default");
+    for param in &mut generics.ty_params {
+        let send = syn::PolyTraitRef {
+            bound_lifetimes: Vec::new(),
+            trait_ref: syn::Path::from("Send"),
+        };
+        param.bounds.push(syn::TyParamBound::Trait(send, syn::TraitBoundModifier::None));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let iter_name = match unique_attr(&ast.attrs, "strum", "iter_name") {
+        Some(iter_name) => quote::Ident::from(iter_name),
+        None => quote::Ident::from(&*format!("{}Iter", name)),
+    };
+    let variant_count = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, "EnumIter"))
+        .count();
+
+    quote!{
+        impl #impl_generics rayon::iter::IntoParallelIterator for #iter_name #ty_generics
+            #where_clause {
+            type Item = #name #ty_generics;
+            type Iter = rayon::iter::Map<rayon::range::Iter<usize>, fn(usize) -> #name #ty_generics>;
+
+            fn into_par_iter(self) -> Self::Iter {
+                let get: fn(usize) -> #name #ty_generics = |idx| #name::iter().get(idx).unwrap();
+                let indices = self.idx..#variant_count - self.back_idx;
+                rayon::iter::ParallelIterator::map(
+                    rayon::iter::IntoParallelIterator::into_par_iter(indices), get)
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Iterates over the variants in parallel, in the same order as `iter()`.
+            pub fn par_iter()
+                -> <#iter_name #ty_generics as rayon::iter::IntoParallelIterator>::Iter {
+                rayon::iter::IntoParallelIterator::into_par_iter(#name::iter())
+            }
+        }
+    }
+}
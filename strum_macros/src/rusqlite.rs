@@ -0,0 +1,45 @@
+//! `EnumRusqlite`, which implements rusqlite's `ToSql` and `FromSql` for TEXT columns with the
+//! strings `IntoStaticStr` and `EnumString` use.
+
+use quote;
+use syn;
+
+use super::{extend_generics, stored_str_arms};
+
+pub fn rusqlite_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let self_ty = quote!{ #name #ty_generics };
+
+    let store = |value: quote::Tokens, _| value;
+    let disabled = quote!{
+        return Err(rusqlite::Error::ToSqlConversionFailure(
+            "to_sql() called on disabled variant.".into()))
+    };
+    let arms = stored_str_arms(ast, "EnumRusqlite", &store, disabled);
+
+    let from_sql_predicates = format!("<{} as strum::__core::str::FromStr>::Err: \
+                                       std::error::Error + Send + Sync + 'static",
+                                      self_ty.as_str());
+    let from_sql_generics = extend_generics(&ast.generics, &[], &[], &from_sql_predicates);
+    let (from_sql_impl_generics, _, from_sql_where_clause) = from_sql_generics.split_for_impl();
+
+    quote!{
+        impl #impl_generics rusqlite::types::ToSql for #self_ty #where_clause {
+            fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+                let value: &str = match *self {
+                    #(#arms),*
+                };
+                Ok(rusqlite::types::ToSqlOutput::from(value))
+            }
+        }
+
+        impl #from_sql_impl_generics rusqlite::types::FromSql for #self_ty #from_sql_where_clause {
+            fn column_result(value: rusqlite::types::ValueRef<'_>)
+                -> rusqlite::types::FromSqlResult<Self> {
+                strum::__core::str::FromStr::from_str(value.as_str()?)
+                    .map_err(|err| rusqlite::types::FromSqlError::Other(Box::new(err)))
+            }
+        }
+    }
+}
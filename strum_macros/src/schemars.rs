@@ -0,0 +1,44 @@
+//! `EnumJsonSchema`, which implements schemars' `JsonSchema` as a string schema listing the
+//! strings `EnumString` accepts.
+
+use quote;
+use syn;
+
+use super::{get_serializations, is_disabled, unique_attr, Naming};
+
+pub fn json_schema_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let name_str = name.as_ref();
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumJsonSchema only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+
+    // A `default` variant accepts any string, so there's nothing to enumerate.
+    let has_default = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, "EnumString"))
+        .any(|variant| unique_attr(&variant.attrs, "strum", "default") == Some("true"));
+    let schema = if has_default {
+        quote!{ schemars::json_schema!({ "type": "string" }) }
+    } else {
+        let values = variants.iter()
+            .filter(|variant| !is_disabled(&variant.attrs, "EnumString"))
+            .flat_map(|variant| get_serializations(&variant.ident, &variant.attrs, naming))
+            .collect::<Vec<_>>();
+        quote!{ schemars::json_schema!({ "type": "string", "enum": [#(#values),*] }) }
+    };
+
+    quote!{
+        impl #impl_generics schemars::JsonSchema for #name #ty_generics #where_clause {
+            fn schema_name() -> strum::__Cow<'static, str> {
+                strum::__Cow::Borrowed(#name_str)
+            }
+
+            fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                #schema
+            }
+        }
+    }
+}
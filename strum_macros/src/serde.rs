@@ -0,0 +1,121 @@
+//! `EnumSerialize` and `EnumDeserialize`, which implement serde's traits with the same strings
+//! that `EnumString` and `IntoStaticStr` use.
+
+use quote;
+use syn;
+
+use super::{get_preferred_name, get_serializations, is_disabled, unique_attr, Naming};
+
+pub fn serialize_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumSerialize only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+
+    let mut arms = Vec::new();
+    for variant in variants {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+
+        if is_disabled(&variant.attrs, "EnumSerialize") {
+            continue;
+        }
+
+        // The catch-all variant round-trips the string it captured.
+        if unique_attr(&variant.attrs, "strum", "default") == Some("true") {
+            let pattern = match variant.data {
+                Tuple(..) => quote::Ident::from("(ref inner)"),
+                Struct(ref fields) => {
+                    let field = fields[0].ident.as_ref().unwrap();
+                    quote::Ident::from(&*format!("{{ {}: ref inner }}", field))
+                }
+                Unit => panic!("Default only works on variants with a single String field"),
+            };
+
+            arms.push(quote!{
+                &#name::#ident #pattern => {
+                    serializer.serialize_str(strum::__core::convert::AsRef::<str>::as_ref(inner))
+                }
+            });
+            continue;
+        }
+
+        let output = get_preferred_name(ident, &variant.attrs, naming);
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+
+        arms.push(quote!{ &#name::#ident #params => serializer.serialize_str(#output) });
+    }
+
+    if arms.len() < variants.len() {
+        arms.push(quote!{
+            _ => Err(serde::ser::Error::custom("serialize() called on disabled variant."))
+        });
+    }
+
+    quote!{
+        impl #impl_generics serde::Serialize for #name #ty_generics #where_clause {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+    }
+}
+
+pub fn deserialize_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let name_str = name.as_ref();
+    let (_, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumDeserialize only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+
+    // The strings `EnumString` accepts, reported back when a value doesn't parse.
+    let expected = variants.iter()
+        .filter(|variant| !is_disabled(&variant.attrs, "EnumString"))
+        .filter(|variant| unique_attr(&variant.attrs, "strum", "default") != Some("true"))
+        .flat_map(|variant| get_serializations(&variant.ident, &variant.attrs, naming))
+        .collect::<Vec<_>>();
+
+    let mut de_generics = ast.generics.clone();
+    de_generics.lifetimes.insert(0, syn::LifetimeDef::new("'de"));
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+    let visitor = quote!{
+        struct Visitor<T>(strum::__core::marker::PhantomData<T>);
+
+        impl<'de, T: strum::__core::str::FromStr> serde::de::Visitor<'de> for Visitor<T> {
+            type Value = T;
+
+            fn expecting(&self, f: &mut strum::__core::fmt::Formatter)
+                -> strum::__core::fmt::Result
+            {
+                write!(f, "a string that parses as {}", #name_str)
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<T, E> {
+                value.parse().map_err(|_| E::unknown_variant(value, VARIANTS))
+            }
+        }
+    };
+
+    quote!{
+        impl #de_impl_generics serde::Deserialize<'de> for #name #ty_generics #where_clause {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                const VARIANTS: &'static [&'static str] = &[#(#expected),*];
+                #visitor
+                deserializer.deserialize_str(Visitor(strum::__core::marker::PhantomData))
+            }
+        }
+    }
+}
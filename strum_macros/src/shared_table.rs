@@ -0,0 +1,75 @@
+//! `#[strum(shared_table)]`. `EnumVariantNames` puts every string the derives would otherwise spell
+//! out in their own match arms into one hidden `strum::__SharedTable::STRINGS` table, and `Display`,
+//! `IntoStaticStr`, `EnumMessage` and `EnumString` read them out of it by index. Every derive lays
+//! the table out with `layout`, so the indices agree without the derives knowing about each other.
+
+use quote;
+use syn;
+
+use super::{extract_flag, get_preferred_name, get_serializations, Naming};
+
+/// Where one variant's strings are in the table.
+pub struct Strings {
+    /// The index of the first of the variant's serializations.
+    pub start: usize,
+    /// How many serializations there are, in the order `get_serializations` returns them.
+    pub serializations: usize,
+    /// The index of the string `Display` and `IntoStaticStr` use. It's one of the serializations,
+    /// or follows them if it isn't.
+    pub preferred: usize,
+}
+
+pub struct Layout {
+    pub strings: Vec<String>,
+    /// One entry per variant, in the order they're declared.
+    pub variants: Vec<Strings>,
+}
+
+/// The layout of the enum's table, or `None` if it doesn't have `#[strum(shared_table)]`.
+pub fn layout(ast: &syn::DeriveInput, naming: Naming) -> Option<Layout> {
+    if !extract_flag(&ast.attrs, "strum", "shared_table").unwrap_or(false) {
+        return None;
+    }
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => return None,
+    };
+
+    let mut layout = Layout { strings: Vec::new(), variants: Vec::new() };
+    for variant in variants {
+        let start = layout.strings.len();
+        let serializations = get_serializations(&variant.ident, &variant.attrs, naming);
+        let count = serializations.len();
+        let preferred_name = get_preferred_name(&variant.ident, &variant.attrs, naming);
+        let position = serializations.iter().position(|s| *s == preferred_name);
+        layout.strings.extend(serializations);
+        let preferred = match position {
+            Some(idx) => start + idx,
+            None => {
+                layout.strings.push(preferred_name);
+                layout.strings.len() - 1
+            }
+        };
+        layout.variants.push(Strings { start, serializations: count, preferred });
+    }
+    Some(layout)
+}
+
+/// The table itself, `<Name as strum::__SharedTable>::STRINGS`.
+pub fn table(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    quote!{ <#name #ty_generics as strum::__SharedTable>::STRINGS }
+}
+
+/// The impl of `strum::__SharedTable` that `EnumVariantNames` adds.
+pub fn table_impl(ast: &syn::DeriveInput, layout: &Layout) -> quote::Tokens {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let strings = &layout.strings;
+    quote!{
+        impl #impl_generics strum::__SharedTable for #name #ty_generics #where_clause {
+            const STRINGS: &'static [&'static str] = &[#(#strings),*];
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! `EnumSqlxType`, which lets sqlx store an enum in a TEXT column. Values are encoded as the
+//! string `IntoStaticStr` would return and decoded with the `FromStr` impl from `EnumString`.
+
+use quote;
+use syn;
+
+use super::{extend_generics, is_disabled, stored_str_arms, unique_attr};
+
+pub fn sqlx_type_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let self_ty = quote!{ #name #ty_generics };
+
+    // The captured string of a `default` variant only lives as long as `self`, so it's copied
+    // into a `String` rather than tied to the lifetime of the query.
+    let owned = match ast.body {
+        syn::Body::Enum(ref variants) => variants.iter().any(|variant| {
+            !is_disabled(&variant.attrs, "EnumSqlxType") &&
+            unique_attr(&variant.attrs, "strum", "default") == Some("true")
+        }),
+        _ => panic!("EnumSqlxType only works on Enums"),
+    };
+    let store = |value: quote::Tokens, is_static: bool| if is_static {
+        quote!{ <&'q str as sqlx::Encode<'q, DB>>::encode_by_ref(&#value, buf) }
+    } else {
+        quote!{ <strum::__String as sqlx::Encode<'q, DB>>::encode(#value.into(), buf) }
+    };
+    let disabled = quote!{ Err("encode() called on disabled variant.".into()) };
+    let arms = stored_str_arms(ast, "EnumSqlxType", &store, disabled);
+
+    let type_predicates = "DB: sqlx::Database, str: sqlx::Type<DB>";
+    let type_generics = extend_generics(&ast.generics, &[], &["DB"], type_predicates);
+    let (type_impl_generics, _, type_where_clause) = type_generics.split_for_impl();
+
+    let mut encode_predicates = String::from("DB: sqlx::Database, &'q str: sqlx::Encode<'q, DB>");
+    if owned {
+        encode_predicates.push_str(", strum::__String: sqlx::Encode<'q, DB>");
+    }
+    let encode_generics = extend_generics(&ast.generics, &["'q"], &["DB"], &encode_predicates);
+    let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+
+    let decode_predicates = format!("DB: sqlx::Database, &'r str: sqlx::Decode<'r, DB>, \
+                                     <{} as strum::__core::str::FromStr>::Err: \
+                                     strum::__core::convert::Into<sqlx::error::BoxDynError>",
+                                    self_ty.as_str());
+    let decode_generics = extend_generics(&ast.generics, &["'r"], &["DB"], &decode_predicates);
+    let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+
+    quote!{
+        impl #type_impl_generics sqlx::Type<DB> for #self_ty #type_where_clause {
+            fn type_info() -> DB::TypeInfo {
+                <str as sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <str as sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl #encode_impl_generics sqlx::Encode<'q, DB> for #self_ty #encode_where_clause {
+            fn encode_by_ref(&self, buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>)
+                -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                match *self {
+                    #(#arms),*
+                }
+            }
+        }
+
+        impl #decode_impl_generics sqlx::Decode<'r, DB> for #self_ty #decode_where_clause {
+            fn decode(value: <DB as sqlx::Database>::ValueRef<'r>)
+                -> Result<Self, sqlx::error::BoxDynError> {
+                let value = <&'r str as sqlx::Decode<'r, DB>>::decode(value)?;
+                strum::__core::str::FromStr::from_str(value)
+                    .map_err(strum::__core::convert::Into::into)
+            }
+        }
+    }
+}
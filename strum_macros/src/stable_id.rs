@@ -0,0 +1,74 @@
+//! `EnumStableId`, which gives each variant an ID hashed from its serialization.
+
+use std::collections::HashMap;
+
+use quote;
+use syn;
+
+use super::{bound_generic_fields, default_fields, get_preferred_name, unique_attr, Naming};
+
+/// The 64 bit FNV-1a hash, which is simple enough to stay the same across versions of strum.
+fn fnv1a(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+pub fn stable_id_inner(ast: &syn::DeriveInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let variants = match ast.body {
+        syn::Body::Enum(ref v) => v,
+        _ => panic!("EnumStableId only works on Enums"),
+    };
+    let naming = Naming::from_attrs(&ast.attrs);
+
+    let mut seen: HashMap<u64, &syn::Ident> = HashMap::new();
+    let mut to_id = Vec::new();
+    let mut from_id = Vec::new();
+    for variant in variants {
+        use syn::VariantData::*;
+        let ident = &variant.ident;
+        let params = match variant.data {
+            Unit => quote::Ident::from(""),
+            Tuple(..) => quote::Ident::from("(..)"),
+            Struct(..) => quote::Ident::from("{..}"),
+        };
+
+        // `stable_id="..."` pins the string that's hashed, so the serialization can change later.
+        let id = match unique_attr(&variant.attrs, "strum", "stable_id") {
+            Some(key) => fnv1a(key),
+            None => fnv1a(&get_preferred_name(ident, &variant.attrs, naming)),
+        };
+        if let Some(other) = seen.insert(id, ident) {
+            panic!("{}::{} and {}::{} have the same stable id, give one of them a stable_id",
+                   name, other, name, ident);
+        }
+
+        let id = quote::Ident::from(format!("{:#018x}", id));
+        let fields = default_fields(variant);
+        to_id.push(quote!{ #name::#ident #params => #id });
+        from_id.push(quote!{ #id => Some(#name::#ident #fields) });
+    }
+    from_id.push(quote!{ _ => None });
+
+    let generics = bound_generic_fields(ast, "EnumStableId", "Default");
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote!{
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// An ID for the variant that only depends on its serialization, not on its position.
+            pub const fn stable_id(&self) -> u64 {
+                match *self {
+                    #(#to_id),*
+                }
+            }
+
+            /// The variant with the given stable ID, with any fields set to their defaults.
+            pub fn from_stable_id(id: u64) -> Option<#name #ty_generics> {
+                match id {
+                    #(#from_id),*
+                }
+            }
+        }
+    }
+}
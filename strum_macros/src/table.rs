@@ -0,0 +1,66 @@
+//! Code generation for very large enums. rustc takes time that grows faster than linearly with the
+//! number of arms in a single match, so past a point the derives look strings up in flat static
+//! tables and turn indices back into variants with matches split over several small functions.
+
+use quote;
+
+/// The most arms `index_lookup` puts in a single match.
+const CHUNK: usize = 64;
+
+/// Generates an expression of type `Option<#ty>` that evaluates to `Some(values[index])`, or `None`
+/// if `index` is out of range. `values` can't refer to any local variables, because big lookups
+/// are split into nested functions. Those can't name the impl's generic parameters either, so
+/// `generic` keeps everything in one match.
+pub fn index_lookup(index: &quote::Tokens,
+                    ty: &quote::Tokens,
+                    values: &[quote::Tokens],
+                    generic: bool)
+                    -> quote::Tokens {
+    if values.len() <= CHUNK || generic {
+        let indices = 0..values.len();
+        return quote!{
+            match #index {
+                #(#indices => Some(#values),)*
+                _ => None,
+            }
+        };
+    }
+
+    let chunks = values.chunks(CHUNK).enumerate().map(|(chunk, values)| {
+        let lookup = quote::Ident::from(format!("lookup_{}", chunk));
+        let indices = (0..values.len()).map(|idx| chunk * CHUNK + idx);
+        quote!{
+            #chunk => {
+                fn #lookup(index: usize) -> Option<#ty> {
+                    match index {
+                        #(#indices => Some(#values),)*
+                        _ => None,
+                    }
+                }
+                #lookup(index)
+            }
+        }
+    });
+
+    quote!{
+        {
+            let index: usize = #index;
+            match index / #CHUNK {
+                #(#chunks,)*
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Generates a `static` slice of `(key, index)` pairs named `name`, sorted by key so it can be
+/// binary searched.
+pub fn sorted_table(name: &str, mut entries: Vec<(String, usize)>) -> quote::Tokens {
+    entries.sort();
+    entries.dedup_by(|a, b| a.0 == b.0);
+    let name = quote::Ident::from(name);
+    let entries = entries.iter().map(|&(ref key, idx)| quote!{ (#key, #idx) });
+    quote!{
+        static #name: &[(&str, usize)] = &[#(#entries),*];
+    }
+}
@@ -0,0 +1,475 @@
+//! Checks every `#[strum(..)]` attribute before any code is generated, so a misspelled key or a
+//! value of the wrong kind is reported instead of silently ignored. syn 0.11 doesn't keep spans,
+//! so each error remembers which item of the attributes it's about, and that item is found again
+//! in the input's tokens to point the error at its key. Errors that aren't about one item point at
+//! the name of the enum or variant instead. Every strum derive on the enum reports the same errors
+//! at the same place, which the compiler then only shows once.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote;
+use syn;
+use syn::{Attribute, Lit, MetaItem, NestedMetaItem};
+
+use super::{extract_flag, is_disabled, unraw};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Value {
+    /// `key`, `key=true` or `key="false"`.
+    Flag,
+    /// `key="..."`.
+    Str,
+    /// `key(..)`.
+    List,
+    /// Either `key="..."` or `key(..)`.
+    StrOrList,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Place {
+    Enum,
+    Variant,
+    Both,
+}
+
+/// Every key strum understands, what kind of value it takes, where it goes, and whether it can be
+/// given more than once.
+const KEYS: &[(&str, Value, Place, bool)] = &[
+    ("abbreviations", Value::Flag, Place::Enum, false),
+    ("allow_qualified", Value::Flag, Place::Enum, false),
+    ("ascii_case_insensitive", Value::Flag, Place::Both, false),
+    ("bound", Value::Str, Place::Enum, false),
+    ("capture_input", Value::Flag, Place::Enum, false),
+    ("case_insensitive", Value::Str, Place::Enum, false),
+    ("const_into_str", Value::Flag, Place::Enum, false),
+    ("default", Value::Str, Place::Variant, false),
+    ("default_conversion", Value::Str, Place::Variant, false),
+    ("default_with", Value::Str, Place::Enum, false),
+    ("detailed_message", Value::Str, Place::Variant, false),
+    ("disabled", Value::StrOrList, Place::Variant, true),
+    ("field_delimiter", Value::Str, Place::Enum, false),
+    ("flags_name", Value::Str, Place::Enum, false),
+    ("flags_repr", Value::Str, Place::Enum, false),
+    ("flatten", Value::Flag, Place::Variant, false),
+    ("iter_name", Value::Str, Place::Enum, false),
+    ("iter_vis", Value::Str, Place::Enum, false),
+    ("message", Value::Str, Place::Variant, false),
+    ("message_key", Value::Str, Place::Variant, false),
+    ("message_locale", Value::List, Place::Variant, true),
+    ("no_bound", Value::Flag, Place::Enum, false),
+    ("normalize_separators", Value::Flag, Place::Enum, false),
+    ("parse_discriminant", Value::Flag, Place::Enum, false),
+    ("parse_err_fn", Value::Str, Place::Enum, false),
+    ("parse_err_ty", Value::Str, Place::Enum, false),
+    ("parse_with", Value::Str, Place::Variant, false),
+    ("postgres_type", Value::Str, Place::Enum, false),
+    ("prefix", Value::Str, Place::Enum, false),
+    ("prop_accessors", Value::Flag, Place::Enum, false),
+    ("props", Value::List, Place::Variant, true),
+    ("props_required", Value::List, Place::Enum, true),
+    ("rich_error", Value::Flag, Place::Enum, false),
+    ("serialize", Value::Str, Place::Variant, true),
+    ("serialize_all", Value::Str, Place::Enum, false),
+    ("serialize_pattern", Value::Str, Place::Variant, true),
+    ("shared_table", Value::Flag, Place::Enum, false),
+    ("stable_id", Value::Str, Place::Variant, false),
+    ("suffix", Value::Str, Place::Enum, false),
+    ("suggest", Value::Flag, Place::Enum, false),
+    ("to_string", Value::Str, Place::Variant, false),
+    ("transparent", Value::Flag, Place::Variant, false),
+    ("trim", Value::Flag, Place::Enum, false),
+    ("try_from", Value::Flag, Place::Enum, false),
+    ("use_phf", Value::Flag, Place::Enum, false),
+    ("use_serde_names", Value::Flag, Place::Enum, false),
+];
+
+/// Derives that would still use a `transparent` variant's name even though `Display` prints its
+/// field, so the two would disagree.
+const IGNORE_TRANSPARENT: &[&str] = &["EnumString", "EnumCStr"];
+
+/// A problem with an attribute, on the enum itself or on the variant at `variant`. `item` counts
+/// the items in all of the `#[strum(..)]` attributes there, if the problem is with one of them.
+pub struct Error {
+    variant: Option<usize>,
+    item: Option<usize>,
+    message: String,
+}
+
+/// Where the names of the enum and its variants are in the input, and the first token of every
+/// item in their `#[strum(..)]` attributes.
+pub struct Spans {
+    name: Option<Span>,
+    variants: Vec<Span>,
+    enum_items: Vec<Span>,
+    variant_items: Vec<Vec<Span>>,
+}
+
+/// Adds the first token of each item in `group` to `items`, if it's the brackets of a
+/// `#[strum(..)]` attribute.
+fn strum_items(group: &Group, items: &mut Vec<Span>) {
+    let mut trees = group.stream().into_iter();
+    let args = match (group.delimiter(), trees.next(), trees.next()) {
+        (Delimiter::Bracket, Some(TokenTree::Ident(ident)), Some(TokenTree::Group(args)))
+            if ident.to_string() == "strum" && args.delimiter() == Delimiter::Parenthesis => args,
+        _ => return,
+    };
+
+    let mut expecting = true;
+    for tree in args.stream() {
+        match tree {
+            TokenTree::Punct(ref punct) if punct.as_char() == ',' => expecting = true,
+            _ if expecting => {
+                items.push(tree.span());
+                expecting = false;
+            }
+            _ => (),
+        }
+    }
+}
+
+impl Spans {
+    pub fn of(input: &TokenStream) -> Spans {
+        let mut spans = Spans {
+            name: None,
+            variants: Vec::new(),
+            enum_items: Vec::new(),
+            variant_items: Vec::new(),
+        };
+        let mut tokens = input.clone().into_iter();
+        while let Some(tree) = tokens.next() {
+            match tree {
+                TokenTree::Group(ref group) if spans.name.is_none() => {
+                    strum_items(group, &mut spans.enum_items)
+                }
+                TokenTree::Ident(ref ident) if ident.to_string() == "enum" => {
+                    if let Some(TokenTree::Ident(name)) = tokens.next() {
+                        spans.name = Some(name.span());
+                    }
+                }
+                TokenTree::Group(ref group) if spans.name.is_some() &&
+                                               group.delimiter() == Delimiter::Brace => {
+                    // Each variant's name is the first identifier after the previous comma, since
+                    // its attributes are all in brackets.
+                    let mut expecting = true;
+                    let mut items = Vec::new();
+                    for tree in group.stream() {
+                        match tree {
+                            TokenTree::Punct(ref punct) if punct.as_char() == ',' => expecting = true,
+                            TokenTree::Group(ref group) if expecting => {
+                                strum_items(group, &mut items)
+                            }
+                            TokenTree::Ident(ref ident) if expecting => {
+                                spans.variants.push(ident.span());
+                                spans.variant_items.push(std::mem::take(&mut items));
+                                expecting = false;
+                            }
+                            _ => (),
+                        }
+                    }
+                    break;
+                }
+                _ => (),
+            }
+        }
+        spans
+    }
+}
+
+/// Runs `inner` if the attributes are valid for `derive`, and otherwise returns every problem
+/// with them.
+pub fn checked(ast: &syn::DeriveInput,
+               derive: &str,
+               inner: fn(&syn::DeriveInput) -> quote::Tokens)
+               -> Result<quote::Tokens, Vec<Error>> {
+    let errors = check(ast, derive);
+    if errors.is_empty() { Ok(inner(ast)) } else { Err(errors) }
+}
+
+fn check(ast: &syn::DeriveInput, derive: &str) -> Vec<Error> {
+    let name = unraw(ast.ident.as_ref());
+    let mut messages = Vec::new();
+    check_attrs(&ast.attrs, Place::Enum, name, &mut messages);
+    let mut errors = messages.into_iter()
+        .map(|(item, message)| Error { variant: None, item, message })
+        .collect::<Vec<_>>();
+
+    if let syn::Body::Enum(ref variants) = ast.body {
+        for (idx, variant) in variants.iter().enumerate() {
+            let location = format!("{}::{}", name, unraw(variant.ident.as_ref()));
+            let mut messages = Vec::new();
+            check_attrs(&variant.attrs, Place::Variant, &location, &mut messages);
+            // The attributes have to be well formed before they can be read.
+            if errors.is_empty() && messages.is_empty() && IGNORE_TRANSPARENT.contains(&derive) {
+                check_transparent(&variant.attrs, derive, &location, &mut messages);
+            }
+            errors.extend(messages.into_iter()
+                .map(|(item, message)| Error { variant: Some(idx), item, message }));
+        }
+    }
+    errors
+}
+
+/// A `compile_error!` for each error, at the item it's about, or otherwise the name of the enum or
+/// variant.
+pub fn compile_errors(errors: &[Error], spans: &Spans) -> TokenStream {
+    let mut output = Vec::new();
+    for error in errors {
+        let (name, items) = match error.variant {
+            None => (spans.name, Some(&spans.enum_items)),
+            Some(idx) => (spans.variants.get(idx).cloned(), spans.variant_items.get(idx)),
+        };
+        let item = match (items, error.item) {
+            (Some(items), Some(item)) => items.get(item).cloned(),
+            _ => None,
+        };
+        let span = item.or(name).unwrap_or_else(Span::call_site);
+
+        let mut message = Literal::string(&error.message);
+        message.set_span(span);
+        let mut args = Group::new(Delimiter::Parenthesis, TokenTree::Literal(message).into());
+        args.set_span(span);
+        let mut bang = Punct::new('!', Spacing::Alone);
+        bang.set_span(span);
+        let mut semi = Punct::new(';', Spacing::Alone);
+        semi.set_span(span);
+        output.extend(vec![TokenTree::Ident(Ident::new("compile_error", span)),
+                           TokenTree::Punct(bang),
+                           TokenTree::Group(args),
+                           TokenTree::Punct(semi)]);
+    }
+    output.into_iter().collect()
+}
+
+/// Each error is given with the position of the item it's about, counting the items of every
+/// `#[strum(..)]` attribute in `attrs` in order.
+fn check_attrs(attrs: &[Attribute],
+               place: Place,
+               location: &str,
+               errors: &mut Vec<(Option<usize>, String)>) {
+    let mut seen = Vec::new();
+    let mut position = 0;
+    for attr in attrs.iter().filter(|attr| attr.name() == "strum") {
+        let nested = match attr.value {
+            MetaItem::List(_, ref nested) => nested,
+            _ => {
+                errors.push((None, format!("{}: expected `#[strum(key=\"value\", ..)]`", location)));
+                continue;
+            }
+        };
+
+        for item in nested {
+            let here = Some(position);
+            position += 1;
+            let item = match *item {
+                NestedMetaItem::MetaItem(ref item) => item,
+                NestedMetaItem::Literal(_) => {
+                    errors.push((here,
+                                 format!("{}: expected `key=\"value\"` in #[strum(..)], found \
+                                          a bare literal",
+                                         location)));
+                    continue;
+                }
+            };
+
+            let key = item.name();
+            let &(_, value, allowed, repeatable) = match KEYS.iter().find(|k| k.0 == key) {
+                Some(entry) => entry,
+                None => {
+                    errors.push((here, unknown_key(key, place, location)));
+                    continue;
+                }
+            };
+
+            if allowed != Place::Both && allowed != place {
+                let target = if allowed == Place::Enum { "the enum itself" } else { "variants" };
+                errors.push((here,
+                             format!("{}: `{}` can only be applied to {}", location, key, target)));
+                continue;
+            }
+
+            if let Some(problem) = check_value(item, value) {
+                errors.push((here, format!("{}: `{}` {}", location, key, problem)));
+            }
+
+            if !repeatable && seen.contains(&key) {
+                errors.push((here, format!("{}: `{}` can only be given once", location, key)));
+            }
+            seen.push(key);
+        }
+    }
+}
+
+/// `transparent` only changes `Display`. A derive that would ignore it has to have the variant
+/// disabled, or for `EnumString`, parse the field with `flatten`.
+fn check_transparent(attrs: &[Attribute],
+                     derive: &str,
+                     location: &str,
+                     errors: &mut Vec<(Option<usize>, String)>) {
+    let flag = |key| extract_flag(attrs, "strum", key).unwrap_or(false);
+    if !flag("transparent") || is_disabled(attrs, derive) ||
+       (derive == "EnumString" && flag("flatten")) {
+        return;
+    }
+
+    let fix = if derive == "EnumString" {
+        "Add `flatten` to parse the field instead, or `disabled(EnumString)`"
+    } else {
+        "Add `disabled(EnumCStr)` to leave it out"
+    };
+    errors.push((None,
+                 format!("{}: `transparent` only changes Display, so {} would still use the \
+                          variant's name. {}",
+                         location,
+                         derive,
+                         fix)));
+}
+
+/// Describes what's wrong with the value given to a key, if anything.
+fn check_value(item: &MetaItem, value: Value) -> Option<&'static str> {
+    match (item, value) {
+        (&MetaItem::Word(_), Value::Flag) |
+        (&MetaItem::NameValue(_, Lit::Bool(_)), Value::Flag) |
+        (&MetaItem::NameValue(_, Lit::Str(..)), Value::Str) |
+        (&MetaItem::NameValue(_, Lit::Str(..)), Value::StrOrList) |
+        (&MetaItem::List(..), Value::List) |
+        (&MetaItem::List(..), Value::StrOrList) => None,
+        (&MetaItem::NameValue(_, Lit::Str(ref s, _)), Value::Flag) => {
+            match &**s {
+                "true" | "false" => None,
+                _ => Some("is a flag and only takes `true` or `false`"),
+            }
+        }
+        (_, Value::Flag) => Some("is a flag, write it on its own or with `=true` or `=false`"),
+        (_, Value::Str) => Some("expects a string, as in `key=\"...\"`"),
+        (_, Value::List) => Some("expects a list, as in `key(..)`"),
+        (_, Value::StrOrList) => Some("expects a string or a list"),
+    }
+}
+
+fn unknown_key(key: &str, place: Place, location: &str) -> String {
+    let valid = KEYS.iter()
+        .filter(|k| k.2 == place || k.2 == Place::Both)
+        .map(|k| k.0)
+        .collect::<Vec<_>>();
+
+    let closest = KEYS.iter()
+        .map(|k| (edit_distance(key, k.0), k.0))
+        .filter(|&(distance, _)| distance <= 2)
+        .min_by_key(|&(distance, _)| distance);
+
+    let mut message = format!("{}: unknown strum attribute `{}`", location, key);
+    if let Some((_, closest)) = closest {
+        message.push_str(&format!(", did you mean `{}`?", closest));
+    }
+    message.push_str(&format!(" Valid options here are: {}", valid.join(", ")));
+    message
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..b.len() + 1).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + if ca == cb { 0 } else { 1 };
+            curr.push(::std::cmp::min(substitution, ::std::cmp::min(prev[j + 1], curr[j]) + 1));
+        }
+        prev = curr;
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The first key of `#[strum(..)]`.
+    fn item(source: &str) -> MetaItem {
+        match syn::parse_outer_attr(&format!("#[strum({})]", source)).unwrap().value {
+            MetaItem::List(_, mut nested) => match nested.remove(0) {
+                NestedMetaItem::MetaItem(item) => item,
+                NestedMetaItem::Literal(_) => panic!("{} isn't a key", source),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn errors(source: &str) -> Vec<(Option<usize>, Option<usize>, String)> {
+        let ast = syn::parse_derive_input(source).unwrap();
+        check(&ast, "EnumString")
+            .into_iter()
+            .map(|error| (error.variant, error.item, error.message))
+            .collect()
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(0, edit_distance("serialize", "serialize"));
+        assert_eq!(1, edit_distance("serialise", "serialize"));
+        assert_eq!(1, edit_distance("to_strin", "to_string"));
+        assert_eq!(2, edit_distance("defualt", "default"));
+        assert_eq!(3, edit_distance("kitten", "sitting"));
+        assert_eq!(5, edit_distance("", "props"));
+    }
+
+    #[test]
+    fn values() {
+        assert!(check_value(&item("trim"), Value::Flag).is_none());
+        assert!(check_value(&item("trim=false"), Value::Flag).is_none());
+        assert!(check_value(&item("trim=\"true\""), Value::Flag).is_none());
+        assert!(check_value(&item("trim=\"yes\""), Value::Flag).is_some());
+        assert!(check_value(&item("trim(x)"), Value::Flag).is_some());
+        assert!(check_value(&item("prefix=\"a\""), Value::Str).is_none());
+        assert!(check_value(&item("prefix"), Value::Str).is_some());
+        assert!(check_value(&item("prefix=1"), Value::Str).is_some());
+        assert!(check_value(&item("props(a=1)"), Value::List).is_none());
+        assert!(check_value(&item("props=\"a\""), Value::List).is_some());
+        assert!(check_value(&item("disabled=\"true\""), Value::StrOrList).is_none());
+        assert!(check_value(&item("disabled(Display)"), Value::StrOrList).is_none());
+        assert!(check_value(&item("disabled"), Value::StrOrList).is_some());
+    }
+
+    #[test]
+    fn suggestions() {
+        let message = unknown_key("serialise", Place::Variant, "Color::Red");
+        assert!(message.starts_with("Color::Red: unknown strum attribute `serialise`, did you \
+                                     mean `serialize`? Valid options here are: "),
+                "{}",
+                message);
+        assert!(message.contains("to_string"));
+        assert!(!message.contains("serialize_all"));
+
+        let message = unknown_key("colour", Place::Enum, "Color");
+        assert!(!message.contains("did you mean"), "{}", message);
+        assert!(message.contains("serialize_all"));
+    }
+
+    #[test]
+    fn errors_know_where_they_are() {
+        assert!(errors("#[strum(trim)] enum Color { #[strum(serialize=\"r\")] Red }").is_empty());
+
+        let found = errors("#[strum(serialise_all=\"snake_case\", prefix)]
+                            enum Color {
+                                Red,
+                                #[strum(serialize_all=\"kebab-case\")]
+                                Green,
+                                #[strum(to_string=\"b\", to_string=\"c\")]
+                                Blue,
+                            }");
+        let places = found.iter().map(|&(variant, item, _)| (variant, item)).collect::<Vec<_>>();
+        assert_eq!(vec![(None, Some(0)), (None, Some(1)), (Some(1), Some(0)), (Some(2), Some(1))],
+                   places);
+        assert!(found[0].2.contains("did you mean `serialize_all`?"));
+        assert_eq!("Color: `prefix` expects a string, as in `key=\"...\"`", found[1].2);
+        assert_eq!("Color::Green: `serialize_all` can only be applied to the enum itself",
+                   found[2].2);
+        assert_eq!("Color::Blue: `to_string` can only be given once", found[3].2);
+    }
+
+    #[test]
+    fn raw_identifiers_are_named_as_written() {
+        let found = errors("enum __strum_raw_type { #[strum(serialise=\"t\")] __strum_raw_fn }");
+        assert!(found[0].2.starts_with("type::fn: "), "{}", found[0].2);
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg_attr(not(test), no_std)]
+
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+#[derive(Debug,Eq,PartialEq,EnumString,EnumIter,EnumMessage,Display,IntoStaticStr,EnumCount)]
+#[strum(try_from)]
+pub enum Color {
+    #[strum(message="The color red")]
+    Red,
+    #[strum(serialize="b",serialize="blue")]
+    Blue { hue: usize },
+    Green(u8),
+}
+
+pub mod nested {
+    #[derive(Debug,Eq,PartialEq,EnumString,Display,EnumDiscriminants,FromRepr)]
+    #[strum(ascii_case_insensitive)]
+    pub enum Mode {
+        Off,
+        On(u8),
+    }
+}
+
+/// The generated code has to keep working next to a module that shadows the `core` crate.
+pub mod shadowed {
+    mod core {}
+
+    #[derive(Debug,Eq,PartialEq,EnumString,EnumIter,Display,IntoStaticStr,EnumIndex,FromRepr)]
+    #[strum(try_from)]
+    pub enum Level {
+        Low,
+        #[strum(serialize="hi")]
+        High,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::nested::Mode;
+    use super::shadowed::Level;
+    use std::str::FromStr;
+
+    /// A fixed buffer that formatted text can be written into, like firmware would log with.
+    struct Buffer {
+        bytes: [u8; 16],
+        len: usize,
+    }
+
+    impl Buffer {
+        fn new() -> Buffer {
+            Buffer { bytes: [0; 16], len: 0 }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl core::fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            if end > self.bytes.len() {
+                return Err(core::fmt::Error);
+            }
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn core_paths() {
+        assert_eq!(Ok(Color::Blue { hue: 0 }), Color::from_str("b"));
+        assert_eq!(Ok(Mode::On(0)), Mode::from_str("ON"));
+        assert_eq!("blue", <&'static str>::from(Color::Blue { hue: 2 }));
+        assert_eq!(3, Color::iter().count());
+        assert_eq!(Ok(Color::Red), core::convert::TryFrom::try_from("Red"));
+        assert_eq!(Ok(Level::High), Level::from_str("hi"));
+        assert_eq!(Some(Level::Low), Level::from_repr(0));
+        assert_eq!(1, Level::High.to_index());
+    }
+
+    #[test]
+    fn write_into_buffer() {
+        let mut buf = Buffer::new();
+        Color::Blue { hue: 1 }.write_str(&mut buf).unwrap();
+        Mode::On(3).write_str(&mut buf).unwrap();
+        assert_eq!("blueOn", buf.as_str());
+    }
+}
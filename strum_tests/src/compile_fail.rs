@@ -0,0 +1,371 @@
+//! Derives that have to be rejected. Each example that must not compile follows one that only
+//! differs in what's being rejected, so it can't pass by failing for some other reason.
+//!
+//! `transparent` only changes `Display`, so `EnumString` can't ignore it.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display, EnumString)]
+//! enum Reply {
+//!     #[strum(transparent, flatten)]
+//!     Code(u16),
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display, EnumString)]
+//! enum Reply {
+//!     #[strum(transparent)]
+//!     Code(u16),
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Neither can `EnumCStr`, unless the variant is left out of it.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display, EnumCStr)]
+//! enum Reply {
+//!     #[strum(transparent, disabled(EnumCStr))]
+//!     Code(u16),
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display, EnumCStr)]
+//! enum Reply {
+//!     #[strum(transparent)]
+//!     Code(u16),
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! The error of a generic field's `TryFrom<&str>` impl can't be named, so only `from_str` can pass
+//! it through.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! enum Input<T> {
+//!     #[strum(default="true", default_conversion="from_str")]
+//!     Value(T),
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! enum Input<T> {
+//!     #[strum(default="true", default_conversion="try_from")]
+//!     Value(T),
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! A variant can have as many serializations as it likes, even ones that only differ in case.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! #[strum(ascii_case_insensitive)]
+//! enum Color {
+//!     #[strum(serialize="b", serialize="blue", serialize="BLUE")]
+//!     Blue,
+//!     Red,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! But two variants can't parse from the same string.
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! enum Color {
+//!     #[strum(serialize="b", serialize="blue")]
+//!     Blue,
+//!     #[strum(serialize="b")]
+//!     Black,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! That includes the names `serialize_all` gives them.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! #[strum(serialize_all="snake_case")]
+//! enum Color {
+//!     DarkRed,
+//!     #[strum(serialize="dark-red")]
+//!     Maroon,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! #[strum(serialize_all="snake_case")]
+//! enum Color {
+//!     DarkRed,
+//!     #[strum(serialize="dark_red")]
+//!     Maroon,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Strings that only differ in case are fine, unless one of the variants ignores case.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! enum Color {
+//!     #[strum(serialize="red")]
+//!     Red,
+//!     #[strum(serialize="RED")]
+//!     Alarm,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! enum Color {
+//!     #[strum(serialize="red")]
+//!     Red,
+//!     #[strum(serialize="RED", ascii_case_insensitive)]
+//!     Alarm,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! With `case_insensitive="unicode"`, strings are compared after folding case and accents.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! #[strum(case_insensitive="unicode")]
+//! enum Season {
+//!     #[strum(serialize="été")]
+//!     Summer,
+//!     #[strum(serialize="ete")]
+//!     Holiday,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! #[strum(case_insensitive="unicode")]
+//! enum Season {
+//!     #[strum(serialize="été")]
+//!     Summer,
+//!     #[strum(serialize="ÉTÉ")]
+//!     Holiday,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! With `normalize_separators`, strings that only differ in their separators collide.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! #[strum(normalize_separators)]
+//! enum Season {
+//!     #[strum(serialize="foo-bar")]
+//!     Summer,
+//!     #[strum(serialize="foobar")]
+//!     Holiday,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(EnumString)]
+//! #[strum(normalize_separators)]
+//! enum Season {
+//!     #[strum(serialize="foo-bar")]
+//!     Summer,
+//!     #[strum(serialize="foo_bar")]
+//!     Holiday,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Every key is checked, so a misspelled one is an error instead of being ignored.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display)]
+//! enum Color {
+//!     #[strum(serialize="r")]
+//!     Red,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display)]
+//! enum Color {
+//!     #[strum(serialise="r")]
+//!     Red,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Keys have to be where they apply.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display)]
+//! #[strum(serialize_all="snake_case")]
+//! enum Color {
+//!     DarkRed,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display)]
+//! enum Color {
+//!     #[strum(serialize_all="snake_case")]
+//!     DarkRed,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! And take the right kind of value.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display)]
+//! #[strum(prefix="color/")]
+//! enum Color {
+//!     Red,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display)]
+//! #[strum(prefix)]
+//! enum Color {
+//!     Red,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! A key that only makes sense once can't be repeated, unlike `serialize`.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display)]
+//! enum Color {
+//!     #[strum(to_string="red", serialize="r", serialize="rouge")]
+//!     Red,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display)]
+//! enum Color {
+//!     #[strum(to_string="red", to_string="rouge")]
+//!     Red,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! The discriminants of a `#[non_exhaustive]` enum are non-exhaustive too, so another crate has to
+//! match them with a `_` arm.
+//!
+//! ```
+//! extern crate strum_tests;
+//! use strum_tests::CarrierDiscriminants;
+//!
+//! fn tracked(kind: CarrierDiscriminants) -> bool {
+//!     match kind {
+//!         CarrierDiscriminants::Post | CarrierDiscriminants::Courier => true,
+//!         CarrierDiscriminants::Drone => false,
+//!         _ => false,
+//!     }
+//! }
+//! # fn main() { assert!(tracked(CarrierDiscriminants::Post)); }
+//! ```
+//!
+//! ```compile_fail
+//! extern crate strum_tests;
+//! use strum_tests::CarrierDiscriminants;
+//!
+//! fn tracked(kind: CarrierDiscriminants) -> bool {
+//!     match kind {
+//!         CarrierDiscriminants::Post | CarrierDiscriminants::Courier => true,
+//!         CarrierDiscriminants::Drone => false,
+//!     }
+//! }
+//! # fn main() { assert!(tracked(CarrierDiscriminants::Post)); }
+//! ```
+//!
+//! `shared_table` keeps the strings in the table `EnumVariantNames` adds, so it has to be derived.
+//!
+//! ```
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display, EnumString, EnumVariantNames)]
+//! #[strum(shared_table)]
+//! enum Color {
+//!     Red,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate strum_macros;
+//! # extern crate strum;
+//! #[derive(Display, EnumString)]
+//! #[strum(shared_table)]
+//! enum Color {
+//!     Red,
+//! }
+//! # fn main() {}
+//! ```
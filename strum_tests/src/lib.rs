@@ -2,11 +2,8 @@ extern crate strum;
 #[macro_use]
 extern crate strum_macros;
 
-use std::str::FromStr;
-use strum::{IntoEnumIterator, EnumMessage};
-
-#[derive(Debug,Eq,PartialEq,EnumString,EnumIter,EnumMessage)]
-enum Color {
+#[derive(Debug,Eq,PartialEq,EnumString,EnumIter,EnumMessage,EnumCount,EnumDiscriminants,EnumIndex)]
+pub enum Color {
     #[strum(message="The color red")]
     Red,
     #[strum(message="Blue blue")]
@@ -17,9 +14,821 @@ enum Color {
     Green(String),
 }
 
+#[derive(Debug,Eq,PartialEq,EnumString,Display,EnumVariantNames,IntoStaticStr,EnumIs)]
+pub enum Brightness {
+    #[strum(serialize="dim",serialize="d")]
+    Dim,
+    #[strum(to_string="bright",serialize="b")]
+    Bright { lumens: usize },
+    Blinding(u8),
+    #[strum(disabled="true")]
+    Off,
+}
+
+#[derive(Debug,EnumDiscriminants)]
+#[strum_discriminants(vis="pub")]
+pub enum Shape<'a, T: 'a> {
+    Circle(T),
+    Polygon { points: &'a [T] },
+}
+
+#[allow(dead_code)]
+#[derive(Debug,EnumDiscriminants)]
+#[strum_discriminants(derive(EnumString, Display, PartialOrd), name(RequestKind))]
+#[strum_discriminants(vis(pub(crate)), derive(Hash, EnumMessage))]
+#[strum_discriminants(forward_attrs(doc, strum))]
+pub(crate) enum Request {
+    /// Fetches a resource.
+    #[strum(serialize="get", serialize="fetch")]
+    #[allow(unused)]
+    Get { path: String },
+    Delete(u32),
+}
+
+#[derive(Debug,Eq,PartialEq,FromRepr)]
+pub enum Vehicle {
+    Car = 1,
+    Truck = 5,
+    Bike,
+    #[strum(disabled="true")]
+    Unicycle,
+}
+
+#[derive(Debug,Eq,PartialEq,VariantArray,EnumIs)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+    HTTPNorthWest2,
+    #[strum(disabled="true")]
+    Nowhere,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,Display,EnumVariantNames)]
+#[strum(serialize_all="kebab-case", try_from)]
+pub enum LogLevel {
+    DebugVerbose,
+    Info,
+    #[strum(serialize="warning")]
+    Warn,
+    HTTPError,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(ascii_case_insensitive, try_from)]
+pub enum Answer {
+    Yes,
+    #[strum(serialize="n",serialize="nope")]
+    No,
+    #[strum(serialize="Q",ascii_case_insensitive=false)]
+    Quit,
+    #[strum(default="true")]
+    Other(String),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(trim, try_from, capture_input)]
+pub enum Switch {
+    Enabled,
+    #[strum(serialize="disabled")]
+    Disabled,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(serialize_all="kebab-case", normalize_separators, try_from)]
+pub enum DisplayMode {
+    DarkMode,
+    #[strum(ascii_case_insensitive)]
+    HighContrast,
+    #[strum(serialize="light")]
+    Light,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,EnumCStr)]
+#[strum(case_insensitive="unicode")]
+pub enum Loanword {
+    #[strum(serialize="été")]
+    Summer,
+    #[strum(serialize="straße")]
+    Street,
+    #[strum(serialize="Ωμέγα", ascii_case_insensitive=false)]
+    Omega,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(serialize_all="snake_case", abbreviations)]
+pub enum Subcommand {
+    Status,
+    Stash,
+    #[strum(ascii_case_insensitive)]
+    Commit,
+    #[strum(serialize="log", serialize="history")]
+    Log,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+pub enum Operand {
+    #[strum(serialize="+")]
+    Plus,
+    #[strum(serialize_pattern="reg*")]
+    Register(u8),
+    #[strum(serialize_pattern="op_*")]
+    Op { name: String },
+    #[strum(serialize_pattern="<*>", ascii_case_insensitive)]
+    Placeholder,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(serialize_all="snake_case", parse_discriminant)]
+#[repr(u16)]
+pub enum StatusCode {
+    Ok = 200,
+    Created,
+    NotFound = 404,
+    #[strum(disabled="true")]
+    Teapot = 418,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,Display)]
+#[strum(prefix="size/")]
+pub enum Size {
+    Small,
+    Large,
+    #[strum(serialize="")]
+    Unspecified,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(allow_qualified)]
+pub enum Outline {
+    Circle,
+    #[strum(serialize="sq")]
+    Square,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(rich_error)]
+pub enum Compression {
+    None,
+    #[strum(serialize="gzip", serialize="gz")]
+    Gzip,
+    #[strum(serialize="zstd")]
+    Zstd,
+    #[strum(disabled="true")]
+    Lz4,
+    #[strum(serialize_pattern="level*")]
+    Level(u8),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,Display,IntoStaticStr,EnumVariantNames,EnumMessage)]
+#[strum(serialize_all="kebab-case", shared_table, const_into_str, try_from)]
+pub enum Protocol {
+    #[strum(ascii_case_insensitive)]
+    Http,
+    #[strum(to_string="HTTPS", serialize="tls")]
+    Https,
+    #[strum(disabled(EnumVariantNames), serialize="ws", serialize="web-socket")]
+    WebSocket,
+    Ftp(u16),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(serialize_all="lowercase")]
+pub enum ChemicalElement {
+    Hydrogen,
+    Helium,
+    Lithium,
+    Beryllium,
+    Boron,
+    Carbon,
+    Nitrogen,
+    Oxygen,
+    Fluorine,
+    Neon,
+    Sodium,
+    Magnesium,
+    Aluminium,
+    Silicon,
+    Phosphorus,
+    #[strum(serialize="s", serialize="sulfur", serialize="sulphur")]
+    Sulfur,
+    #[strum(ascii_case_insensitive)]
+    Chlorine,
+    Argon,
+    #[strum(default="true")]
+    Other(String),
+}
+
+/// Enough variants for `from_str` to binary search tables and `EnumIter` to split up `get`.
+#[derive(Debug,Eq,PartialEq,EnumString,EnumIter)]
+pub enum Opcode {
+    Op000, Op001, Op002, Op003, Op004, Op005, Op006, Op007, Op008, Op009, Op010, Op011,
+    Op012, Op013, Op014, Op015, Op016, Op017, Op018, Op019, Op020, Op021, Op022, Op023,
+    Op024, Op025, Op026, Op027, Op028, Op029, Op030, Op031, Op032, Op033, Op034, Op035,
+    Op036, Op037, Op038, Op039, Op040, Op041, Op042, Op043, Op044, Op045, Op046, Op047,
+    Op048, Op049, Op050, Op051, Op052, Op053, Op054, Op055, Op056, Op057, Op058, Op059,
+    Op060, Op061, Op062, Op063, Op064, Op065, Op066, Op067, Op068, Op069, Op070, Op071,
+    Op072, Op073, Op074, Op075, Op076, Op077, Op078, Op079, Op080, Op081, Op082, Op083,
+    Op084, Op085, Op086, Op087, Op088, Op089, Op090, Op091, Op092, Op093, Op094, Op095,
+    Op096, Op097, Op098, Op099, Op100, Op101, Op102, Op103, Op104, Op105, Op106, Op107,
+    Op108, Op109, Op110, Op111, Op112, Op113, Op114, Op115, Op116, Op117, Op118, Op119,
+    Op120, Op121, Op122, Op123, Op124, Op125, Op126, Op127, Op128, Op129, Op130, Op131,
+    Op132, Op133, Op134, Op135, Op136, Op137, Op138, Op139, Op140, Op141, Op142, Op143,
+    Op144, Op145, Op146, Op147, Op148, Op149, Op150, Op151, Op152, Op153, Op154, Op155,
+    Op156, Op157, Op158, Op159, Op160, Op161, Op162, Op163, Op164, Op165, Op166, Op167,
+    Op168, Op169, Op170, Op171, Op172, Op173, Op174, Op175, Op176, Op177, Op178, Op179,
+    Op180, Op181, Op182, Op183, Op184, Op185, Op186, Op187, Op188, Op189, Op190, Op191,
+    Op192, Op193, Op194, Op195, Op196, Op197, Op198, Op199, Op200, Op201, Op202, Op203,
+    Op204, Op205, Op206, Op207, Op208, Op209, Op210, Op211, Op212, Op213, Op214, Op215,
+    Op216, Op217, Op218, Op219, Op220, Op221, Op222, Op223, Op224, Op225, Op226, Op227,
+    Op228, Op229, Op230, Op231, Op232, Op233, Op234, Op235, Op236, Op237, Op238, Op239,
+    Op240, Op241, Op242, Op243, Op244, Op245, Op246, Op247, Op248, Op249, Op250, Op251,
+    Op252, Op253, Op254, Op255, Op256, Op257, Op258, Op259, Op260, Op261, Op262, Op263,
+    Op264, Op265, Op266, Op267, Op268, Op269, Op270, Op271, Op272, Op273, Op274, Op275,
+    Op276, Op277, Op278, Op279, Op280, Op281, Op282, Op283, Op284, Op285, Op286, Op287,
+    Op288, Op289, Op290, Op291, Op292, Op293, Op294, Op295, Op296,
+    #[strum(ascii_case_insensitive)]
+    Halt,
+    #[strum(serialize="ld", serialize="load")]
+    Load(u8),
+    #[strum(default="true")]
+    Unknown(String),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,EnumIter,EnumCount,EnumVariantNames,Display)]
+pub enum Platform {
+    Linux,
+    #[cfg(any())]
+    Fuchsia,
+    #[cfg_attr(test, strum(serialize="macos", to_string="macOS"))]
+    MacOs,
+    #[cfg(test)]
+    Windows,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,EnumIter,EnumDiscriminants)]
+#[non_exhaustive]
+pub enum Carrier {
+    Post,
+    Courier(u32),
+    #[non_exhaustive]
+    Drone,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,Display,EnumVariantNames,EnumIs)]
+#[allow(non_camel_case_types)]
+pub enum ReservedWord {
+    r#type,
+    r#match,
+    #[strum(serialize="fn")]
+    r#fn,
+    #[strum(serialize="__strum_raw_other")]
+    Other,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,Display)]
+pub enum Wrapper<T> {
+    Empty,
+    Filled(T),
+    #[strum(to_string="pair {0:?} and {1}")]
+    Pair(T, u8),
+    #[strum(default="true", default_conversion="from_str")]
+    Parsed(T),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(bound="T: std::str::FromStr")]
+pub enum Setting<T> {
+    Unset,
+    #[strum(serialize="=", parse_with="parse_setting")]
+    Value(T),
+}
+
+fn parse_setting<T: std::str::FromStr>(s: &str) -> Result<Setting<T>, strum::ParseError> {
+    s.parse()
+        .map(Setting::Value)
+        .map_err(|_| strum::ParseError::VariantNotFound { type_name: "Setting" })
+}
+
+#[derive(Debug,Eq,PartialEq,EnumIter)]
+#[strum(no_bound)]
+pub enum Batch<T> {
+    Empty,
+    Items(Vec<T>),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(trim)]
+pub enum Lexeme<'a> {
+    #[strum(serialize="fn")]
+    Fn,
+    #[strum(serialize="(")]
+    OpenParen,
+    #[strum(default="true")]
+    Word(&'a str),
+}
+
+/// Stands in for enums from another crate, which can't have derives added to them.
+pub mod foreign {
+    #[derive(Debug,Eq,PartialEq)]
+    pub enum Method {
+        Get,
+        Post,
+        Extension(String),
+    }
+
+    #[derive(Debug,Eq,PartialEq)]
+    #[non_exhaustive]
+    pub enum Signal {
+        Hangup,
+        Kill { code: i32 },
+        Stop,
+    }
+}
+
+impl_for_external! {
+    #[derive(Debug,Eq,PartialEq,EnumString,Display,EnumCount)]
+    #[strum(serialize_all="UPPERCASE")]
+    pub enum foreign::Method {
+        Get,
+        Post,
+        #[strum(default="true")]
+        Extension(String),
+    }
+}
+
+impl_for_external! {
+    #[derive(Debug,Eq,PartialEq,Display)]
+    #[non_exhaustive]
+    pub enum foreign::Signal as SignalName {
+        #[strum(to_string="SIGHUP")]
+        Hangup,
+        #[strum(to_string="SIGKILL")]
+        Kill { code: i32 },
+    }
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+pub enum Toggle {
+    #[strum(ascii_case_insensitive)]
+    On,
+    Off,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,Display,IntoStaticStr,EnumVariantNames)]
+#[strum(prefix="color/",serialize_all="snake_case")]
+pub enum Namespaced {
+    DarkRed,
+    #[strum(serialize="blue",serialize="b")]
+    Blue,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,Display)]
+#[strum(prefix="api.",suffix="_v2")]
+pub enum Endpoint {
+    Users,
+    #[strum(to_string="orders")]
+    Orders,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,EnumIter,Display)]
+#[strum(use_phf)]
+pub enum Nato {
+    #[strum(serialize="alpha",serialize="a")]
+    Alfa,
+    Bravo,
+    Charlie,
+    Delta,
+    Echo,
+    Foxtrot,
+    Golf,
+    Hotel,
+    India,
+    Juliett,
+    Kilo,
+    Lima,
+    Mike,
+    November,
+    Oscar,
+    Papa,
+    Quebec,
+    Romeo,
+    Sierra,
+    Tango,
+    Uniform,
+    Victor,
+    Whiskey,
+    Xray,
+    Yankee,
+    #[strum(ascii_case_insensitive)]
+    Zulu,
+    #[strum(default="true")]
+    Unknown(String),
+}
+
+#[derive(Debug,Eq,PartialEq,IntoStaticStr)]
+#[strum(const_into_str,serialize_all="lowercase")]
+pub enum Planet {
+    Mercury,
+    #[strum(serialize="terra",serialize="earth")]
+    Earth,
+    #[strum(ascii_case_insensitive)]
+    Mars,
+}
+
+pub static PLANET_NAMES: [&str; 3] = [Planet::Mercury.as_str(),
+                                      Planet::Earth.as_str(),
+                                      Planet::Mars.as_str()];
+
+#[derive(Debug,Eq,PartialEq,EnumString,IntoStaticStr,EnumCStr)]
+#[strum(const_into_str,case_insensitive="ascii")]
+pub enum Greek {
+    Alpha,
+    Beta,
+}
+
+#[derive(Debug,IntoStaticStr)]
+#[strum(const_into_str)]
+pub enum Measurement {
+    Length(f32),
+    Unknown,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(capture_input,use_phf,try_from)]
+pub enum Fruit {
+    Apple,
+    Banana,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(suggest)]
+pub enum Shade {
+    Green,
+    #[strum(serialize="grey",serialize="gray")]
+    Grey,
+    Purple,
+}
+
+#[derive(Debug,Eq,PartialEq)]
+pub enum ConfigError {
+    UnknownBackend(String),
+}
+
+impl ConfigError {
+    pub fn unknown_backend(input: &str) -> ConfigError {
+        ConfigError::UnknownBackend(input.to_string())
+    }
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(parse_err_ty="ConfigError", parse_err_fn="ConfigError::unknown_backend")]
+pub enum Backend {
+    Sqlite,
+    Postgres,
+}
+
+#[derive(Debug,Eq,PartialEq,strum::EnumString,strum::Display)]
+pub enum Reexported {
+    #[strum(serialize="first")]
+    First,
+    Second,
+}
+
+#[derive(Debug,Eq,PartialEq,EnumIter,EnumIndex,EnumRotate)]
+pub enum Container<T, U> where U: Clone {
+    Empty,
+    Full(T),
+    Pair(T, Option<U>),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString,EnumIter,Display)]
+pub enum Status {
+    Active,
+    #[strum(disabled(EnumIter, Display))]
+    Legacy,
+    Retired,
+}
+
+// `EnumIter` only adds the functions it documents, so this doesn't clash with anything.
+impl Status {
+    pub fn iter_positions(&self) -> (usize, usize) {
+        (0, 0)
+    }
+}
+
+#[derive(EnumMessage)]
+pub enum Command {
+    /// Opens a file.
+    Open,
+    /// Saves the current file.
+    ///
+    /// Overwrites any existing file.
+    #[strum(message="Save")]
+    Save,
+    Quit,
+}
+
+#[derive(Debug,EnumMessage)]
+pub enum Mismatch {
+    #[strum(message="expected {expected} but got {found:?}")]
+    Type { expected: &'static str, found: String },
+    #[strum(message="line {0}, column {{{1}}}")]
+    At(usize, usize),
+    #[strum(message="{0} is out of range")]
+    Range(u8, String),
+    #[strum(message="unknown")]
+    Unknown,
+}
+
+#[derive(EnumMessage)]
+pub enum MenuItem {
+    #[strum(message="Open", message_locale(de="Öffnen", fr="Ouvrir"))]
+    Open,
+    #[strum(message="Close", message_key="menu-close")]
+    Close,
+    #[strum(message_key="menu-help")]
+    Help,
+}
+
+#[derive(EnumProperty)]
+#[strum(prop_accessors)]
+pub enum Class {
+    #[strum(props(Teacher="Ms.Frizzle", Room=201, Online=false))]
+    History,
+    #[strum(props(Teacher="Mr.Smith"))]
+    #[strum(props(Online=true))]
+    Math { grade: u8 },
+    Recess,
+    #[strum(disabled="true", props(Teacher="Nobody"))]
+    Detention,
+}
+
+#[derive(EnumProperty)]
+#[strum(props_required(Symbol, Protons))]
+pub enum Element {
+    #[strum(props(Symbol="H", Protons=1, Name="Hydrogen"))]
+    Hydrogen,
+    #[strum(props(Symbol="He", Protons=2))]
+    Helium,
+}
+
+#[derive(EnumProperty)]
+pub enum Celestial {
+    #[strum(props(Name="Mercury", Moons=0, Type="rocky", Rings=false, Symbol="\u{263f}", Order=1,
+                  Atmosphere="thin", Gas=false, Day=1408, Aphelion=70, Discovered="antiquity"))]
+    Mercury,
+    #[strum(props(Name="Saturn", Moons=146, Rings=true, Type="gas", Order=6, Gas=true))]
+    Saturn,
+}
+
+#[derive(Display)]
+pub enum Cart {
+    #[strum(to_string="{0} items")]
+    Items(usize),
+    #[strum(to_string="range {range} to {end:>3}")]
+    Range { range: u8, end: u8 },
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+pub enum Token {
+    #[strum(serialize="quit")]
+    Quit,
+    #[strum(flatten)]
+    Level { level: LogLevel },
+    // Color has a default variant, so it has to come last.
+    #[strum(flatten)]
+    Color(Color),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+pub enum Label {
+    None,
+    #[strum(default="true")]
+    Other(Box<str>),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+pub enum Handle {
+    Root,
+    #[strum(default="true")]
+    Named(std::sync::Arc<str>),
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(capture_input)]
+pub enum Port {
+    #[strum(serialize="http")]
+    Http,
+    #[strum(default="true", default_conversion="from_str")]
+    Number(u16),
+}
+
+#[derive(Debug,Eq,PartialEq)]
+pub struct Tag(String);
+
+impl<'a> core::convert::TryFrom<&'a str> for Tag {
+    type Error = ();
+
+    fn try_from(s: &'a str) -> Result<Tag, ()> {
+        s.strip_prefix('#').map(|tag| Tag(tag.to_string())).ok_or(())
+    }
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+pub enum Keyword {
+    Fn,
+    #[strum(default="true")]
+    Unknown { name: String },
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(default_with="Ident::normalize")]
+pub enum Ident {
+    SelfValue,
+    Other(String),
+}
+
+impl Ident {
+    fn normalize(s: &str) -> Result<Ident, strum::ParseError> {
+        if s.is_empty() {
+            Err(strum::ParseError::VariantNotFound { type_name: "Ident" })
+        } else {
+            Ok(Ident::Other(s.to_lowercase()))
+        }
+    }
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(field_delimiter=":", ascii_case_insensitive)]
+pub enum Paint {
+    Red,
+    Blue(u8),
+    #[strum(serialize="rgb")]
+    Rgb(u8, u8, u8),
+    Named { name: String },
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+#[strum(ascii_case_insensitive)]
+pub enum Swatch {
+    Black,
+    #[strum(serialize="#", parse_with="Swatch::parse_hex")]
+    Hex(u32),
+    #[strum(serialize="gray", parse_with="Swatch::parse_gray")]
+    Gray(u8),
+}
+
+impl Swatch {
+    fn parse_hex(rest: &str) -> Result<Swatch, strum::ParseError> {
+        u32::from_str_radix(rest, 16)
+            .map(Swatch::Hex)
+            .map_err(|_| strum::ParseError::VariantNotFound { type_name: "Swatch" })
+    }
+
+    fn parse_gray(rest: &str) -> Result<Swatch, strum::ParseError> {
+        Ok(Swatch::Gray(rest.parse().unwrap_or(128)))
+    }
+}
+
+#[derive(Debug,Eq,PartialEq,EnumString)]
+pub enum Mention {
+    #[strum(default="true", default_conversion="try_from")]
+    Tag(Tag),
+}
+
+#[derive(Display)]
+pub enum AppError {
+    NotFound,
+    #[strum(transparent)]
+    Other(String),
+    #[strum(transparent)]
+    Io { code: i32 },
+}
+
+#[derive(Debug,Eq,PartialEq,Display,EnumString)]
+pub enum Verbosity {
+    Quiet,
+    #[strum(transparent, flatten)]
+    Level(LogLevel),
+    #[strum(transparent, disabled(EnumString))]
+    Custom(String),
+}
+
+#[derive(Display)]
+#[strum(serialize_all="SCREAMING_SNAKE_CASE")]
+pub enum Shouty {
+    DarkMode,
+}
+
+#[derive(Debug,Eq,PartialEq,Display,EnumString)]
+#[allow(clippy::enum_variant_names)]
+pub enum Tide {
+    #[strum(serialize="high")]
+    HighTide,
+    #[allow(clippy::all)]
+    LowTide,
+}
+
+#[derive(Debug,Eq,PartialEq,Display,EnumString)]
+#[strum(serialize_all="SCREAMING-KEBAB-CASE")]
+pub enum ShoutyKebab {
+    DarkMode,
+    HTTPServer,
+}
+
+#[derive(Display)]
+#[strum(serialize_all="camelCase")]
+pub enum Camel {
+    DarkModeOn,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumSetType)]
+pub enum Permission {
+    Read,
+    Write,
+    Execute,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumFlags)]
+pub enum Capability {
+    Read,
+    Write,
+    ExecuteCode,
+}
+
+#[derive(EnumFlags)]
+#[strum(flags_name="Modes", flags_repr="u32")]
+pub enum Mode {
+    Fast,
+    Safe,
+}
+
+#[derive(Debug, PartialEq, EnumRotate)]
+pub enum Theme {
+    Light,
+    Dark,
+    #[strum(disabled(EnumRotate))]
+    System,
+    Custom { accent: u32 },
+}
+
+#[derive(Debug, PartialEq, EnumStableId)]
+pub enum JobEvent {
+    Started,
+    #[strum(to_string="stopped")]
+    Stopped,
+    #[strum(stable_id="paused")]
+    Suspended { since: u64 },
+}
+
+#[derive(Debug, PartialEq, EnumString)]
+pub enum Punct {
+    #[strum(serialize="(")]
+    OpenParen,
+    #[strum(serialize=")")]
+    CloseParen,
+    #[strum(serialize="+", serialize="p", ascii_case_insensitive)]
+    Plus,
+}
+
+#[derive(Debug, PartialEq, EnumCStr)]
+#[strum(serialize_all="snake_case", ascii_case_insensitive)]
+pub enum LogTarget {
+    Stderr,
+    #[strum(serialize="syslog", serialize="log")]
+    SystemLog,
+    File { fd: i32 },
+}
+
+// Wide enough that its sets need more than a u128.
+#[derive(Debug, Clone, Copy, PartialEq, EnumSetType)]
+pub enum Wide {
+    W0, W1, W2, W3, W4, W5, W6, W7, W8, W9, W10, W11, W12,
+    W13, W14, W15, W16, W17, W18, W19, W20, W21, W22, W23, W24, W25,
+    W26, W27, W28, W29, W30, W31, W32, W33, W34, W35, W36, W37, W38,
+    W39, W40, W41, W42, W43, W44, W45, W46, W47, W48, W49, W50, W51,
+    W52, W53, W54, W55, W56, W57, W58, W59, W60, W61, W62, W63, W64,
+    W65, W66, W67, W68, W69, W70, W71, W72, W73, W74, W75, W76, W77,
+    W78, W79, W80, W81, W82, W83, W84, W85, W86, W87, W88, W89, W90,
+    W91, W92, W93, W94, W95, W96, W97, W98, W99, W100, W101, W102, W103,
+    W104, W105, W106, W107, W108, W109, W110, W111, W112, W113, W114, W115, W116,
+    W117, W118, W119, W120, W121, W122, W123, W124, W125, W126, W127, W128, W129,
+}
+
+mod compile_fail;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
+    use std::string::ToString;
+    use strum::{IntoEnumIterator, EnumMessage, EnumProperty, EnumCount, VariantNames, VariantArray};
+
+    fn not_found(type_name: &'static str) -> strum::ParseError {
+        strum::ParseError::VariantNotFound { type_name }
+    }
 
     #[test]
     fn from_str_basic() {
@@ -31,4 +840,1015 @@ mod tests {
         assert_eq!(Ok(Color::Green(String::from("White"))),
                    Color::from_str("White"));
     }
+
+    #[test]
+    fn display_preferred_name() {
+        assert_eq!("dim", Brightness::Dim.to_string());
+        assert_eq!("bright", Brightness::Bright { lumens: 3 }.to_string());
+        assert_eq!("Blinding", Brightness::Blinding(255).to_string());
+    }
+
+    #[test]
+    fn display_padding() {
+        assert_eq!("   dim", format!("{:>6}", Brightness::Dim));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        assert_eq!(Ok(Brightness::Bright { lumens: 0 }), Brightness::from_str("bright"));
+        assert_eq!(Ok(Brightness::Bright { lumens: 0 }), Brightness::from_str("b"));
+        assert_eq!(Ok(Brightness::Dim),
+                   Brightness::from_str(&Brightness::Dim.to_string()));
+    }
+
+    #[test]
+    fn count_matches_iter() {
+        assert_eq!(4, Color::COUNT);
+        assert_eq!(Color::iter().count(), <Color as EnumCount>::COUNT);
+    }
+
+    #[test]
+    fn discriminants_from_ref_and_value() {
+        assert_eq!(ColorDiscriminants::Blue, (&Color::Blue { hue: 7 }).into());
+        assert_eq!(ColorDiscriminants::Green,
+                   ColorDiscriminants::from(Color::Green(String::from("lime"))));
+    }
+
+    #[test]
+    fn discriminants_generic_enum() {
+        let points = [1, 2, 3];
+        assert_eq!(ShapeDiscriminants::Circle, ShapeDiscriminants::from(&Shape::Circle(1.0)));
+        assert_eq!(ShapeDiscriminants::Polygon,
+                   ShapeDiscriminants::from(Shape::Polygon { points: &points }));
+    }
+
+    #[test]
+    fn discriminants_options() {
+        let kind = RequestKind::from(&Request::Delete(3));
+        assert_eq!(RequestKind::Delete, kind);
+        assert_eq!("fetch", RequestKind::Get.to_string());
+        assert_eq!(Ok(RequestKind::Get), "get".parse::<RequestKind>());
+        assert_eq!(Ok(RequestKind::Delete), "Delete".parse::<RequestKind>());
+        assert_eq!(Some("Fetches a resource."), RequestKind::Get.get_documentation());
+        assert_eq!(None, RequestKind::Delete.get_documentation());
+        assert!(RequestKind::Get < kind);
+        assert_eq!(RequestKind::Get, RequestKind::from(Request::Get { path: "/".into() }));
+    }
+
+    #[test]
+    fn into_discriminant() {
+        use strum::IntoDiscriminant;
+
+        fn kinds<E: IntoDiscriminant>(values: &[E]) -> Vec<E::Discriminant> {
+            values.iter().map(|value| value.discriminant()).collect()
+        }
+
+        assert_eq!(vec![ColorDiscriminants::Red, ColorDiscriminants::Blue],
+                   kinds(&[Color::Red, Color::Blue { hue: 2 }]));
+        assert_eq!(ShapeDiscriminants::Circle, Shape::Circle(2u8).discriminant());
+    }
+
+    #[test]
+    fn from_repr_explicit_discriminants() {
+        assert_eq!(None, Vehicle::from_repr(0));
+        assert_eq!(Some(Vehicle::Car), Vehicle::from_repr(1));
+        assert_eq!(Some(Vehicle::Truck), Vehicle::from_repr(5));
+        assert_eq!(Some(Vehicle::Bike), Vehicle::from_repr(6));
+        assert_eq!(None, Vehicle::from_repr(7));
+    }
+
+    #[test]
+    fn repr_conversions() {
+        use std::convert::TryFrom;
+
+        assert_eq!(5usize, Vehicle::Truck.into());
+        assert_eq!(7, usize::from(Vehicle::Unicycle));
+        assert_eq!(Ok(Vehicle::Bike), Vehicle::try_from(6));
+        assert_eq!(Err(strum::FromReprError { value: 7 }), Vehicle::try_from(7));
+        assert_eq!("No variant has the discriminant 0",
+                   Vehicle::try_from(0).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn variant_names_match_display() {
+        assert_eq!(["dim", "bright", "Blinding"], Brightness::VARIANTS);
+    }
+
+    #[test]
+    fn variant_array_skips_disabled() {
+        assert_eq!(&[Direction::North,
+                     Direction::East,
+                     Direction::South,
+                     Direction::West,
+                     Direction::HTTPNorthWest2],
+                   Direction::VARIANTS);
+    }
+
+    #[test]
+    fn into_static_str_matches_display() {
+        let dim: &'static str = Brightness::Dim.into();
+        assert_eq!("dim", dim);
+        assert_eq!("bright", <&'static str>::from(&Brightness::Bright { lumens: 1 }));
+        assert_eq!("Blinding", <&'static str>::from(Brightness::Blinding(2)));
+    }
+
+    #[test]
+    fn try_from_str_and_string() {
+        use std::convert::TryFrom;
+
+        assert_eq!(Ok(LogLevel::Warn), LogLevel::try_from("warning"));
+        assert_eq!(Ok(LogLevel::HTTPError), LogLevel::try_from(String::from("http-error")));
+        assert_eq!(Err(not_found("LogLevel")), LogLevel::try_from("Warn"));
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        use std::convert::TryFrom;
+
+        assert_eq!(Ok(LogLevel::DebugVerbose), LogLevel::try_from(&b"debug-verbose"[..]));
+        assert_eq!(Err(not_found("LogLevel")), LogLevel::try_from(&b"info\xff"[..]));
+        assert_eq!(Ok(Answer::Yes), Answer::try_from(&b"YES"[..]));
+        assert_eq!(Ok(Answer::Other("\u{e9}t\u{e9}".into())),
+                   Answer::try_from("\u{e9}t\u{e9}".as_bytes()));
+        assert_eq!(Ok(Fruit::Banana), Fruit::try_from(&b"Banana"[..]));
+        let err = Fruit::try_from(&b"Fig\xc3"[..]).unwrap_err();
+        assert_eq!("Fig", err.input);
+    }
+
+    #[test]
+    fn char_conversions() {
+        use std::convert::TryFrom;
+
+        assert_eq!(Ok(Punct::OpenParen), Punct::try_from('('));
+        assert_eq!(Ok(Punct::Plus), Punct::try_from('P'));
+        assert_eq!(Err(not_found("Punct")), Punct::try_from('-'));
+        assert_eq!(')', char::from(Punct::CloseParen));
+        let plus: char = Punct::Plus.into();
+        assert_eq!('+', plus);
+    }
+
+    #[test]
+    fn try_from_os_str() {
+        use std::convert::TryFrom;
+        use std::ffi::{OsStr, OsString};
+
+        assert_eq!(Ok(LogLevel::Info), LogLevel::try_from(OsStr::new("info")));
+        let arg = OsString::from("Banana");
+        assert_eq!(Ok(Fruit::Banana), Fruit::try_from(arg.as_os_str()));
+        assert_eq!(Err(strum::ParseError::EmptyInput), LogLevel::try_from(OsStr::new("")));
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            let err = Fruit::try_from(OsStr::from_bytes(b"Banana\xff")).unwrap_err();
+            assert_eq!("Banana", err.input);
+        }
+    }
+
+    #[test]
+    fn max_serialized_len() {
+        // "Blinding" is the longest, and the disabled `Off` doesn't count.
+        const BUF_LEN: usize = Brightness::MAX_SERIALIZED_LEN;
+        assert_eq!(8, BUF_LEN);
+        let buf = [0u8; Brightness::MAX_SERIALIZED_LEN];
+        assert_eq!(8, buf.len());
+    }
+
+    #[test]
+    fn compare_with_str() {
+        assert!(Brightness::Dim == "dim");
+        assert!(Brightness::Dim != "d");
+        assert!("bright" == Brightness::Bright { lumens: 3 });
+        assert!(*"Blinding" == Brightness::Blinding(0));
+        assert!(Brightness::Blinding(0) == *"Blinding");
+        assert!(Brightness::Off != "Off");
+        assert_eq!(Brightness::Dim, "dim");
+    }
+
+    #[test]
+    fn enum_is_predicates() {
+        const IS_DIM: [bool; 2] = [Brightness::Dim.is_dim(), Brightness::Dim.is_blinding()];
+        assert_eq!([true, false], IS_DIM);
+        assert!(Brightness::Bright { lumens: 2 }.is_bright());
+        assert!(!Brightness::Blinding(3).is_bright());
+        assert!(Direction::HTTPNorthWest2.is_http_north_west2());
+    }
+
+    #[test]
+    fn serialize_all_applies_to_parse_and_display() {
+        assert_eq!(Ok(LogLevel::DebugVerbose), LogLevel::from_str("debug-verbose"));
+        assert!(LogLevel::from_str("DebugVerbose").is_err());
+        assert_eq!("http-error", LogLevel::HTTPError.to_string());
+        assert_eq!(["debug-verbose", "info", "warning", "http-error"], LogLevel::VARIANTS);
+    }
+
+    #[test]
+    fn serialize_all_other_styles() {
+        assert_eq!("DARK_MODE", Shouty::DarkMode.to_string());
+        assert_eq!("darkModeOn", Camel::DarkModeOn.to_string());
+    }
+
+    #[test]
+    fn unreadable_attributes_are_skipped() {
+        assert_eq!(Ok(Tide::HighTide), Tide::from_str("high"));
+        assert_eq!("LowTide", Tide::LowTide.to_string());
+    }
+
+    #[test]
+    fn serialize_all_screaming_kebab_case() {
+        assert_eq!("DARK-MODE", ShoutyKebab::DarkMode.to_string());
+        assert_eq!("HTTP-SERVER", ShoutyKebab::HTTPServer.to_string());
+        assert_eq!(Ok(ShoutyKebab::HTTPServer), ShoutyKebab::from_str("HTTP-SERVER"));
+        assert!(ShoutyKebab::from_str("http-server").is_err());
+    }
+
+    #[test]
+    fn ascii_case_insensitive_parse() {
+        assert_eq!(Ok(Answer::Yes), Answer::from_str("yes"));
+        assert_eq!(Ok(Answer::Yes), Answer::from_str("YES"));
+        assert_eq!(Ok(Answer::No), Answer::from_str("N"));
+        assert_eq!(Ok(Answer::No), Answer::from_str("NoPe"));
+        assert_eq!(Ok(Answer::Other(String::from("Maybe"))), Answer::from_str("Maybe"));
+    }
+
+    #[test]
+    fn ascii_case_insensitive_variant_override() {
+        assert_eq!(Ok(Answer::Quit), Answer::from_str("Q"));
+        assert_eq!(Ok(Answer::Other(String::from("q"))), Answer::from_str("q"));
+        assert_eq!(Ok(Toggle::On), Toggle::from_str("ON"));
+        assert!(Toggle::from_str("OFF").is_err());
+    }
+
+    #[test]
+    fn prefix_display_and_parse() {
+        assert_eq!("color/dark_red", Namespaced::DarkRed.to_string());
+        assert_eq!("color/blue", <&'static str>::from(Namespaced::Blue));
+        assert_eq!(["color/dark_red", "color/blue"], Namespaced::VARIANTS);
+        assert_eq!(Ok(Namespaced::Blue), Namespaced::from_str("color/b"));
+        assert!(Namespaced::from_str("blue").is_err());
+    }
+
+    #[test]
+    fn suffix_display_and_parse() {
+        assert_eq!("api.Users_v2", Endpoint::Users.to_string());
+        assert_eq!("api.orders_v2", Endpoint::Orders.to_string());
+        assert_eq!(Ok(Endpoint::Orders), Endpoint::from_str("api.orders_v2"));
+        assert!(Endpoint::from_str("api.orders").is_err());
+    }
+
+    #[test]
+    fn phf_round_trip() {
+        for letter in Nato::iter().filter(|l| !matches!(l, Nato::Unknown(..))) {
+            assert_eq!(Ok(&letter), Nato::from_str(&letter.to_string()).as_ref());
+        }
+    }
+
+    #[test]
+    fn phf_serializations_and_default() {
+        assert_eq!(Ok(Nato::Alfa), Nato::from_str("a"));
+        assert_eq!(Ok(Nato::Zulu), Nato::from_str("ZULU"));
+        assert_eq!(Ok(Nato::Unknown(String::from("golf"))), Nato::from_str("golf"));
+        assert_eq!(Ok(Nato::Unknown(String::new())), Nato::from_str(""));
+    }
+
+    #[test]
+    fn const_into_str() {
+        const UNKNOWN: &str = Measurement::Unknown.as_str();
+        assert_eq!(["mercury", "terra", "mars"], PLANET_NAMES);
+        assert_eq!("Length", Measurement::Length(1.5).as_str());
+        assert_eq!("Unknown", UNKNOWN);
+    }
+
+    #[test]
+    fn const_from_str() {
+        const EARTH: Option<Planet> = Planet::from_str_const("earth");
+        assert_eq!(Some(Planet::Earth), EARTH);
+        assert_eq!(Some(Planet::Mars), Planet::from_str_const("MARS"));
+        assert_eq!(None, Planet::from_str_const("MERCURY"));
+    }
+
+    #[test]
+    fn normalize_separators() {
+        use std::convert::TryFrom;
+
+        assert_eq!(Ok(DisplayMode::DarkMode), DisplayMode::from_str("dark-mode"));
+        assert_eq!(Ok(DisplayMode::DarkMode), DisplayMode::from_str("dark_mode"));
+        assert_eq!(Ok(DisplayMode::DarkMode), DisplayMode::from_str("dark mode"));
+        assert_eq!(Ok(DisplayMode::HighContrast), DisplayMode::from_str("High_Contrast"));
+        assert_eq!(Ok(DisplayMode::DarkMode), DisplayMode::try_from(&b"dark_mode"[..]));
+        assert_eq!(Ok(DisplayMode::Light), DisplayMode::from_str("light"));
+        assert!(DisplayMode::from_str("Dark_Mode").is_err());
+        assert!(DisplayMode::from_str("darkmode").is_err());
+    }
+
+    #[test]
+    fn unicode_case_folding() {
+        assert_eq!(Ok(Loanword::Summer), Loanword::from_str("ÉTÉ"));
+        assert_eq!(Ok(Loanword::Summer), Loanword::from_str("e\u{301}te\u{301}"));
+        assert_eq!(Ok(Loanword::Street), Loanword::from_str("STRASSE"));
+        assert_eq!(Ok(Loanword::Omega), Loanword::from_str("Ωμέγα"));
+        assert!(Loanword::from_str("ΩΜΈΓΑ").is_err());
+        assert!(Loanword::from_str("ete").is_err());
+    }
+
+    #[test]
+    fn unicode_case_folding_cstr() {
+        use std::convert::TryFrom;
+        use std::ffi::CStr;
+
+        let summer = CStr::from_bytes_with_nul("ÉTÉ\0".as_bytes()).unwrap();
+        assert_eq!(Ok(Loanword::Summer), Loanword::try_from(summer));
+        let omega = CStr::from_bytes_with_nul("ΩΜΈΓΑ\0".as_bytes()).unwrap();
+        assert!(Loanword::try_from(omega).is_err());
+        let invalid = CStr::from_bytes_with_nul(b"\xe9t\xe9\0").unwrap();
+        assert!(Loanword::try_from(invalid).is_err());
+    }
+
+    #[test]
+    fn case_insensitive_ascii() {
+        use std::convert::TryFrom;
+        use std::ffi::CStr;
+
+        assert_eq!(Ok(Greek::Alpha), Greek::from_str("alpha"));
+        assert_eq!(Some(Greek::Alpha), Greek::from_str_const("alpha"));
+        assert_eq!(Some(Greek::Beta), Greek::from_str_const("BETA"));
+        let alpha = CStr::from_bytes_with_nul(b"alpha\0").unwrap();
+        assert_eq!(Ok(Greek::Alpha), Greek::try_from(alpha));
+        assert_eq!(None, Greek::from_str_const("gamma"));
+    }
+
+    #[test]
+    fn abbreviations() {
+        assert_eq!(Ok(Subcommand::Status), Subcommand::from_str("status"));
+        assert_eq!(Ok(Subcommand::Status), Subcommand::from_str("stat"));
+        assert_eq!(Ok(Subcommand::Stash), Subcommand::from_str("stas"));
+        assert_eq!(Ok(Subcommand::Commit), Subcommand::from_str("CO"));
+        assert_eq!(Ok(Subcommand::Log), Subcommand::from_str("hist"));
+        assert_eq!(Ok(Subcommand::Log), Subcommand::from_str("l"));
+        assert_eq!(Err(strum::ParseError::Ambiguous), Subcommand::from_str("sta"));
+        assert_eq!(Err(not_found("Subcommand")), Subcommand::from_str("statuses"));
+        assert_eq!(Err(strum::ParseError::EmptyInput), Subcommand::from_str(""));
+    }
+
+    #[test]
+    fn serialize_pattern() {
+        assert_eq!(Ok(Operand::Plus), Operand::from_str("+"));
+        assert_eq!(Ok(Operand::Register(0)), Operand::from_str("reg0"));
+        assert_eq!(Ok(Operand::Register(31)), Operand::from_str("reg31"));
+        assert_eq!(Ok(Operand::Op { name: "add".into() }), Operand::from_str("op_add"));
+        assert_eq!(Ok(Operand::Placeholder), Operand::from_str("<name>"));
+        assert!(Operand::from_str("reg").is_err());
+        assert!(Operand::from_str("regx").is_err());
+        assert!(Operand::from_str("reg256").is_err());
+        assert!(Operand::from_str("op_").is_err());
+        assert!(Operand::from_str("<>").is_err());
+    }
+
+    #[test]
+    fn parse_discriminant() {
+        assert_eq!(Ok(StatusCode::Ok), StatusCode::from_str("ok"));
+        assert_eq!(Ok(StatusCode::Ok), StatusCode::from_str("200"));
+        assert_eq!(Ok(StatusCode::Created), StatusCode::from_str("201"));
+        assert_eq!(Ok(StatusCode::NotFound), StatusCode::from_str("404"));
+        assert!(StatusCode::from_str("418").is_err());
+        assert!(StatusCode::from_str("teapot").is_err());
+        assert!(StatusCode::from_str("500").is_err());
+        assert!(StatusCode::from_str("-1").is_err());
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(Ok(Size::Unspecified), Size::from_str(""));
+        assert_eq!(Ok(Size::Small), Size::from_str("size/Small"));
+        assert_eq!("", Size::Unspecified.to_string());
+        assert_eq!(Err(strum::ParseError::EmptyInput), Brightness::from_str(""));
+        assert_eq!(Err(not_found("Brightness")), Brightness::from_str(" "));
+    }
+
+    #[test]
+    fn qualified_variants() {
+        assert_eq!(Ok(Outline::Circle), Outline::from_str("Circle"));
+        assert_eq!(Ok(Outline::Circle), Outline::from_str("Outline::Circle"));
+        assert_eq!(Ok(Outline::Square), Outline::from_str("geometry::Outline::sq"));
+        assert!(Outline::from_str("Color::Circle").is_err());
+        assert!(Outline::from_str("MyOutline::Circle").is_err());
+        assert!(Outline::from_str("Outline::").is_err());
+        assert!(Brightness::from_str("Brightness::Dim").is_err());
+    }
+
+    #[test]
+    fn rich_error() {
+        assert_eq!(Ok(Compression::Gzip), Compression::from_str("gz"));
+        assert_eq!(Ok(Compression::Level(9)), Compression::from_str("level9"));
+        assert_eq!(Err(CompressionParseError), Compression::from_str("lz4"));
+        assert_eq!(&["None", "gzip", "gz", "zstd", "level*"], CompressionParseError::EXPECTED);
+        assert_eq!("expected one of: None, gzip, gz, zstd, level*",
+                   CompressionParseError.to_string());
+
+        let err: Box<dyn std::error::Error> = Box::new(CompressionParseError);
+        assert!(err.source().is_none());
+        assert_eq!(not_found("Compression"), CompressionParseError.into());
+    }
+
+    #[test]
+    fn shared_table() {
+        assert_eq!(&["http", "HTTPS", "ftp"], Protocol::VARIANTS);
+        assert_eq!("HTTPS", Protocol::Https.to_string());
+        assert_eq!("web-socket", Protocol::WebSocket.to_string());
+        assert_eq!("ftp", <&'static str>::from(Protocol::Ftp(21)));
+        assert_eq!("http", Protocol::Http.as_str());
+        assert!(Protocol::Ftp(21) == "ftp");
+        assert_eq!(10, Protocol::MAX_SERIALIZED_LEN);
+        assert_eq!(Ok(Protocol::Https), Protocol::from_str("HTTPS"));
+        assert_eq!(Ok(Protocol::Https), Protocol::from_str("tls"));
+        assert_eq!(Ok(Protocol::Http), Protocol::from_str("HTTP"));
+        assert_eq!(Ok(Protocol::WebSocket), std::convert::TryFrom::try_from(&b"ws"[..]));
+        assert_eq!(Ok(Protocol::Ftp(0)), Protocol::from_str("ftp"));
+        assert_eq!(Err(not_found("Protocol")), Protocol::from_str("FTP"));
+        assert_eq!(&["tls", "HTTPS"], Protocol::Https.get_serializations());
+        assert_eq!(&["ws", "web-socket"], Protocol::WebSocket.get_serializations());
+
+        // Every string is in the table once, and the derives all read it from there.
+        assert_eq!(&["http", "tls", "HTTPS", "ws", "web-socket", "ftp"],
+                   <Protocol as strum::__SharedTable>::STRINGS);
+    }
+
+    #[test]
+    fn bucketed_matching() {
+        assert_eq!(Ok(ChemicalElement::Hydrogen), ChemicalElement::from_str("hydrogen"));
+        assert_eq!(Ok(ChemicalElement::Neon), ChemicalElement::from_str("neon"));
+        assert_eq!(Ok(ChemicalElement::Sulfur), ChemicalElement::from_str("s"));
+        assert_eq!(Ok(ChemicalElement::Sulfur), ChemicalElement::from_str("sulphur"));
+        assert_eq!(Ok(ChemicalElement::Chlorine), ChemicalElement::from_str("CHLORINE"));
+        assert_eq!(Ok(ChemicalElement::Other("Argon".into())), ChemicalElement::from_str("Argon"));
+        assert_eq!(Ok(ChemicalElement::Other("neonn".into())), ChemicalElement::from_str("neonn"));
+    }
+
+    #[test]
+    fn table_matching() {
+        assert_eq!(Ok(Opcode::Op000), Opcode::from_str("Op000"));
+        assert_eq!(Ok(Opcode::Op157), Opcode::from_str("Op157"));
+        assert_eq!(Ok(Opcode::Op296), Opcode::from_str("Op296"));
+        assert_eq!(Ok(Opcode::Halt), Opcode::from_str("hALT"));
+        assert_eq!(Ok(Opcode::Load(0)), Opcode::from_str("ld"));
+        assert_eq!(Ok(Opcode::Load(0)), Opcode::from_str("load"));
+        assert_eq!(Ok(Opcode::Unknown("op000".into())), Opcode::from_str("op000"));
+        assert_eq!(Ok(Opcode::Unknown("Op297".into())), Opcode::from_str("Op297"));
+
+        assert_eq!(300, Opcode::iter().count());
+        assert_eq!(Some(Opcode::Op064), Opcode::iter().nth(64));
+        assert_eq!(Some(Opcode::Halt), Opcode::iter().nth(297));
+        assert_eq!(Some(Opcode::Unknown(String::new())), Opcode::iter().last());
+    }
+
+    #[test]
+    fn cfg_variants() {
+        assert_eq!(vec![Platform::Linux, Platform::MacOs, Platform::Windows],
+                   Platform::iter().collect::<Vec<_>>());
+        assert_eq!(3, Platform::COUNT);
+        assert_eq!(&["Linux", "macOS", "Windows"], Platform::VARIANTS);
+        assert_eq!(Ok(Platform::MacOs), Platform::from_str("macos"));
+        assert_eq!(Err(not_found("Platform")), Platform::from_str("Fuchsia"));
+        assert_eq!("macOS", Platform::MacOs.to_string());
+    }
+
+    #[test]
+    fn non_exhaustive() {
+        assert_eq!(vec![Carrier::Post, Carrier::Courier(0), Carrier::Drone],
+                   Carrier::iter().collect::<Vec<_>>());
+        assert_eq!(Ok(Carrier::Drone), Carrier::from_str("Drone"));
+
+        let kinds = Carrier::iter().map(CarrierDiscriminants::from).collect::<Vec<_>>();
+        assert_eq!(vec![CarrierDiscriminants::Post,
+                        CarrierDiscriminants::Courier,
+                        CarrierDiscriminants::Drone],
+                   kinds);
+    }
+
+    #[test]
+    fn raw_identifiers() {
+        assert_eq!(Ok(ReservedWord::r#type), ReservedWord::from_str("type"));
+        assert_eq!(Ok(ReservedWord::r#fn), ReservedWord::from_str("fn"));
+        assert_eq!(Err(not_found("ReservedWord")), ReservedWord::from_str("r#type"));
+        assert_eq!("match", ReservedWord::r#match.to_string());
+        assert_eq!(&["type", "match", "fn", "__strum_raw_other"], ReservedWord::VARIANTS);
+        assert!(ReservedWord::r#type.is_type());
+        // Strings that look like a hidden raw identifier are left alone.
+        assert_eq!("__strum_raw_other", ReservedWord::Other.to_string());
+        assert_eq!(Ok(ReservedWord::Other), ReservedWord::from_str("__strum_raw_other"));
+    }
+
+    #[test]
+    fn generic_string_derives() {
+        assert_eq!(Ok(Wrapper::Filled(0)), Wrapper::<u8>::from_str("Filled"));
+        assert_eq!(Ok(Wrapper::Parsed(7)), Wrapper::<u8>::from_str("7"));
+        assert_eq!(Err(strum::ConversionError::Conversion("seven".parse::<u8>().unwrap_err())),
+                   Wrapper::<u8>::from_str("seven"));
+        assert_eq!("Filled", Wrapper::Filled(3).to_string());
+        assert_eq!("pair \"a\" and 2", Wrapper::Pair("a", 2).to_string());
+    }
+
+    #[test]
+    fn custom_bounds() {
+        assert_eq!(Ok(Setting::Value(5)), Setting::<u8>::from_str("=5"));
+        assert_eq!(Ok(Setting::Unset), Setting::<u8>::from_str("Unset"));
+        assert_eq!(Err(not_found("Setting")), Setting::<u8>::from_str("=five"));
+
+        #[derive(Debug,Eq,PartialEq)]
+        struct NotDefault;
+        assert_eq!(vec![Batch::Empty, Batch::Items(Vec::new())],
+                   Batch::<NotDefault>::iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn borrowed_default() {
+        use strum::TryFromStr;
+
+        let source = String::from("fn  main (");
+        let lexemes = source.split(' ')
+            .filter(|word| !word.is_empty())
+            .map(Lexeme::try_from_str)
+            .collect::<Result<Vec<_>, _>>();
+        assert_eq!(Ok(vec![Lexeme::Fn, Lexeme::Word("main"), Lexeme::OpenParen]), lexemes);
+
+        // The word points into the input instead of a copy of it.
+        match Lexeme::try_from_str(&source[3..9]) {
+            Ok(Lexeme::Word(word)) => assert_eq!(source[4..8].as_ptr(), word.as_ptr()),
+            other => panic!("{:?}", other),
+        }
+
+        // Types with `FromStr` get it for free.
+        assert_eq!(Ok(Color::Blue { hue: 0 }), Color::try_from_str("Blue"));
+    }
+
+    #[test]
+    fn trim_whitespace() {
+        use std::convert::TryFrom;
+
+        assert_eq!(Ok(Switch::Enabled), Switch::from_str("  Enabled\n"));
+        assert_eq!(Ok(Switch::Disabled), Switch::from_str("\tdisabled"));
+        assert_eq!(Ok(Switch::Disabled), Switch::try_from(&b" disabled "[..]));
+        assert_eq!(Ok(Switch::Enabled), Switch::try_from("\u{3000}Enabled".as_bytes()));
+        assert_eq!("En abled", Switch::from_str(" En abled ").unwrap_err().input);
+    }
+
+    #[test]
+    fn capture_input_error() {
+        let err = Fruit::from_str("Cherry").unwrap_err();
+        assert_eq!(strum::ParseErrorWithInput {
+                       input: String::from("Cherry"),
+                       suggestion: None,
+                       type_name: "Fruit",
+                   },
+                   err);
+        assert_eq!("Matching variant not found for \"Cherry\"", err.to_string());
+        assert_eq!(not_found("Fruit"), strum::ParseError::from(err));
+        assert_eq!(Ok(Fruit::Banana), Fruit::from_str("Banana"));
+    }
+
+    #[test]
+    fn suggest_closest_variant() {
+        let err = Shade::from_str("gren").unwrap_err();
+        assert_eq!(Some("Green"), err.suggestion);
+        assert_eq!("Matching variant not found for \"gren\", did you mean \"Green\"?",
+                   err.to_string());
+        assert_eq!(Some("gray"), Shade::from_str("grayy").unwrap_err().suggestion);
+        assert_eq!(None, Shade::from_str("orange").unwrap_err().suggestion);
+    }
+
+    #[test]
+    fn custom_parse_error() {
+        assert_eq!(Ok(Backend::Postgres), Backend::from_str("Postgres"));
+        assert_eq!(Err(ConfigError::UnknownBackend(String::from("Mysql"))),
+                   Backend::from_str("Mysql"));
+    }
+
+    #[test]
+    fn reexported_derives() {
+        assert_eq!(Ok(Reexported::First), Reexported::from_str("first"));
+        assert_eq!("Second", Reexported::Second.to_string());
+    }
+
+    #[test]
+    fn iter_both_ends() {
+        let mut iter = Color::iter();
+        assert_eq!(4, iter.len());
+        assert_eq!(Some(Color::Green(String::new())), iter.next_back());
+        assert_eq!(Some(Color::Red), iter.next());
+        assert_eq!(2, iter.len());
+
+        let rest = iter.clone().collect::<Vec<_>>();
+        assert_eq!(vec![Color::Blue { hue: 0 }, Color::Yellow], rest);
+        assert_eq!(Some(Color::Yellow), iter.next_back());
+        assert_eq!(Some(Color::Blue { hue: 0 }), iter.next_back());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next_back());
+        assert_eq!("ColorIter { len: 0 }", format!("{:?}", iter));
+    }
+
+    #[test]
+    fn iter_random_access() {
+        let mut iter = Color::iter();
+        assert_eq!(Some(Color::Blue { hue: 0 }), iter.nth(1));
+        assert_eq!(2, iter.clone().count());
+        assert_eq!(Some(Color::Green(String::new())), iter.clone().last());
+        assert_eq!(Some(Color::Yellow), iter.nth_back(1));
+        assert_eq!(None, iter.next());
+        assert_eq!(None, Color::iter().nth(4));
+        assert_eq!(vec![Color::Yellow], Color::iter().skip(2).take(1).collect::<Vec<_>>());
+
+        let mut iter = Color::iter();
+        assert_eq!(None, iter.nth(10));
+        assert_eq!(None, iter.next_back());
+    }
+
+    fn collect_all<E: IntoEnumIterator>() -> Vec<E> {
+        E::iter().collect()
+    }
+
+    #[test]
+    fn iter_first_and_last() {
+        const FIRST: Color = Color::first();
+        assert_eq!(Color::Red, FIRST);
+        assert_eq!(Color::Green(String::new()), Color::last());
+        assert_eq!(Container::Empty::<u8, u8>, Container::first());
+        assert_eq!(Container::Pair::<u8, u8>(0, None), Container::last());
+        assert_eq!(Status::Retired, Status::last());
+    }
+
+    #[test]
+    fn iter_range() {
+        assert_eq!(vec![Color::Blue { hue: 0 }, Color::Yellow],
+                   Color::range(Color::Blue { hue: 5 }..=Color::Yellow).collect::<Vec<_>>());
+        assert_eq!(vec![Color::Yellow, Color::Green(String::new())],
+                   Color::range(Color::Yellow..).collect::<Vec<_>>());
+        assert_eq!(vec![Color::Red], Color::range(..Color::Blue { hue: 0 }).collect::<Vec<_>>());
+        assert_eq!(3, Color::range(Color::Blue { hue: 0 }..).rev().count());
+        assert_eq!(0, Color::range(Color::Yellow..Color::Red).len());
+
+        // Disabled variants aren't yielded, even at the ends of the range.
+        assert_eq!(vec![Status::Retired], Status::range(Status::Legacy..).collect::<Vec<_>>());
+        assert_eq!(vec![Status::Active], Status::range(..=Status::Legacy).collect::<Vec<_>>());
+        assert_eq!(0, Status::range(Status::Legacy..=Status::Legacy).count());
+    }
+
+    #[test]
+    fn iter_inherent_and_trait() {
+        assert_eq!(Color::iter().collect::<Vec<_>>(), collect_all::<Color>());
+    }
+
+    #[derive(Debug,Eq,PartialEq,EnumIter)]
+    #[strum(iter_name="SeasonVariants", iter_vis="pub(crate)")]
+    pub(crate) enum Season {
+        Spring,
+        Summer,
+        Autumn,
+        Winter,
+    }
+
+    #[test]
+    fn custom_iter_name() {
+        let iter: SeasonVariants = Season::iter();
+        assert_eq!(Some(Season::Winter), iter.last());
+    }
+
+    #[test]
+    fn iter_generic_enum() {
+        let all = Container::<String, u8>::iter().collect::<Vec<_>>();
+        assert_eq!(vec![Container::Empty,
+                        Container::Full(String::new()),
+                        Container::Pair(String::new(), None)],
+                   all);
+    }
+
+    #[test]
+    fn iter_refs_unit_enum() {
+        let all: Vec<&'static Season> = Season::iter_refs().collect();
+        assert_eq!(vec![&Season::Spring, &Season::Summer, &Season::Autumn, &Season::Winter], all);
+        assert_eq!(all, collect_all::<&'static Season>());
+    }
+
+    #[test]
+    fn disabled_per_derive() {
+        assert_eq!(vec![Status::Active, Status::Retired], Status::iter().collect::<Vec<_>>());
+        assert_eq!(Ok(Status::Legacy), Status::from_str("Legacy"));
+        assert_eq!("Retired", Status::Retired.to_string());
+    }
+
+    #[test]
+    fn documentation() {
+        assert_eq!(Some("Opens a file."), Command::Open.get_documentation());
+        assert_eq!(Some("Saves the current file.\n\nOverwrites any existing file."),
+                   Command::Save.get_documentation());
+        assert_eq!(Some("Save"), Command::Save.get_message());
+        assert_eq!(None, Command::Quit.get_documentation());
+    }
+
+    #[test]
+    fn message_templates() {
+        let mismatch = Mismatch::Type { expected: "int", found: String::from("str") };
+        assert_eq!(Some("expected {expected} but got {found:?}"), mismatch.get_message());
+        assert_eq!(Some(String::from("expected int but got \"str\"")), mismatch.get_message_owned());
+        assert_eq!(Some(String::from("line 3, column {4}")), Mismatch::At(3, 4).get_message_owned());
+        assert_eq!(Some(String::from("7 is out of range")),
+                   Mismatch::Range(7, String::new()).get_message_owned());
+        assert_eq!(Some(String::from("unknown")), Mismatch::Unknown.get_message_owned());
+    }
+
+    #[test]
+    fn localized_messages() {
+        assert_eq!(Some("Öffnen"), MenuItem::Open.get_message_lang("de"));
+        assert_eq!(Some("Ouvrir"), MenuItem::Open.get_message_lang("fr"));
+        assert_eq!(Some("Open"), MenuItem::Open.get_message_lang("es"));
+        assert_eq!(Some("Close"), MenuItem::Close.get_message_lang("de"));
+        assert_eq!(None, MenuItem::Help.get_message_lang("de"));
+    }
+
+    #[test]
+    fn message_keys() {
+        let translations = |key: &str| if key == "menu-help" { Some(String::from("Hilfe")) } else { None };
+        assert_eq!(None, MenuItem::Open.get_message_key());
+        assert_eq!(Some("menu-close"), MenuItem::Close.get_message_key());
+        assert_eq!(Some(String::from("Hilfe")), MenuItem::Help.resolve_message(&translations));
+        assert_eq!(Some(String::from("Close")), MenuItem::Close.resolve_message(&translations));
+    }
+
+    #[test]
+    fn properties() {
+        assert_eq!(Some("Ms.Frizzle"), Class::History.get_str("Teacher"));
+        assert_eq!(Some(201), Class::History.get_int("Room"));
+        assert_eq!(Some(false), Class::History.get_bool("Online"));
+        assert_eq!(None, Class::History.get_str("Room"));
+        assert_eq!(Some("Mr.Smith"), Class::Math { grade: 3 }.get_str("Teacher"));
+        assert_eq!(Some(true), Class::Math { grade: 3 }.get_bool("Online"));
+        assert_eq!(None, Class::Recess.get_str("Teacher"));
+        assert_eq!(None, Class::Detention.get_str("Teacher"));
+    }
+
+    #[test]
+    fn sorted_property_tables() {
+        // The props are declared out of order, and every key has to be found by the binary search,
+        // including the ones that sort first and last.
+        let mercury = Celestial::Mercury;
+        for &(key, value) in &[("Aphelion", 70), ("Day", 1408), ("Moons", 0), ("Order", 1)] {
+            assert_eq!(Some(value), mercury.get_int(key), "{}", key);
+        }
+        for &(key, value) in &[("Atmosphere", "thin"), ("Discovered", "antiquity"),
+                               ("Name", "Mercury"), ("Symbol", "\u{263f}"), ("Type", "rocky")] {
+            assert_eq!(Some(value), mercury.get_str(key), "{}", key);
+        }
+        assert_eq!(Some(false), mercury.get_bool("Gas"));
+        assert_eq!(Some(false), mercury.get_bool("Rings"));
+
+        assert_eq!(None, mercury.get_str("Aardvark"));
+        assert_eq!(None, mercury.get_str("Zenith"));
+        assert_eq!(None, mercury.get_str("Nam"));
+        assert_eq!(None, mercury.get_str("name"));
+        assert_eq!(None, mercury.get_str("Moons"));
+        assert_eq!(None, mercury.get_int("Name"));
+        assert_eq!(None, Celestial::Saturn.get_str("Atmosphere"));
+        assert_eq!(Some(146), Celestial::Saturn.get_int("Moons"));
+        assert_eq!(Some(true), Celestial::Saturn.get_bool("Rings"));
+    }
+
+    #[test]
+    fn all_properties() {
+        assert_eq!(&[("Teacher", "Ms.Frizzle"), ("Room", "201"), ("Online", "false")],
+                   Class::History.get_all_props());
+        assert_eq!(&[("Teacher", "Mr.Smith"), ("Online", "true")],
+                   Class::Math { grade: 1 }.get_all_props());
+        assert!(Class::Recess.get_all_props().is_empty());
+    }
+
+    #[test]
+    fn property_accessors() {
+        assert_eq!(Some("Ms.Frizzle"), Class::History.teacher());
+        assert_eq!(Some(201), Class::History.room());
+        assert_eq!(None, Class::Math { grade: 2 }.room());
+        assert_eq!(Some(true), Class::Math { grade: 2 }.online());
+        assert_eq!(None, Class::Recess.teacher());
+    }
+
+    #[test]
+    fn required_properties() {
+        assert_eq!("He", Element::Helium.symbol());
+        assert_eq!(1, Element::Hydrogen.protons());
+        assert_eq!(Some("Hydrogen"), Element::Hydrogen.get_str("Name"));
+    }
+
+    #[test]
+    fn display_templates() {
+        assert_eq!("3 items", Cart::Items(3).to_string());
+        assert_eq!("range 1 to   9", Cart::Range { range: 1, end: 9 }.to_string());
+    }
+
+    #[test]
+    fn transparent_display() {
+        assert_eq!("NotFound", AppError::NotFound.to_string());
+        assert_eq!("disk full", AppError::Other(String::from("disk full")).to_string());
+        assert_eq!("  -2", format!("{:>4}", AppError::Io { code: -2 }));
+    }
+
+    #[test]
+    fn transparent_round_trip() {
+        assert_eq!("warning", Verbosity::Level(LogLevel::Warn).to_string());
+        assert_eq!(Ok(Verbosity::Level(LogLevel::Warn)), Verbosity::from_str("warning"));
+        assert_eq!(Ok(Verbosity::Quiet), Verbosity::from_str("Quiet"));
+        assert_eq!("loud", Verbosity::Custom(String::from("loud")).to_string());
+        assert_eq!(Err(not_found("Verbosity")), Verbosity::from_str("Custom"));
+    }
+
+    #[test]
+    fn flatten_nested_enum() {
+        assert_eq!(Ok(Token::Quit), Token::from_str("quit"));
+        assert_eq!(Ok(Token::Color(Color::Yellow)), Token::from_str("yellow"));
+        assert_eq!(Ok(Token::Level { level: LogLevel::Warn }), Token::from_str("warning"));
+        assert_eq!(Ok(Token::Color(Color::Green(String::from("Color")))), Token::from_str("Color"));
+    }
+
+    #[test]
+    fn default_conversions() {
+        assert_eq!(Ok(Label::Other("misc".into())), Label::from_str("misc"));
+        assert_eq!(Ok(Handle::Root), Handle::from_str("Root"));
+        assert_eq!(Ok(Handle::Named("home".into())), Handle::from_str("home"));
+        assert_eq!(Ok(Port::Http), Port::from_str("http"));
+        assert_eq!(Ok(Port::Number(8080)), Port::from_str("8080"));
+        assert_eq!("ftp", Port::from_str("ftp").unwrap_err().input);
+        assert_eq!(Ok(Mention::Tag(Tag(String::from("rust")))), Mention::from_str("#rust"));
+        assert_eq!(Err(strum::ConversionError::Conversion(())), Mention::from_str("rust"));
+        assert_eq!(Err(strum::ConversionError::Conversion("".parse::<u8>().unwrap_err())),
+                   Wrapper::<u8>::from_str(""));
+        assert_eq!("invalid digit found in string",
+                   Wrapper::<u8>::from_str("seven").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn default_struct_variant() {
+        assert_eq!(Ok(Keyword::Fn), Keyword::from_str("Fn"));
+        assert_eq!(Ok(Keyword::Unknown { name: String::from("let") }), Keyword::from_str("let"));
+    }
+
+    #[test]
+    fn default_with_function() {
+        assert_eq!(Ok(Ident::SelfValue), Ident::from_str("SelfValue"));
+        assert_eq!(Ok(Ident::Other(String::from("foo"))), Ident::from_str("FOO"));
+        assert_eq!(Err(not_found("Ident")), Ident::from_str(""));
+    }
+
+    #[test]
+    fn parse_delimited_fields() {
+        assert_eq!(Ok(Paint::Red), Paint::from_str("red"));
+        assert_eq!(Ok(Paint::Blue(0)), Paint::from_str("Blue"));
+        assert_eq!(Ok(Paint::Blue(42)), Paint::from_str("blue:42"));
+        assert_eq!(Ok(Paint::Rgb(1, 2, 3)), Paint::from_str("RGB:1:2:3"));
+        assert_eq!(Ok(Paint::Named { name: String::from("teal") }), Paint::from_str("Named:teal"));
+        assert_eq!(Err(not_found("Paint")), Paint::from_str("Blue:x"));
+        assert_eq!(Err(not_found("Paint")), Paint::from_str("rgb:1:2"));
+        assert_eq!(Err(not_found("Paint")), Paint::from_str("Blue:1:2"));
+        assert_eq!(Err(not_found("Paint")), Paint::from_str("Red:1"));
+    }
+
+    #[test]
+    fn parse_with_function() {
+        assert_eq!(Ok(Swatch::Black), Swatch::from_str("black"));
+        assert_eq!(Ok(Swatch::Hex(0xff0000)), Swatch::from_str("#ff0000"));
+        assert_eq!(Err(not_found("Swatch")), Swatch::from_str("#zz"));
+        assert_eq!(Ok(Swatch::Gray(128)), Swatch::from_str("GRAY"));
+        assert_eq!(Ok(Swatch::Gray(40)), Swatch::from_str("Gray40"));
+        assert_eq!(Err(not_found("Swatch")), Swatch::from_str("white"));
+    }
+
+    #[test]
+    fn enum_set() {
+        use strum::EnumSet;
+
+        let mut perms = EnumSet::new();
+        assert!(perms.is_empty());
+        assert!(perms.insert(Permission::Execute));
+        assert!(!perms.insert(Permission::Execute));
+        perms.insert(Permission::Read);
+        assert_eq!(2, perms.len());
+        assert_eq!(vec![Permission::Read, Permission::Execute], perms.iter().collect::<Vec<_>>());
+
+        let write = EnumSet::from(Permission::Write);
+        assert_eq!(EnumSet::all(), perms | write);
+        assert_eq!(write, !perms);
+        assert!((perms & write).is_empty());
+        assert_eq!(EnumSet::from(Permission::Read), perms - EnumSet::from(Permission::Execute));
+        assert!(perms.is_subset(&EnumSet::all()));
+        assert!(perms.remove(Permission::Read));
+        assert!(!perms.contains(Permission::Read));
+        assert_eq!("{Execute}", format!("{:?}", perms));
+        assert_eq!(8, std::mem::size_of::<EnumSet<Permission>>());
+    }
+
+    #[test]
+    fn wide_enum_set() {
+        use strum::EnumSet;
+
+        let set: EnumSet<Wide> = [Wide::W0, Wide::W64, Wide::W129].iter().cloned().collect();
+        assert_eq!(3, set.len());
+        assert!(set.contains(Wide::W64));
+        assert!(!set.contains(Wide::W63));
+        assert_eq!(vec![Wide::W0, Wide::W64, Wide::W129], set.iter().collect::<Vec<_>>());
+        assert_eq!(127, (!set).len());
+        assert_eq!(130, EnumSet::<Wide>::all().len());
+        assert_eq!(24, std::mem::size_of::<EnumSet<Wide>>());
+    }
+
+    #[test]
+    fn enum_flags() {
+        let mut caps = CapabilityFlags::READ | CapabilityFlags::EXECUTE_CODE;
+        assert_eq!(0b101, caps.bits());
+        assert!(caps.contains(CapabilityFlags::READ));
+        assert!(!caps.contains(CapabilityFlags::all()));
+        assert!(caps.intersects(CapabilityFlags::EXECUTE_CODE | CapabilityFlags::WRITE));
+        assert_eq!(CapabilityFlags::WRITE, !caps);
+        caps.remove(CapabilityFlags::READ);
+        caps |= CapabilityFlags::from(Capability::Write);
+        assert_eq!(vec![Capability::Write, Capability::ExecuteCode], caps.iter().collect::<Vec<_>>());
+        assert_eq!("CapabilityFlags(WRITE | EXECUTE_CODE)", format!("{:?}", caps));
+
+        assert_eq!(Some(CapabilityFlags::all()), CapabilityFlags::from_bits(0b111));
+        assert_eq!(None, CapabilityFlags::from_bits(0b1000));
+        assert_eq!(CapabilityFlags::READ, CapabilityFlags::from_bits_truncate(0b1001));
+        assert_eq!(3u8, u8::from(CapabilityFlags::READ | CapabilityFlags::WRITE));
+        let collected: CapabilityFlags = vec![Capability::Read, Capability::Read].into_iter().collect();
+        assert_eq!(CapabilityFlags::READ, collected);
+
+        assert_eq!(2u32, Modes::SAFE.bits());
+        assert!(Modes::empty().is_empty());
+        assert!((Modes::FAST | Modes::SAFE).is_all());
+    }
+
+    #[test]
+    fn enum_index() {
+        let mut seen = [0; Color::COUNT];
+        seen[Color::Yellow] += 1;
+        seen[Color::Green("teal".into())] += 2;
+        seen[Color::Blue { hue: 3 }] += 3;
+        assert_eq!([0, 3, 1, 2], seen);
+        assert_eq!(1, Color::Blue { hue: 0 }.to_index());
+        assert_eq!(3, strum::EnumIndex::to_index(&Color::Green(String::new())));
+
+        let names = ["empty", "full", "pair"];
+        assert_eq!("pair", names[Container::Pair::<u8, u8>(1, None)]);
+    }
+
+    #[test]
+    fn enum_rotate() {
+        assert_eq!(Theme::Dark, Theme::Light.next());
+        assert_eq!(Theme::Custom { accent: 0 }, Theme::Dark.next());
+        assert_eq!(Theme::Light, Theme::Custom { accent: 7 }.next());
+        assert_eq!(Theme::Custom { accent: 0 }, Theme::Light.previous());
+        assert_eq!(Theme::Dark, Theme::Custom { accent: 7 }.previous());
+        assert_eq!(Theme::Custom { accent: 0 }, Theme::System.next());
+        assert_eq!(Theme::Dark, Theme::System.previous());
+        assert_eq!(Container::Pair(0, None), Container::Full::<u8, u8>(3).next());
+    }
+
+    #[test]
+    fn foreign_enums() {
+        use std::convert::TryFrom;
+
+        assert_eq!(foreign::Method::Post, Method::from_str("POST").unwrap().into());
+        let extension = Method::from(foreign::Method::Extension("PURGE".to_owned()));
+        assert_eq!(Method::Extension("PURGE".to_owned()), extension);
+        assert_eq!("GET", Method::from(foreign::Method::Get).to_string());
+        assert_eq!(3, Method::COUNT);
+
+        let kill = SignalName::try_from(foreign::Signal::Kill { code: 9 }).unwrap();
+        assert_eq!("SIGKILL", kill.to_string());
+        assert_eq!(foreign::Signal::Kill { code: 9 }, kill.into());
+        assert_eq!(Err(foreign::Signal::Stop), SignalName::try_from(foreign::Signal::Stop));
+    }
+
+    #[test]
+    fn stable_ids() {
+        assert_eq!(0x3fd6bd99e3f6a5be, JobEvent::Started.stable_id());
+        assert_eq!(0xa3deee7949f9fece, JobEvent::Stopped.stable_id());
+        assert_eq!(0x5a34b413c316fdab, JobEvent::Suspended { since: 9 }.stable_id());
+        assert_eq!(Some(JobEvent::Suspended { since: 0 }),
+                   JobEvent::from_stable_id(0x5a34b413c316fdab));
+        assert_eq!(Some(JobEvent::Stopped), JobEvent::from_stable_id(0xa3deee7949f9fece));
+        assert_eq!(None, JobEvent::from_stable_id(0));
+    }
+
+    #[test]
+    fn cstr_conversions() {
+        use std::convert::TryFrom;
+        use std::ffi::CStr;
+
+        const STDERR: &CStr = LogTarget::Stderr.as_cstr();
+        assert_eq!(b"stderr\0", STDERR.to_bytes_with_nul());
+        assert_eq!("syslog", LogTarget::SystemLog.as_cstr().to_str().unwrap());
+        assert_eq!("file", LogTarget::File { fd: 3 }.as_cstr().to_str().unwrap());
+
+        let log = CStr::from_bytes_with_nul(b"LOG\0").unwrap();
+        assert_eq!(Ok(LogTarget::SystemLog), LogTarget::try_from(log));
+        assert_eq!(Ok(LogTarget::File { fd: 0 }),
+                   LogTarget::try_from(LogTarget::File { fd: 1 }.as_cstr()));
+        let unknown = CStr::from_bytes_with_nul(b"tty\0").unwrap();
+        assert_eq!(Err(not_found("LogTarget")), LogTarget::try_from(unknown));
+    }
 }
@@ -0,0 +1,62 @@
+#![cfg(feature = "arbitrary")]
+
+extern crate arbitrary;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use arbitrary::{Arbitrary, Unstructured};
+
+#[derive(Debug,PartialEq,EnumArbitrary)]
+enum Command {
+    Ping,
+    Move { x: i8, y: i8 },
+    Say(String),
+    #[strum(disabled="true")]
+    #[allow(dead_code)]
+    Shutdown,
+}
+
+#[derive(Debug,PartialEq,EnumArbitrary)]
+enum Pair<T> {
+    Same(T),
+    Different(T, T),
+}
+
+fn build<'a, T: Arbitrary<'a>>(data: &'a [u8]) -> T {
+    T::arbitrary(&mut Unstructured::new(data)).unwrap()
+}
+
+#[test]
+fn covers_enabled_variants() {
+    let mut seen = [false; 3];
+    for first in 0..=255u8 {
+        let data = [first, 1, 2, 3, 4];
+        match build(&data) {
+            Command::Ping => seen[0] = true,
+            Command::Move { .. } => seen[1] = true,
+            Command::Say(_) => seen[2] = true,
+            Command::Shutdown => panic!("built a disabled variant"),
+        }
+        assert_eq!(build::<Command>(&data), build::<Command>(&data));
+    }
+    assert_eq!([true; 3], seen);
+}
+
+#[test]
+fn fields_come_from_the_buffer() {
+    let data = (0..=255u8).collect::<Vec<_>>();
+    for start in 0..250 {
+        if let Command::Move { x, y } = build(&data[start..]) {
+            assert_eq!((data[start + 1] as i8, data[start + 2] as i8), (x, y));
+            return;
+        }
+    }
+    panic!("never built a Move");
+}
+
+#[test]
+fn runs_out_of_bytes() {
+    assert_eq!(Command::Ping, build(&[]));
+    assert_eq!(Pair::Same(false), build::<Pair<bool>>(&[]));
+}
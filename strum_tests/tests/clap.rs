@@ -0,0 +1,53 @@
+#![cfg(feature = "clap")]
+
+extern crate clap;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use clap::{Arg, Command, ValueEnum};
+use clap::builder::EnumValueParser;
+
+#[derive(Debug,Clone,Copy,PartialEq,EnumValueEnum)]
+#[strum(serialize_all="kebab-case")]
+enum Mode {
+    #[strum(message="Optimize for speed")]
+    Fast,
+    #[strum(serialize="small", serialize="s", message="Optimize for size")]
+    Small,
+    #[strum(disabled="true")]
+    Debug,
+    NoOpt,
+}
+
+fn parse(args: &[&str]) -> Result<Mode, clap::Error> {
+    let command = Command::new("build")
+        .arg(Arg::new("mode").long("mode").value_parser(EnumValueParser::<Mode>::new()));
+    let matches = command.try_get_matches_from(args)?;
+    Ok(*matches.get_one::<Mode>("mode").unwrap())
+}
+
+#[test]
+fn value_names() {
+    assert_eq!(&["fast", "small", "no-opt"], Mode::VALUE_NAMES);
+    assert_eq!(&[Mode::Fast, Mode::Small, Mode::NoOpt], Mode::value_variants());
+}
+
+#[test]
+fn possible_values() {
+    let small = Mode::Small.to_possible_value().unwrap();
+    assert_eq!("small", small.get_name());
+    assert!(small.matches("s", false));
+    assert_eq!(Some("Optimize for size"), small.get_help().map(|help| help.to_string()).as_deref());
+    assert!(Mode::NoOpt.to_possible_value().unwrap().get_help().is_none());
+    assert!(Mode::Debug.to_possible_value().is_none());
+}
+
+#[test]
+fn parse_arguments() {
+    assert_eq!(Mode::Fast, parse(&["build", "--mode", "fast"]).unwrap());
+    assert_eq!(Mode::Small, parse(&["build", "--mode", "s"]).unwrap());
+    assert_eq!(Mode::NoOpt, parse(&["build", "--mode", "no-opt"]).unwrap());
+    assert_eq!(Ok(Mode::Small), Mode::from_str("SMALL", true));
+    assert!(parse(&["build", "--mode", "debug"]).is_err());
+}
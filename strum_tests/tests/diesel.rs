@@ -0,0 +1,45 @@
+#![cfg(feature = "diesel")]
+
+extern crate diesel;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use diesel::backend::Backend;
+use diesel::deserialize::{FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::pg::Pg;
+use diesel::serialize::ToSql;
+use diesel::sql_types::Text;
+
+#[derive(Debug,AsExpression,FromSqlRow,EnumString,EnumDieselText)]
+#[diesel(sql_type = Text)]
+#[strum(serialize_all="snake_case")]
+#[allow(dead_code)]
+enum Status {
+    Active,
+    OnHold,
+    #[strum(disabled="true")]
+    Deleted,
+}
+
+#[derive(Debug,EnumString,EnumDieselText)]
+#[allow(dead_code)]
+enum Label {
+    Pinned,
+    #[strum(default="true")]
+    Other(String),
+}
+
+// Only compiles if the derive implemented both traits for the backend.
+fn assert_text<T, DB>()
+    where DB: Backend,
+          T: ToSql<Text, DB> + FromSql<Text, DB>
+{
+}
+
+#[test]
+fn implements_text_traits() {
+    assert_text::<Status, Pg>();
+    assert_text::<Label, Pg>();
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "postgres-types")]
+
+extern crate bytes;
+extern crate postgres_types;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, Kind, ToSql, Type};
+
+#[derive(Debug,PartialEq,EnumString,EnumPostgres)]
+#[strum(serialize_all="snake_case")]
+enum Status {
+    Active,
+    OnHold,
+    #[strum(disabled="true")]
+    Deleted,
+}
+
+#[derive(Debug,PartialEq,EnumString,EnumPostgres)]
+#[strum(serialize_all="snake_case", postgres_type="mood")]
+enum Mood {
+    Happy,
+    Sad,
+}
+
+fn mood_type() -> Type {
+    let labels = vec![String::from("happy"), String::from("sad")];
+    Type::new(String::from("mood"), 16_000, Kind::Enum(labels), String::from("public"))
+}
+
+#[test]
+fn to_sql() {
+    let mut buf = BytesMut::new();
+    assert!(matches!(Status::OnHold.to_sql(&Type::TEXT, &mut buf), Ok(IsNull::No)));
+    assert_eq!(b"on_hold", &buf[..]);
+    assert!(Status::Deleted.to_sql(&Type::TEXT, &mut buf).is_err());
+    assert!(Status::Active.to_sql_checked(&Type::INT4, &mut buf).is_err());
+}
+
+#[test]
+fn from_sql() {
+    assert_eq!(Status::OnHold, Status::from_sql(&Type::VARCHAR, b"on_hold").unwrap());
+    assert!(Status::from_sql(&Type::TEXT, b"deleted").is_err());
+}
+
+#[test]
+fn text_columns() {
+    assert!(<Status as ToSql>::accepts(&Type::TEXT));
+    assert!(<Status as FromSql>::accepts(&Type::VARCHAR));
+    assert!(!<Status as ToSql>::accepts(&Type::INT4));
+    assert!(!<Status as ToSql>::accepts(&mood_type()));
+}
+
+#[test]
+fn enum_type() {
+    let mood = mood_type();
+    assert!(<Mood as ToSql>::accepts(&mood));
+    assert!(<Mood as FromSql>::accepts(&mood));
+    assert!(!<Mood as ToSql>::accepts(&Type::TEXT));
+
+    let mut buf = BytesMut::new();
+    assert!(matches!(Mood::Sad.to_sql_checked(&mood, &mut buf), Ok(IsNull::No)));
+    assert_eq!(Mood::Sad, Mood::from_sql(&mood, &buf).unwrap());
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "proptest")]
+
+#[macro_use]
+extern crate proptest;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use proptest::arbitrary::any;
+use proptest::strategy::{Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+#[derive(Debug,Clone,EnumProptest)]
+#[allow(dead_code)]
+enum Shape {
+    Dot,
+    Line(u8, u8),
+    Circle { radius: u16 },
+    #[strum(disabled="true")]
+    Invalid,
+}
+
+#[derive(Debug,Clone,EnumProptest)]
+#[allow(dead_code)]
+enum Pair<T> {
+    Same(T),
+    Different(T, T),
+}
+
+#[test]
+fn covers_enabled_variants() {
+    let mut runner = TestRunner::deterministic();
+    let mut seen = [false; 3];
+    for _ in 0..200 {
+        match any::<Shape>().new_tree(&mut runner).unwrap().current() {
+            Shape::Dot => seen[0] = true,
+            Shape::Line(..) => seen[1] = true,
+            Shape::Circle { .. } => seen[2] = true,
+            Shape::Invalid => panic!("generated a disabled variant"),
+        }
+    }
+    assert_eq!([true; 3], seen);
+}
+
+proptest! {
+    #[test]
+    fn generic_fields(pair: Pair<bool>) {
+        match pair {
+            Pair::Same(_) | Pair::Different(..) => {}
+        }
+    }
+
+    #[test]
+    fn never_invalid(shape: Shape) {
+        prop_assert!(!matches!(shape, Shape::Invalid));
+    }
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "quickcheck")]
+
+extern crate quickcheck;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+#[derive(Debug,Clone,EnumQuickCheck)]
+#[allow(dead_code)]
+enum Shape {
+    Dot,
+    Line(u8, u8),
+    Circle { radius: u16 },
+    #[strum(disabled="true")]
+    Invalid,
+}
+
+#[derive(Debug,Clone,EnumQuickCheck)]
+#[allow(dead_code)]
+enum Pair<T> {
+    Same(T),
+    Different(T, T),
+}
+
+#[test]
+fn covers_enabled_variants() {
+    let mut g = Gen::new(10);
+    let mut seen = [false; 3];
+    for _ in 0..200 {
+        match Shape::arbitrary(&mut g) {
+            Shape::Dot => seen[0] = true,
+            Shape::Line(..) => seen[1] = true,
+            Shape::Circle { .. } => seen[2] = true,
+            Shape::Invalid => panic!("generated a disabled variant"),
+        }
+    }
+    assert_eq!([true; 3], seen);
+}
+
+#[test]
+fn property() {
+    fn never_invalid(shape: Shape) -> bool {
+        !matches!(shape, Shape::Invalid)
+    }
+
+    fn generic_fields(pair: Pair<bool>) -> bool {
+        match pair {
+            Pair::Same(_) | Pair::Different(..) => true,
+        }
+    }
+
+    QuickCheck::new().quickcheck(never_invalid as fn(Shape) -> bool);
+    QuickCheck::new().quickcheck(generic_fields as fn(Pair<bool>) -> bool);
+}
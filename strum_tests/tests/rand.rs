@@ -0,0 +1,81 @@
+#![cfg(feature = "rand")]
+
+extern crate rand;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+#[derive(Debug,PartialEq,EnumRandom)]
+enum Color {
+    Red,
+    Green,
+    Rgb(u8, u8, u8),
+    #[strum(disabled="true")]
+    #[allow(dead_code)]
+    Transparent,
+}
+
+#[derive(Debug,PartialEq,EnumRandom)]
+enum Loot {
+    #[strum(props(weight=80))]
+    Common,
+    #[strum(props(weight="15"))]
+    Rare,
+    Legendary,
+    #[strum(props(weight=0))]
+    Cursed,
+}
+
+fn counts<F: FnMut(&mut StdRng) -> Color>(mut sample: F) -> [usize; 3] {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut counts = [0; 3];
+    for _ in 0..3000 {
+        match sample(&mut rng) {
+            Color::Red => counts[0] += 1,
+            Color::Green => counts[1] += 1,
+            Color::Rgb(r, g, b) => {
+                assert_eq!((0, 0, 0), (r, g, b));
+                counts[2] += 1;
+            }
+            Color::Transparent => panic!("picked a disabled variant"),
+        }
+    }
+    counts
+}
+
+#[test]
+fn picks_uniformly() {
+    for &count in &counts(|rng| rng.random()) {
+        assert!(count > 900 && count < 1100, "{} of 3000", count);
+    }
+}
+
+#[test]
+fn same_seed_same_picks() {
+    let picks = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..20).map(|_| rng.random::<Color>()).collect::<Vec<_>>()
+    };
+    assert_eq!(picks(3), picks(3));
+}
+
+#[test]
+fn picks_by_weight() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let mut counts = [0; 3];
+    for _ in 0..9600 {
+        match Loot::sample_weighted(&mut rng) {
+            Loot::Common => counts[0] += 1,
+            Loot::Rare => counts[1] += 1,
+            Loot::Legendary => counts[2] += 1,
+            Loot::Cursed => panic!("picked a variant with a weight of 0"),
+        }
+    }
+    // The weights add up to 96, so each one is worth about 100 picks.
+    assert!(counts[0] > 7600 && counts[0] < 8400, "{:?}", counts);
+    assert!(counts[1] > 1300 && counts[1] < 1700, "{:?}", counts);
+    assert!(counts[2] > 40 && counts[2] < 200, "{:?}", counts);
+}
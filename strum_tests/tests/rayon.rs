@@ -0,0 +1,53 @@
+#![cfg(feature = "rayon")]
+
+extern crate rayon;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use rayon::prelude::*;
+
+#[derive(Debug,Clone,PartialEq,EnumIter,EnumParIter)]
+enum Opcode {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    #[strum(disabled="true")]
+    #[allow(dead_code)]
+    Nop,
+    Jump(u16),
+    Load { addr: u32 },
+}
+
+#[derive(Debug,Clone,PartialEq,EnumIter,EnumParIter)]
+enum Slot<T> {
+    Empty,
+    Full(T),
+}
+
+#[test]
+fn matches_iter() {
+    let sequential = Opcode::iter().collect::<Vec<_>>();
+    assert_eq!(sequential, Opcode::par_iter().collect::<Vec<_>>());
+    assert_eq!(sequential.len(), Opcode::par_iter().len());
+    assert_eq!(Slot::<u8>::iter().collect::<Vec<_>>(), Slot::<u8>::par_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn indexed() {
+    let sequential = Opcode::iter().enumerate().collect::<Vec<_>>();
+    assert_eq!(sequential, Opcode::par_iter().enumerate().collect::<Vec<_>>());
+    let zipped = Opcode::par_iter().zip(Opcode::par_iter().skip(1)).collect::<Vec<_>>();
+    assert_eq!(sequential.len() - 1, zipped.len());
+    assert_eq!((Opcode::Add, Opcode::Sub), zipped[0]);
+}
+
+#[test]
+fn partly_used_iterator() {
+    let mut iter = Opcode::iter();
+    iter.next();
+    iter.next_back();
+    let rest = iter.clone().collect::<Vec<_>>();
+    assert_eq!(rest, iter.into_par_iter().collect::<Vec<_>>());
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "rusqlite")]
+
+extern crate rusqlite;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use rusqlite::Connection;
+
+#[derive(Debug,PartialEq,EnumString,EnumRusqlite)]
+#[strum(serialize_all="snake_case")]
+enum Status {
+    Active,
+    OnHold,
+    #[strum(disabled="true")]
+    Deleted,
+}
+
+fn accounts() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute("CREATE TABLE accounts (status TEXT NOT NULL)", []).unwrap();
+    conn
+}
+
+#[test]
+fn round_trip() {
+    let conn = accounts();
+    conn.execute("INSERT INTO accounts (status) VALUES (?1)", [Status::OnHold]).unwrap();
+    let stored: String = conn.query_row("SELECT status FROM accounts", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!("on_hold", stored);
+    let status: Status = conn.query_row("SELECT status FROM accounts", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(Status::OnHold, status);
+}
+
+#[test]
+fn disabled_variant() {
+    let conn = accounts();
+    assert!(conn.execute("INSERT INTO accounts (status) VALUES (?1)", [Status::Deleted]).is_err());
+}
+
+#[test]
+fn unknown_value() {
+    let conn = accounts();
+    conn.execute("INSERT INTO accounts (status) VALUES ('closed')", []).unwrap();
+    let err = conn.query_row("SELECT status FROM accounts", [], |row| row.get::<_, Status>(0))
+        .unwrap_err();
+    match err {
+        rusqlite::Error::FromSqlConversionFailure(_, _, err) => {
+            assert_eq!("Matching variant of Status not found", err.to_string());
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
+    let err = conn.query_row("SELECT 7", [], |row| row.get::<_, Status>(0)).unwrap_err();
+    assert!(matches!(err, rusqlite::Error::InvalidColumnType(..)));
+}
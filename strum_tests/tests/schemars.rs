@@ -0,0 +1,42 @@
+#![cfg(feature = "schemars")]
+
+extern crate schemars;
+#[macro_use]
+extern crate serde_json;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+#[derive(EnumString,EnumJsonSchema)]
+#[strum(serialize_all="snake_case")]
+#[allow(dead_code)]
+enum Mode {
+    FastForward,
+    #[strum(serialize="s", serialize="slow")]
+    Slow,
+    #[strum(disabled="true")]
+    Hidden,
+}
+
+#[derive(EnumString,EnumJsonSchema)]
+#[allow(dead_code)]
+enum Tag {
+    Pinned,
+    #[strum(default="true")]
+    Other(String),
+}
+
+#[test]
+fn schema_lists_serializations() {
+    let schema = schemars::schema_for!(Mode);
+    assert_eq!(Some(&json!("string")), schema.get("type"));
+    assert_eq!(Some(&json!(["fast_forward", "s", "slow"])), schema.get("enum"));
+    assert_eq!(Some(&json!("Mode")), schema.get("title"));
+}
+
+#[test]
+fn default_variant_accepts_any_string() {
+    let schema = schemars::schema_for!(Tag);
+    assert_eq!(Some(&json!("string")), schema.get("type"));
+    assert_eq!(None, schema.get("enum"));
+}
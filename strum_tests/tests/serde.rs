@@ -0,0 +1,96 @@
+#![cfg(feature = "serde")]
+
+#[macro_use]
+extern crate serde;
+extern crate serde_json;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+#[derive(Debug,PartialEq,EnumString,EnumSerialize,EnumDeserialize)]
+#[strum(serialize_all="kebab-case")]
+enum Color {
+    DarkRed,
+    #[strum(serialize="b", serialize="blue")]
+    Blue,
+    #[strum(disabled="true")]
+    Transparent,
+    #[strum(default="true")]
+    Other(String),
+}
+
+#[derive(Debug,PartialEq,EnumString,EnumSerialize,EnumDeserialize)]
+enum Direction {
+    North,
+    South,
+}
+
+#[derive(Debug,PartialEq,Serialize,Deserialize,Display,EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(use_serde_names)]
+enum Level {
+    FatalError,
+    HTTPServer,
+    #[serde(rename = "warn", alias = "warning")]
+    Warning,
+    #[serde(rename = "dbg")]
+    #[strum(to_string="debug")]
+    Debug,
+}
+
+fn round_trip(color: Color) -> Color {
+    serde_json::from_str(&serde_json::to_string(&color).unwrap()).unwrap()
+}
+
+#[test]
+fn serialize() {
+    assert_eq!("\"dark-red\"", serde_json::to_string(&Color::DarkRed).unwrap());
+    assert_eq!("\"blue\"", serde_json::to_string(&Color::Blue).unwrap());
+    assert_eq!("\"teal\"", serde_json::to_string(&Color::Other("teal".into())).unwrap());
+    assert!(serde_json::to_string(&Color::Transparent).is_err());
+}
+
+#[test]
+fn deserialize() {
+    assert_eq!(Color::Blue, serde_json::from_str("\"b\"").unwrap());
+    assert_eq!(Color::Other("Transparent".into()), serde_json::from_str("\"Transparent\"").unwrap());
+    assert!(serde_json::from_str::<Color>("7").is_err());
+}
+
+#[test]
+fn round_trips() {
+    assert_eq!(Color::DarkRed, round_trip(Color::DarkRed));
+    assert_eq!(Color::Blue, round_trip(Color::Blue));
+    assert_eq!(Color::Other("teal".into()), round_trip(Color::Other("teal".into())));
+}
+
+#[test]
+fn unknown_variant() {
+    let err = serde_json::from_str::<Direction>("\"East\"").unwrap_err();
+    assert_eq!("unknown variant `East`, expected `North` or `South` at line 1 column 6",
+               err.to_string());
+}
+
+#[test]
+fn serde_names() {
+    for level in [Level::FatalError, Level::HTTPServer, Level::Warning] {
+        let json = serde_json::to_string(&level).unwrap();
+        assert_eq!(json, format!("\"{}\"", level));
+        assert_eq!(Ok(level), json.trim_matches('"').parse());
+    }
+    assert_eq!("h-t-t-p-server", Level::HTTPServer.to_string());
+}
+
+#[test]
+fn serde_aliases_parse() {
+    assert_eq!(Level::Warning, serde_json::from_str("\"warning\"").unwrap());
+    assert_eq!(Ok(Level::Warning), "warning".parse());
+    assert!("Warning".parse::<Level>().is_err());
+}
+
+#[test]
+fn to_string_overrides_serde_rename() {
+    assert_eq!("debug", Level::Debug.to_string());
+    assert_eq!(Ok(Level::Debug), "debug".parse());
+    assert_eq!(Ok(Level::Debug), "dbg".parse());
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "sqlx")]
+
+extern crate sqlx;
+extern crate strum;
+#[macro_use]
+extern crate strum_macros;
+
+use sqlx::{Database, Decode, Encode, Postgres, Type};
+use sqlx::encode::IsNull;
+
+#[derive(Debug,EnumString,EnumSqlxType)]
+#[strum(serialize_all="snake_case")]
+#[allow(dead_code)]
+enum Status {
+    Active,
+    OnHold,
+    #[strum(disabled="true")]
+    Deleted,
+}
+
+#[derive(Debug,EnumString,EnumSqlxType)]
+#[allow(dead_code)]
+enum Label {
+    Pinned,
+    #[strum(default="true")]
+    Other(String),
+}
+
+// Only compiles if the derive implemented all three traits for the database.
+fn assert_column<T, DB>()
+    where DB: Database,
+          T: Type<DB> + for<'q> Encode<'q, DB> + for<'r> Decode<'r, DB>
+{
+}
+
+#[test]
+fn implements_column_traits() {
+    assert_column::<Status, Postgres>();
+    assert_column::<Label, Postgres>();
+}
+
+#[test]
+fn stored_as_text() {
+    assert_eq!(<str as Type<Postgres>>::type_info(), <Status as Type<Postgres>>::type_info());
+    assert!(<Label as Type<Postgres>>::compatible(&<String as Type<Postgres>>::type_info()));
+}
+
+#[test]
+fn encodes_serialization() {
+    let mut buf = sqlx::postgres::PgArgumentBuffer::default();
+    assert!(matches!(Status::OnHold.encode_by_ref(&mut buf), Ok(IsNull::No)));
+    assert!(matches!(Label::Other(String::from("misc")).encode_by_ref(&mut buf), Ok(IsNull::No)));
+    assert_eq!(b"on_holdmisc", &buf[..]);
+    assert!(Status::Deleted.encode_by_ref(&mut buf).is_err());
+}
@@ -8,3 +8,14 @@ cargo build --verbose
 cargo test --verbose
 
 cd ..
+
+cd ./strum_nostd_tests
+cargo build --verbose
+cargo test --verbose
+
+cd ..
+
+# The derives for other crates' traits are only tested with those crates enabled.
+cd ./strum_tests
+cargo test --verbose --features "serde clap schemars sqlx diesel postgres-types rusqlite rand proptest quickcheck arbitrary rayon"
+cd ..